@@ -20,14 +20,14 @@ use crate::recursion_level;
 use crate::runner;
 use crate::time_summary;
 use crate::toolchain;
-use crate::types::{CliArgs, GlobalConfig};
+use crate::types::{CliArgs, Config, GlobalConfig};
 use crate::version;
+use std::path::Path;
 use std::time::SystemTime;
 
 pub(crate) static VERSION: &str = env!("CARGO_PKG_VERSION");
 pub(crate) static AUTHOR: &str = env!("CARGO_PKG_AUTHORS");
 pub(crate) static DESCRIPTION: &str = env!("CARGO_PKG_DESCRIPTION");
-pub(crate) static DEFAULT_TOML: &str = "Makefile.toml";
 pub(crate) static DEFAULT_LOG_LEVEL: &str = "info";
 pub(crate) static DEFAULT_TASK_NAME: &str = "default";
 pub(crate) static DEFAULT_OUTPUT_FORMAT: &str = "default";
@@ -39,11 +39,28 @@ pub fn run(
 ) -> Result<(), CargoMakeError> {
     let start_time = SystemTime::now();
 
+    if cli_args.print_env || cli_args.explain_env.is_some() {
+        cli_commands::print_env::init();
+    }
+
     recursion_level::increment();
 
+    // the schema, env, config and explanations are printed to stdout which is shared with the
+    // logger output
+    let print_to_stdout = (cli_args.print_schema
+        || cli_args.print_env
+        || cli_args.print_config
+        || cli_args.explain.is_some())
+        && cli_args.output_file.is_none();
+    let log_level = if print_to_stdout || cli_args.explain_env.is_some() {
+        "error".to_string()
+    } else {
+        cli_args.log_level.clone()
+    };
+
     logger::init(&logger_options.unwrap_or(LoggerOptions {
         name: String::from(env!("CARGO_PKG_NAME")),
-        level: cli_args.log_level.clone(),
+        level: log_level,
         color: !cli_args.disable_color,
     }));
 
@@ -55,6 +72,10 @@ pub fn run(
     debug!("Cli Args {:#?}", &cli_args);
     debug!("Global Configuration {:#?}", &global_config);
 
+    if cli_args.print_schema {
+        return cli_commands::print_schema::run(&cli_args.output_file);
+    }
+
     if version::should_check(&cli_args, &global_config) {
         version::check();
     }
@@ -88,20 +109,40 @@ pub fn run(
     let build_file = &cli_args
         .build_file
         .clone()
-        .unwrap_or(DEFAULT_TOML.to_string());
-    let task = &cli_args.task;
+        .unwrap_or_else(|| descriptor::get_default_makefile(Path::new(".")));
     let profile_name = &cli_args
         .profile
         .clone()
         .unwrap_or_else(profile::default_profile);
     let normalized_profile_name = profile::set(&profile_name);
 
+    if cli_args.fmt {
+        return cli_commands::fmt::run(build_file, cli_args.fmt_check);
+    }
+
+    if cli_args.migrate {
+        return cli_commands::migrate::run(build_file);
+    }
+
+    if let Some(ref file) = cli_args.import {
+        return cli_commands::import_tasks::run(file, &cli_args.output_file);
+    }
+
+    if cli_args.lint {
+        return cli_commands::lint::run(
+            build_file,
+            force_makefile,
+            cli_args.experimental,
+            &cli_args.output_file,
+        );
+    }
+
     environment::load_env_file(cli_args.env_file.clone());
 
     let env = cli_args.env.clone();
 
     let experimental = cli_args.experimental;
-    let config = descriptor::load(&build_file, force_makefile, env, experimental)?;
+    let (config, env_origins) = descriptor::load(&build_file, force_makefile, env, experimental)?;
 
     let mut time_summary_vec = vec![];
     time_summary::add(
@@ -111,6 +152,22 @@ pub fn run(
     );
     let step_time = SystemTime::now();
 
+    let mut cli_args = split_positional_tasks(cli_args, &config);
+    if cli_commands::pick_task::should_pick(&cli_args, global_config, &config) {
+        match cli_commands::pick_task::run(&config)? {
+            Some(name) => {
+                cli_args.task = name;
+                cli_args.additional_tasks = vec![];
+            }
+            None => {
+                info!("No task picked.");
+                return Ok(());
+            }
+        }
+    }
+    let cli_args = &cli_args;
+    let task = &cli_args.task;
+
     match config.config.additional_profiles {
         Some(ref profiles) => profile::set_additional(profiles),
         None => profile::set_additional(&vec![]),
@@ -125,7 +182,11 @@ pub fn run(
         info!("Project: {}", &crate_name);
     }
     info!("Build File: {}", &build_file);
-    info!("Task: {}", &task);
+    if cli_args.additional_tasks.is_empty() {
+        info!("Task: {}", &task);
+    } else {
+        info!("Tasks: {}, {}", &task, cli_args.additional_tasks.join(", "));
+    }
     info!("Profile: {}", &normalized_profile_name);
 
     // ensure profile env was not overridden
@@ -139,6 +200,8 @@ pub fn run(
             &cli_args.list_category_steps,
             cli_args.hide_uninteresting,
         )
+    } else if cli_args.list_profiles {
+        cli_commands::list_profiles::run(&config, &cli_args.output_file)
     } else if cli_args.diff_execution_plan {
         let default_config = descriptor::load_internal_descriptors(true, experimental, None)?;
         cli_commands::diff_steps::run(
@@ -148,16 +211,51 @@ pub fn run(
             &cli_args,
             &env_info.crate_info,
         )
+    } else if let Some(ref name) = cli_args.explain {
+        cli_commands::explain_task::run(&config, name, &cli_args.output_file)
+    } else if let Some(ref name) = cli_args.explain_env {
+        cli_commands::explain_env::run(
+            &config,
+            task,
+            name,
+            &env_origins,
+            &env_info.crate_info,
+            cli_args.disable_workspace,
+        )
+    } else if cli_args.print_config {
+        cli_commands::print_config::run(
+            &config,
+            &cli_args.print_config_task,
+            &env_origins,
+            build_file,
+            &cli_args.output_file,
+        )
+    } else if cli_args.print_env {
+        cli_commands::print_env::run(&config, task, cli_args, &env_origins, &env_info.crate_info)
     } else if cli_args.print_only {
+        let condition_flow_info = if cli_args.why {
+            Some(runner::create_flow_info(
+                config.clone(),
+                task,
+                env_info.clone(),
+                cli_args,
+            ))
+        } else {
+            None
+        };
+
         cli_commands::print_steps::print(
             &mut std::io::stdout(),
             &config,
             &task,
+            &cli_args.additional_tasks,
             &cli_args.output_format,
             cli_args.disable_workspace,
             &cli_args.skip_tasks_pattern,
             &env_info.crate_info,
             cli_args.skip_init_end_tasks,
+            condition_flow_info.as_ref(),
+            cli_args.why_condition_scripts,
         )
     } else {
         runner::run(
@@ -173,6 +271,39 @@ pub fn run(
     Ok(())
 }
 
+/// Splits the `task1 task2 -- args` form of the task arguments into the additional tasks and
+/// the actual task arguments.<br>
+/// The split is only done if all the values before the `--` are defined tasks and the tasks
+/// were not provided via the --tasks CLI argument, otherwise they remain task arguments of the
+/// first task.
+fn split_positional_tasks(cli_args: &CliArgs, config: &Config) -> CliArgs {
+    let mut cli_args = cli_args.clone();
+
+    if !cli_args.tasks_provided {
+        if let Some(arguments) = cli_args.arguments.clone() {
+            if let Some(index) = arguments.iter().position(|argument| argument == "--") {
+                let tasks = &arguments[..index];
+
+                if !tasks.is_empty()
+                    && config.tasks.contains_key(&cli_args.task)
+                    && tasks.iter().all(|name| config.tasks.contains_key(name))
+                {
+                    let task_arguments = arguments[index + 1..].to_vec();
+
+                    cli_args.additional_tasks = tasks.to_vec();
+                    cli_args.arguments = if task_arguments.is_empty() {
+                        None
+                    } else {
+                        Some(task_arguments)
+                    };
+                }
+            }
+        }
+    }
+
+    cli_args
+}
+
 /// Handles the command line arguments and executes the runner.
 pub fn run_cli(command_name: String, sub_command: bool) -> Result<CliArgs, CargoMakeError> {
     let global_config = config::load()?;
@@ -11,6 +11,7 @@ fn run_same() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -38,6 +39,7 @@ fn run_different() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -54,6 +56,7 @@ fn run_different() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -79,6 +82,7 @@ fn run_different_with_skip() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -95,6 +99,7 @@ fn run_different_with_skip() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -117,6 +122,7 @@ fn run_missing_task_in_first_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -129,6 +135,7 @@ fn run_missing_task_in_first_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
@@ -155,6 +162,7 @@ fn run_missing_task_in_second_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config1.tasks.insert("init".to_string(), Task::new());
@@ -168,6 +176,7 @@ fn run_missing_task_in_second_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config2.tasks.insert("init".to_string(), Task::new());
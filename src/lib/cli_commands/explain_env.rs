@@ -0,0 +1,158 @@
+//! # explain_env
+//!
+//! Explains where the value of an env variable came from by printing all its
+//! definitions (internal descriptors, extended makefiles, workspace makefile, task env
+//! and cli) from the first definition to the last override.
+//!
+
+#[cfg(test)]
+#[path = "explain_env_test.rs"]
+mod explain_env_test;
+
+use crate::cli_commands::print_env;
+use crate::descriptor::env::create_env_origins;
+use crate::environment;
+use crate::environment::sources;
+use crate::error::CargoMakeError;
+use crate::profile;
+use crate::secrets;
+use crate::types::{Config, CrateInfo, EnvOrigin, EnvValue};
+use indexmap::IndexMap;
+use std::env;
+
+fn describe_value(value: &EnvValue) -> String {
+    match value {
+        EnvValue::Value(value) => format!("\"{}\"", value),
+        EnvValue::Boolean(value) => value.to_string(),
+        EnvValue::Number(value) => value.to_string(),
+        EnvValue::List(value) => format!("\"{}\"", value.join(";")),
+        EnvValue::Unset(_) => "unset".to_string(),
+        EnvValue::Secret(_) => "secret".to_string(),
+        EnvValue::FromFile(info) => format!("loaded from file: {}", &info.from_file),
+        EnvValue::FromScript(_) => "loaded from script output".to_string(),
+        EnvValue::Script(_) => "script output".to_string(),
+        EnvValue::Decode(info) => format!("decoded from: \"{}\"", &info.source),
+        EnvValue::Conditional(info) => format!("\"{}\" (conditional)", &info.value),
+        EnvValue::PathGlob(info) => format!("path glob: \"{}\"", &info.glob),
+        EnvValue::Profile(_) => "profile".to_string(),
+    }
+}
+
+fn describe_origin(origin: &EnvOrigin, active_profiles: &[String]) -> String {
+    let mut description = format!("[{}]", &origin.layer);
+
+    if let Some(ref file) = origin.file {
+        description.push_str(&format!(" {}", file));
+    }
+
+    if let Some(ref profile_name) = origin.profile {
+        if active_profiles.contains(profile_name) {
+            description.push_str(&format!(" (profile: {})", profile_name));
+        } else {
+            description.push_str(&format!(" (profile: {}, inactive)", profile_name));
+        }
+    }
+
+    format!("{}: {}", description, describe_value(&origin.value))
+}
+
+/// Returns the runtime source of the env value, makefile env values are resolved to
+/// the last active definition.
+pub(crate) fn get_source(
+    name: &str,
+    value: &str,
+    origins: &[EnvOrigin],
+    active_profiles: &[String],
+) -> String {
+    let source = sources::get(name, value);
+
+    if source == environment::MAKEFILE_ENV_SOURCE {
+        let last_origin = origins.iter().rev().find(|origin| match origin.profile {
+            Some(ref profile_name) => active_profiles.contains(profile_name),
+            None => !origin.layer.starts_with("task: "),
+        });
+
+        if let Some(origin) = last_origin {
+            return match origin.file {
+                Some(ref file) => format!("{}: {}", &origin.layer, file),
+                None => origin.layer.to_string(),
+            };
+        }
+    }
+
+    source
+}
+
+pub(crate) fn get_active_profiles(config: &Config) -> Vec<String> {
+    let mut profiles = vec![profile::get()];
+
+    if let Some(ref additional_profiles) = config.config.additional_profiles {
+        profiles.extend(additional_profiles.iter().cloned());
+    }
+
+    profiles
+}
+
+/// Returns the explanation of the env variable value based on the given origins
+/// and the current env.
+fn create_explanation(name: &str, origins: &[EnvOrigin], active_profiles: &[String]) -> String {
+    let mut output = format!("Env: {}\n", name);
+
+    if origins.is_empty() {
+        output.push_str("Definitions: none\n");
+    } else {
+        output.push_str("Definitions (first to last):\n");
+
+        for (index, origin) in origins.iter().enumerate() {
+            output.push_str(&format!(
+                "  {}. {}\n",
+                index + 1,
+                describe_origin(origin, active_profiles)
+            ));
+        }
+    }
+
+    match env::var(name) {
+        Ok(value) => {
+            output.push_str(&format!("Final Value: \"{}\"\n", value));
+            output.push_str(&format!(
+                "Set By: {}\n",
+                get_source(name, &value, origins, active_profiles)
+            ));
+        }
+        Err(_) => output.push_str("Final Value: <not set>\n"),
+    };
+
+    secrets::mask(&output)
+}
+
+/// Prints the explanation of where the env variable value came from
+pub(crate) fn run(
+    config: &Config,
+    task: &str,
+    name: &str,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    crateinfo: &CrateInfo,
+    disable_workspace: bool,
+) -> Result<(), CargoMakeError> {
+    let step = print_env::setup_task_env(config, task, crateinfo, disable_workspace)?;
+
+    let mut origins = env_origins.get(name).cloned().unwrap_or_default();
+
+    if let Some(step) = step {
+        if let Some(ref env) = step.config.env {
+            let task_origins = create_env_origins(env, None, &format!("task: {}", &step.name));
+
+            if let Some(mut task_origins) = task_origins.get(name).cloned() {
+                origins.append(&mut task_origins);
+            }
+        }
+    }
+
+    print!(
+        "{}",
+        create_explanation(name, &origins, &get_active_profiles(config))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+use crate::types::{EnvValueConditioned, EnvValueUnset};
+
+fn create_origin(layer: &str, file: Option<&str>, profile: Option<&str>, value: &str) -> EnvOrigin {
+    EnvOrigin {
+        file: file.map(|value| value.to_string()),
+        layer: layer.to_string(),
+        profile: profile.map(|value| value.to_string()),
+        value: EnvValue::Value(value.to_string()),
+    }
+}
+
+#[test]
+fn describe_value_value() {
+    let output = describe_value(&EnvValue::Value("test".to_string()));
+
+    assert_eq!(output, "\"test\"");
+}
+
+#[test]
+fn describe_value_list() {
+    let output = describe_value(&EnvValue::List(vec!["1".to_string(), "2".to_string()]));
+
+    assert_eq!(output, "\"1;2\"");
+}
+
+#[test]
+fn describe_value_unset() {
+    let output = describe_value(&EnvValue::Unset(EnvValueUnset { unset: true }));
+
+    assert_eq!(output, "unset");
+}
+
+#[test]
+fn describe_value_conditional() {
+    let output = describe_value(&EnvValue::Conditional(EnvValueConditioned {
+        value: "test".to_string(),
+        condition: None,
+    }));
+
+    assert_eq!(output, "\"test\" (conditional)");
+}
+
+#[test]
+fn describe_origin_no_file() {
+    let output = describe_origin(&create_origin("cli", None, None, "test"), &[]);
+
+    assert_eq!(output, "[cli]: \"test\"");
+}
+
+#[test]
+fn describe_origin_with_file() {
+    let output = describe_origin(
+        &create_origin("makefile", Some("/project/Makefile.toml"), None, "test"),
+        &[],
+    );
+
+    assert_eq!(output, "[makefile] /project/Makefile.toml: \"test\"");
+}
+
+#[test]
+fn describe_origin_active_profile() {
+    let output = describe_origin(
+        &create_origin("extend", Some("base.toml"), Some("production"), "test"),
+        &["production".to_string()],
+    );
+
+    assert_eq!(output, "[extend] base.toml (profile: production): \"test\"");
+}
+
+#[test]
+fn describe_origin_inactive_profile() {
+    let output = describe_origin(
+        &create_origin("extend", Some("base.toml"), Some("production"), "test"),
+        &["development".to_string()],
+    );
+
+    assert_eq!(
+        output,
+        "[extend] base.toml (profile: production, inactive): \"test\""
+    );
+}
+
+#[test]
+fn create_explanation_not_defined() {
+    envmnt::remove("EXPLAIN_ENV_TEST_NOT_DEFINED");
+
+    let output = create_explanation("EXPLAIN_ENV_TEST_NOT_DEFINED", &[], &[]);
+
+    assert_eq!(
+        output,
+        "Env: EXPLAIN_ENV_TEST_NOT_DEFINED\nDefinitions: none\nFinal Value: <not set>\n"
+    );
+}
+
+#[test]
+fn create_explanation_override_chain() {
+    envmnt::set("EXPLAIN_ENV_TEST_CHAIN", "cli value");
+
+    let origins = vec![
+        create_origin("internal", None, None, "internal value"),
+        create_origin("extend", Some("base.toml"), None, "extend value"),
+        create_origin("makefile", Some("Makefile.toml"), None, "makefile value"),
+        create_origin("cli", None, None, "cli value"),
+    ];
+    let output = create_explanation("EXPLAIN_ENV_TEST_CHAIN", &origins, &[]);
+
+    envmnt::remove("EXPLAIN_ENV_TEST_CHAIN");
+
+    assert!(output.starts_with(
+        r#"Env: EXPLAIN_ENV_TEST_CHAIN
+Definitions (first to last):
+  1. [internal]: "internal value"
+  2. [extend] base.toml: "extend value"
+  3. [makefile] Makefile.toml: "makefile value"
+  4. [cli]: "cli value"
+Final Value: "cli value"
+Set By: "#
+    ));
+}
+
+#[test]
+fn create_explanation_secret_masked() {
+    envmnt::set("EXPLAIN_ENV_TEST_SECRET", "explain-secret-value");
+    secrets::add("EXPLAIN_ENV_TEST_SECRET");
+
+    let origins = vec![create_origin(
+        "makefile",
+        None,
+        None,
+        "explain-secret-value",
+    )];
+    let output = create_explanation("EXPLAIN_ENV_TEST_SECRET", &origins, &[]);
+
+    envmnt::remove("EXPLAIN_ENV_TEST_SECRET");
+
+    assert!(!output.contains("explain-secret-value"));
+    assert!(output.contains("Final Value: \"***\""));
+}
+
+#[test]
+fn get_source_not_makefile_env() {
+    envmnt::remove("EXPLAIN_ENV_TEST_SOURCE_NONE");
+
+    let output = get_source("EXPLAIN_ENV_TEST_SOURCE_NONE", "test", &[], &[]);
+
+    assert_eq!(output, sources::CARGO_MAKE_SOURCE);
+}
@@ -0,0 +1,361 @@
+//! # explain_task
+//!
+//! Explains how a task was assembled: its alias chain, the extend ancestors and the fields
+//! each of them contributed, the platform overrides, the profile specific env, the clear
+//! resets and finally its dependency tree.
+//!
+
+#[cfg(test)]
+#[path = "explain_task_test.rs"]
+mod explain_task_test;
+
+use crate::cli_commands::explain_env;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::io;
+use crate::types::{
+    get_platform_name, Config, DependencyIdentifier, EnvValue, Task, TaskIdentifier,
+};
+use std::collections::HashSet;
+
+/// The task env values added by cargo-make to track the makefile of each task
+static TASK_LOCATION_ENV: [&str; 2] = [
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE",
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY",
+];
+
+/// The task attributes which are resolved before the task is assembled and therefore are not
+/// reported as contributed fields
+static RESOLUTION_FIELDS: [&str; 9] = [
+    "clear",
+    "extend",
+    "alias",
+    "linux_alias",
+    "windows_alias",
+    "mac_alias",
+    "linux",
+    "windows",
+    "mac",
+];
+
+#[derive(Debug, Clone)]
+/// Holds a single level of the extend chain
+struct ExtendLevel {
+    /// The task name
+    name: String,
+    /// The makefile the task was defined in (internal for the core tasks)
+    source: String,
+    /// The fields defined by this level (after its platform override is applied)
+    fields: Vec<String>,
+    /// The fields defined by the platform override of this level
+    override_fields: Vec<String>,
+    /// True if the task definition reset the definitions from the previous makefiles
+    clear: bool,
+}
+
+/// Returns the alias of the task and the attribute it was defined in
+fn get_alias_attribute(task: &Task) -> Option<(String, String)> {
+    let platform_alias = match get_platform_name().as_str() {
+        "windows" => &task.windows_alias,
+        "mac" => &task.mac_alias,
+        _ => &task.linux_alias,
+    };
+
+    match platform_alias {
+        Some(alias) => Some((alias.to_string(), format!("{}_alias", get_platform_name()))),
+        None => task
+            .alias
+            .as_ref()
+            .map(|alias| (alias.to_string(), "alias".to_string())),
+    }
+}
+
+/// Returns the alias chain, for example: ["b", "--[alias]--> build"]
+fn get_alias_chain(config: &Config, name: &str) -> Result<Vec<String>, CargoMakeError> {
+    // validates the task exists and there are no alias cycles
+    execution_plan::get_actual_task_name(config, name)?;
+
+    let mut chain = vec![name.to_string()];
+    let mut current = name.to_string();
+    while let Some((alias, attribute)) = config.tasks.get(&current).and_then(get_alias_attribute) {
+        chain.push(format!("--[{}]--> {}", attribute, &alias));
+        current = alias;
+    }
+
+    Ok(chain)
+}
+
+/// Returns the names of the fields which are defined in the serialized value
+fn get_defined_fields<T: serde::Serialize>(value: &T) -> Vec<String> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::Object(map)) => map
+            .iter()
+            .filter(|(key, value)| !value.is_null() && !RESOLUTION_FIELDS.contains(&key.as_str()))
+            .filter(|(key, value)| {
+                // the location env is added to every task
+                key.as_str() != "env"
+                    || value.as_object().is_none_or(|env| {
+                        env.keys()
+                            .any(|env_key| !TASK_LOCATION_ENV.contains(&env_key.as_str()))
+                    })
+            })
+            .map(|(key, _)| key.to_string())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns the makefile the task was defined in
+fn get_source(task: &Task) -> String {
+    match task.env {
+        Some(ref env) => match env.get(TASK_LOCATION_ENV[0]) {
+            Some(EnvValue::Value(file)) => file.to_string(),
+            _ => "internal".to_string(),
+        },
+        None => "internal".to_string(),
+    }
+}
+
+/// Returns the extend chain from the task itself up to its root ancestor
+fn get_extend_chain(config: &Config, name: &str) -> Result<Vec<ExtendLevel>, CargoMakeError> {
+    let mut chain = vec![];
+    let mut seen = vec![];
+    let mut current = Some(name.to_string());
+
+    while let Some(name) = current {
+        if seen.contains(&name) {
+            seen.push(name);
+            return Err(CargoMakeError::CircularReference(seen.join(" -> ")));
+        }
+        seen.push(name.clone());
+
+        let task = match config.tasks.get(&name) {
+            Some(task) => task.clone(),
+            None => {
+                return Err(CargoMakeError::NotFound(format!(
+                    "Task {:#?} not found",
+                    name
+                )))
+            }
+        };
+        let override_task = match get_platform_name().as_str() {
+            "windows" => task.windows.clone(),
+            "mac" => task.mac.clone(),
+            _ => task.linux.clone(),
+        };
+        let normalized_task = task.clone().get_normalized_task();
+
+        current = match normalized_task.extend {
+            Some(ref extended_name) => {
+                Some(execution_plan::get_actual_task_name(config, extended_name)?)
+            }
+            None => None,
+        };
+
+        chain.push(ExtendLevel {
+            name,
+            source: get_source(&task),
+            fields: get_defined_fields(&normalized_task),
+            override_fields: override_task
+                .as_ref()
+                .map(get_defined_fields)
+                .unwrap_or_default(),
+            clear: task.clear.unwrap_or(false),
+        });
+    }
+
+    Ok(chain)
+}
+
+fn explain_extend_chain(chain: &[ExtendLevel]) -> String {
+    let mut output = "Extend Chain (root ancestor first):\n".to_string();
+
+    // fields defined by a later level override the same fields of the previous levels
+    let mut overriding_fields: HashSet<&String> = HashSet::new();
+    let mut lines = vec![];
+    for level in chain {
+        let (overridden, contributed): (Vec<&String>, Vec<&String>) = level
+            .fields
+            .iter()
+            .partition(|field| overriding_fields.contains(field));
+
+        let mut line = format!("{} ({})\n", &level.name, &level.source);
+        line.push_str(&format!(
+            "     contributed: {}\n",
+            join_or_none(&contributed)
+        ));
+        if !overridden.is_empty() {
+            line.push_str(&format!("     overridden: {}\n", join_or_none(&overridden)));
+        }
+        if !level.override_fields.is_empty() {
+            line.push_str(&format!(
+                "     platform override ({}): {}\n",
+                get_platform_name(),
+                level.override_fields.join(", ")
+            ));
+        }
+        if level.clear {
+            line.push_str(
+                "     clear: the definitions of this task from the previous makefiles were discarded\n",
+            );
+        }
+        lines.push(line);
+
+        overriding_fields.extend(level.fields.iter());
+    }
+
+    for (index, line) in lines.iter().rev().enumerate() {
+        output.push_str(&format!("  {}. {}", index + 1, line));
+    }
+
+    output
+}
+
+fn join_or_none(values: &[&String]) -> String {
+    if values.is_empty() {
+        "none".to_string()
+    } else {
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+fn explain_profile_env(task: &Task, active_profiles: &[String]) -> String {
+    let mut output = "Profile Env:".to_string();
+
+    let mut found = false;
+    if let Some(ref env) = task.env {
+        for (key, value) in env {
+            if let EnvValue::Profile(sub_env) = value {
+                let status = if active_profiles.contains(key) {
+                    "active"
+                } else {
+                    "inactive"
+                };
+                let names: Vec<String> = sub_env.keys().cloned().collect();
+
+                output.push_str(&format!("\n  {} ({}): {}", key, status, names.join(", ")));
+                found = true;
+            }
+        }
+    }
+
+    if found {
+        output.push('\n');
+    } else {
+        output.push_str(" none\n");
+    }
+
+    output
+}
+
+fn add_dependency_tree(
+    config: &Config,
+    name: &str,
+    depth: usize,
+    path: &mut Vec<String>,
+    expanded: &mut HashSet<String>,
+    output: &mut String,
+) {
+    let indent = "  ".repeat(depth);
+
+    let actual_name = match execution_plan::get_actual_task_name(config, name) {
+        Ok(actual_name) => actual_name,
+        Err(_) => {
+            output.push_str(&format!("{}- {} (not found)\n", indent, name));
+            return;
+        }
+    };
+    let title = if actual_name == name {
+        name.to_string()
+    } else {
+        format!("{} -> {}", name, &actual_name)
+    };
+
+    if path.contains(&actual_name) {
+        output.push_str(&format!("{}- {} (cycle)\n", indent, title));
+        return;
+    }
+    if expanded.contains(&actual_name) {
+        output.push_str(&format!("{}- {} (see above)\n", indent, title));
+        return;
+    }
+
+    let dependencies = match execution_plan::get_normalized_task(config, &actual_name, true) {
+        Ok(task) => task.dependencies.unwrap_or_default(),
+        Err(_) => vec![],
+    };
+    if !dependencies.is_empty() {
+        expanded.insert(actual_name.clone());
+    }
+    output.push_str(&format!("{}- {}\n", indent, title));
+
+    path.push(actual_name);
+    for dependency in dependencies {
+        match dependency {
+            DependencyIdentifier::Definition(TaskIdentifier {
+                name,
+                path: Some(file),
+            }) => {
+                // tasks of other makefiles are not resolved
+                output.push_str(&format!("{}  - {} ({})\n", indent, name, file));
+            }
+            _ => add_dependency_tree(config, dependency.name(), depth + 1, path, expanded, output),
+        }
+    }
+    path.pop();
+}
+
+/// Returns the explanation of how the task was assembled
+pub(crate) fn create_explanation(config: &Config, name: &str) -> Result<String, CargoMakeError> {
+    let alias_chain = get_alias_chain(config, name)?;
+    let actual_name = execution_plan::get_actual_task_name(config, name)?;
+    let chain = get_extend_chain(config, &actual_name)?;
+    let task = execution_plan::get_normalized_task(config, &actual_name, true)?;
+    let active_profiles = explain_env::get_active_profiles(config);
+
+    let mut output = format!("Task: {}\n", name);
+    output.push_str(&format!("Alias Chain: {}\n", alias_chain.join(" ")));
+    output.push_str(&format!("Actual Task: {}\n", &actual_name));
+    output.push_str(&explain_extend_chain(&chain));
+    output.push_str(&format!(
+        "Active Profiles: {}\n",
+        active_profiles.join(", ")
+    ));
+    output.push_str(&explain_profile_env(&task, &active_profiles));
+
+    output.push_str("Dependency Tree:\n");
+    let mut tree = String::new();
+    add_dependency_tree(
+        config,
+        &actual_name,
+        1,
+        &mut vec![],
+        &mut HashSet::new(),
+        &mut tree,
+    );
+    output.push_str(&tree);
+
+    Ok(output)
+}
+
+/// Prints the explanation of how the task was assembled to the output file or stdout
+pub(crate) fn run(
+    config: &Config,
+    name: &str,
+    output_file: &Option<String>,
+) -> Result<(), CargoMakeError> {
+    let output = create_explanation(config, name)?;
+
+    match output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
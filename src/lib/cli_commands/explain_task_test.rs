@@ -0,0 +1,320 @@
+use super::*;
+use crate::types::ConfigSection;
+use indexmap::IndexMap;
+
+fn create_config(tasks: Vec<(&str, &str)>) -> Config {
+    let mut config_tasks = IndexMap::new();
+    for (name, content) in tasks {
+        let task: Task = toml::from_str(content).unwrap();
+        config_tasks.insert(name.to_string(), task);
+    }
+
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        vars: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: config_tasks,
+        plugins: None,
+    }
+}
+
+#[test]
+fn get_alias_attribute_platform_alias_first() {
+    let task: Task = toml::from_str(
+        r#"
+alias = "common"
+linux_alias = "platform"
+windows_alias = "platform"
+mac_alias = "platform"
+"#,
+    )
+    .unwrap();
+
+    let alias = get_alias_attribute(&task);
+
+    assert_eq!(
+        alias,
+        Some((
+            "platform".to_string(),
+            format!("{}_alias", get_platform_name())
+        ))
+    );
+}
+
+#[test]
+fn get_alias_attribute_none() {
+    let alias = get_alias_attribute(&Task::new());
+
+    assert!(alias.is_none());
+}
+
+#[test]
+fn get_alias_chain_multiple() {
+    let config = create_config(vec![
+        ("b", r#"alias = "build""#),
+        ("build", r#"alias = "build-flow""#),
+        ("build-flow", r#"command = "cargo""#),
+    ]);
+
+    let chain = get_alias_chain(&config, "b").unwrap();
+
+    assert_eq!(
+        chain,
+        vec!["b", "--[alias]--> build", "--[alias]--> build-flow"]
+    );
+}
+
+#[test]
+#[should_panic]
+fn get_alias_chain_cycle() {
+    let config = create_config(vec![("a", r#"alias = "b""#), ("b", r#"alias = "a""#)]);
+
+    get_alias_chain(&config, "a").unwrap();
+}
+
+#[test]
+fn get_defined_fields_skip_resolution_fields() {
+    let task: Task = toml::from_str(
+        r#"
+clear = true
+extend = "base"
+command = "echo"
+env = { CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE = "Makefile.toml", CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY = "." }
+
+[linux]
+command = "ls"
+"#,
+    )
+    .unwrap();
+
+    let fields = get_defined_fields(&task);
+
+    assert_eq!(fields, vec!["command"]);
+}
+
+#[test]
+fn get_defined_fields_with_env() {
+    let task: Task = toml::from_str(
+        r#"
+env = { CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE = "Makefile.toml", VALUE = "1" }
+"#,
+    )
+    .unwrap();
+
+    let fields = get_defined_fields(&task);
+
+    assert_eq!(fields, vec!["env"]);
+}
+
+#[test]
+fn get_extend_chain_with_override_and_clear() {
+    let config = create_config(vec![
+        (
+            "base",
+            r#"
+command = "echo"
+description = "base"
+env = { CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE = "base.toml" }
+
+[linux]
+args = ["platform"]
+
+[windows]
+args = ["platform"]
+
+[mac]
+args = ["platform"]
+"#,
+        ),
+        ("middle", r#"extend = "base-alias""#),
+        ("base-alias", r#"alias = "base""#),
+        (
+            "test",
+            r#"
+clear = true
+extend = "middle"
+description = "test"
+"#,
+        ),
+    ]);
+
+    let chain = get_extend_chain(&config, "test").unwrap();
+
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain[0].name, "test");
+    assert_eq!(chain[0].source, "internal");
+    assert_eq!(chain[0].fields, vec!["description"]);
+    assert!(chain[0].clear);
+    assert_eq!(chain[1].name, "middle");
+    assert!(chain[1].fields.is_empty());
+    assert_eq!(chain[2].name, "base");
+    assert_eq!(chain[2].source, "base.toml");
+    assert_eq!(chain[2].fields, vec!["args", "command", "description"]);
+    assert_eq!(chain[2].override_fields, vec!["args"]);
+    assert!(!chain[2].clear);
+}
+
+#[test]
+fn get_extend_chain_cycle() {
+    let config = create_config(vec![("a", r#"extend = "b""#), ("b", r#"extend = "a""#)]);
+
+    let chain = get_extend_chain(&config, "a");
+
+    assert!(chain.is_err());
+}
+
+#[test]
+fn explain_extend_chain_overridden_fields() {
+    let chain = vec![
+        ExtendLevel {
+            name: "test".to_string(),
+            source: "Makefile.toml".to_string(),
+            fields: vec!["description".to_string()],
+            override_fields: vec![],
+            clear: true,
+        },
+        ExtendLevel {
+            name: "base".to_string(),
+            source: "internal".to_string(),
+            fields: vec!["command".to_string(), "description".to_string()],
+            override_fields: vec!["command".to_string()],
+            clear: false,
+        },
+    ];
+
+    let output = explain_extend_chain(&chain);
+
+    assert_eq!(
+        output,
+        format!(
+            r#"Extend Chain (root ancestor first):
+  1. base (internal)
+     contributed: command
+     overridden: description
+     platform override ({}): command
+  2. test (Makefile.toml)
+     contributed: description
+     clear: the definitions of this task from the previous makefiles were discarded
+"#,
+            get_platform_name()
+        )
+    );
+}
+
+#[test]
+fn explain_profile_env_active_and_inactive() {
+    let task: Task = toml::from_str(
+        r#"
+[env]
+VALUE = "1"
+
+[env.development]
+DEV = "1"
+OTHER = "1"
+
+[env.production]
+PROD = "1"
+"#,
+    )
+    .unwrap();
+
+    let output = explain_profile_env(&task, &["development".to_string()]);
+
+    assert_eq!(
+        output,
+        r#"Profile Env:
+  development (active): DEV, OTHER
+  production (inactive): PROD
+"#
+    );
+}
+
+#[test]
+fn explain_profile_env_none() {
+    let output = explain_profile_env(&Task::new(), &["development".to_string()]);
+
+    assert_eq!(output, "Profile Env: none\n");
+}
+
+#[test]
+fn add_dependency_tree_variations() {
+    let config = create_config(vec![
+        (
+            "test",
+            r#"dependencies = ["a", "b", "missing", { name = "other", path = "other.toml" }]"#,
+        ),
+        ("a", r#"dependencies = ["c"]"#),
+        ("b", r#"dependencies = ["a", "c-alias"]"#),
+        ("c", r#"dependencies = ["test"]"#),
+        ("c-alias", r#"alias = "c""#),
+    ]);
+
+    let mut output = String::new();
+    add_dependency_tree(
+        &config,
+        "test",
+        1,
+        &mut vec![],
+        &mut HashSet::new(),
+        &mut output,
+    );
+
+    assert_eq!(
+        output,
+        r#"  - test
+    - a
+      - c
+        - test (cycle)
+    - b
+      - a (see above)
+      - c-alias -> c (see above)
+    - missing (not found)
+    - other (other.toml)
+"#
+    );
+}
+
+#[test]
+fn create_explanation_alias() {
+    let config = create_config(vec![
+        ("b", r#"alias = "build""#),
+        (
+            "build",
+            r#"
+command = "cargo"
+dependencies = ["fmt"]
+"#,
+        ),
+        ("fmt", r#"command = "cargo""#),
+    ]);
+
+    let output = create_explanation(&config, "b").unwrap();
+
+    assert!(output.starts_with(
+        r#"Task: b
+Alias Chain: b --[alias]--> build
+Actual Task: build
+Extend Chain (root ancestor first):
+  1. build (internal)
+     contributed: command, dependencies
+Active Profiles: "#
+    ));
+    assert!(output.ends_with(
+        r#"Profile Env: none
+Dependency Tree:
+  - build
+    - fmt
+"#
+    ));
+}
+
+#[test]
+fn create_explanation_not_found() {
+    let config = create_config(vec![]);
+
+    let output = create_explanation(&config, "missing");
+
+    assert!(output.is_err());
+}
@@ -0,0 +1,298 @@
+//! # fmt
+//!
+//! Rewrites a makefile into a canonical layout.<br>
+//! Keys are sorted within tables (env and vars blocks keep their order as it is
+//! significant), tasks are grouped by category, strings and arrays use a consistent
+//! style and all comments are preserved.
+//!
+
+#[cfg(test)]
+#[path = "fmt_test.rs"]
+mod fmt_test;
+
+use crate::descriptor::descriptor_deserializer::DescriptorFormat;
+use crate::error::CargoMakeError;
+use crate::io;
+use std::cmp::Ordering;
+use std::path::PathBuf;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Key, RawString, Table, Value};
+
+/// The max line length for single line arrays
+static MAX_LINE_LENGTH: usize = 100;
+/// The order of the top level keys, all other keys are placed after them
+static ROOT_KEYS: [&str; 9] = [
+    "extend",
+    "config",
+    "env_files",
+    "env_scripts",
+    "env",
+    "vars",
+    "plugins",
+    "templates",
+    "tasks",
+];
+
+struct FormatState {
+    position: usize,
+    first_table: bool,
+}
+
+/// Returns only the comment lines of the decor, without any blank lines or indentation.
+fn get_comments(raw_string: Option<&RawString>) -> String {
+    raw_string
+        .and_then(|raw_string| raw_string.as_str())
+        .unwrap_or("")
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('#'))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+fn get_trailing_comment(raw_string: Option<&RawString>) -> String {
+    let comment = raw_string
+        .and_then(|raw_string| raw_string.as_str())
+        .unwrap_or("")
+        .trim();
+
+    if comment.starts_with('#') {
+        format!(" {}", comment)
+    } else {
+        "".to_string()
+    }
+}
+
+fn has_comments(raw_string: Option<&RawString>) -> bool {
+    raw_string
+        .and_then(|raw_string| raw_string.as_str())
+        .is_some_and(|value| value.contains('#'))
+}
+
+/// Env and vars blocks (including their profiles) are order sensitive and are not sorted.
+fn is_sorted(path: &[String]) -> bool {
+    let keys = if path
+        .first()
+        .is_some_and(|key| key == "tasks" || key == "templates")
+    {
+        if path.len() < 2 {
+            return false;
+        }
+
+        &path[2..]
+    } else {
+        path
+    };
+
+    !keys.iter().any(|key| key == "env" || key == "vars")
+}
+
+fn get_root_rank(key: &Key) -> usize {
+    ROOT_KEYS
+        .iter()
+        .position(|root_key| *root_key == key.get())
+        .unwrap_or(ROOT_KEYS.len())
+}
+
+fn get_category(item: &Item) -> String {
+    item.as_table_like()
+        .and_then(|task| task.get("category"))
+        .and_then(|category| category.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+fn compare_keys(path: &[String], key1: &Key, item1: &Item, key2: &Key, item2: &Item) -> Ordering {
+    if path.is_empty() {
+        get_root_rank(key1)
+            .cmp(&get_root_rank(key2))
+            .then_with(|| key1.get().cmp(key2.get()))
+    } else if path.len() == 1 && path[0] == "tasks" {
+        // tasks are grouped by category and keep their order within the category
+        get_category(item1).cmp(&get_category(item2))
+    } else {
+        key1.get().cmp(key2.get())
+    }
+}
+
+/// Arrays are written in a single line, unless they are too long or contain multi line
+/// values in which case (for top level arrays only) each value is written in its own line.
+fn format_array(array: &mut Array, path: &[String], top_level: bool) {
+    for value in array.iter_mut() {
+        format_value(value, path, false);
+    }
+
+    let commented = has_comments(Some(array.trailing()))
+        || array.iter().any(|value| {
+            has_comments(value.decor().prefix()) || has_comments(value.decor().suffix())
+        });
+    if commented {
+        // comments inside arrays are kept as is
+        return;
+    }
+
+    array.fmt();
+
+    let key_length = path.last().map_or(0, |key| key.len());
+    let line = array.to_string();
+    let line_length = key_length + " = ".len() + line.trim().len();
+    if top_level && (line.contains('\n') || line_length > MAX_LINE_LENGTH) {
+        for value in array.iter_mut() {
+            value.decor_mut().set_prefix("\n  ");
+            value.decor_mut().set_suffix("");
+        }
+        array.set_trailing_comma(true);
+        array.set_trailing("\n");
+    }
+}
+
+fn format_inline_table(table: &mut InlineTable, path: &[String]) {
+    if is_sorted(path) {
+        table.sort_values_by(|key1, _, key2, _| key1.get().cmp(key2.get()));
+    }
+
+    for (key, value) in table.iter_mut() {
+        let mut value_path = path.to_vec();
+        value_path.push(key.get().to_string());
+
+        format_value(value, &value_path, false);
+    }
+
+    table.fmt();
+}
+
+fn format_value(value: &mut Value, path: &[String], top_level: bool) {
+    match value {
+        // multi line strings (mostly scripts) are kept as is
+        Value::String(formatted) if !formatted.value().contains('\n') => formatted.fmt(),
+        Value::Array(array) => format_array(array, path, top_level),
+        Value::InlineTable(table) => format_inline_table(table, path),
+        _ => (),
+    }
+}
+
+fn format_table(table: &mut Table, path: &[String], state: &mut FormatState) {
+    if !table.is_dotted() {
+        table.set_position(state.position);
+        state.position += 1;
+
+        if !path.is_empty() && !table.is_implicit() {
+            let comments = get_comments(table.decor().prefix());
+            let prefix = if state.first_table {
+                comments
+            } else {
+                format!("\n{}", comments)
+            };
+            table.decor_mut().set_prefix(prefix);
+            state.first_table = false;
+        }
+    }
+
+    if is_sorted(path) || path == ["tasks"] {
+        table.sort_values_by(|key1, item1, key2, item2| {
+            compare_keys(path, key1, item1, key2, item2)
+        });
+    }
+
+    for (mut key, item) in table.iter_mut() {
+        let mut item_path = path.to_vec();
+        item_path.push(key.get().to_string());
+
+        match item {
+            Item::Value(value) => {
+                let comments = get_comments(key.leaf_decor().prefix());
+                key.leaf_decor_mut().set_prefix(comments);
+                key.leaf_decor_mut().set_suffix(" ");
+
+                let trailing_comment = get_trailing_comment(value.decor().suffix());
+                value.decor_mut().set_prefix(" ");
+                value.decor_mut().set_suffix(trailing_comment);
+
+                format_value(value, &item_path, true);
+            }
+            Item::Table(sub_table) => format_table(sub_table, &item_path, state),
+            Item::ArrayOfTables(tables) => {
+                for sub_table in tables.iter_mut() {
+                    format_table(sub_table, &item_path, state);
+                }
+            }
+            Item::None => (),
+        }
+    }
+}
+
+/// Returns the makefile content in the canonical layout.
+pub(crate) fn format(content: &str) -> Result<String, CargoMakeError> {
+    let mut document: DocumentMut = match content.parse() {
+        Ok(document) => document,
+        Err(error) => {
+            return Err(CargoMakeError::DescriptorParseFailed(error.to_string()));
+        }
+    };
+
+    let root = document.as_table_mut();
+    let mut state = FormatState {
+        position: 0,
+        first_table: !root.iter().any(|(_, item)| item.is_value()),
+    };
+    format_table(root, &[], &mut state);
+
+    let trailing = get_comments(Some(document.trailing()));
+    if trailing.is_empty() {
+        document.set_trailing("");
+    } else {
+        document.set_trailing(format!("\n{}", trailing));
+    }
+
+    let formatted = document.to_string();
+
+    // ensure the formatting did not modify the makefile content
+    let original_value: Result<toml::Value, _> = toml::from_str(content);
+    let formatted_value: Result<toml::Value, _> = toml::from_str(&formatted);
+    match (original_value, formatted_value) {
+        (Ok(original_value), Ok(formatted_value)) if original_value == formatted_value => {
+            Ok(formatted)
+        }
+        _ => Err(CargoMakeError::DescriptorParseFailed(
+            "Formatting modified the makefile content.".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn run(build_file: &str, check: bool) -> Result<(), CargoMakeError> {
+    let file_path = PathBuf::from(build_file);
+    if !file_path.is_file() {
+        return Err(CargoMakeError::NotFound(format!(
+            "Descriptor file: {:#?} not found.",
+            &file_path
+        )));
+    }
+
+    if DescriptorFormat::from_file(build_file) != DescriptorFormat::Toml {
+        return Err(CargoMakeError::ParseFileFailed(
+            build_file.to_string(),
+            "Only TOML makefiles can be formatted.".to_string(),
+        ));
+    }
+
+    let content = io::read_text_file(&file_path)?;
+    let formatted = match format(&content) {
+        Ok(formatted) => formatted,
+        Err(error) => {
+            return Err(CargoMakeError::ParseFileFailed(
+                build_file.to_string(),
+                error.to_string(),
+            ));
+        }
+    };
+
+    if formatted == content {
+        info!("Makefile: {} is formatted.", build_file);
+        Ok(())
+    } else if check {
+        Err(CargoMakeError::NotFormatted(build_file.to_string()))
+    } else {
+        io::write_text_file(build_file, &formatted);
+        info!("Formatted makefile: {}", build_file);
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+use super::*;
+use crate::test::get_temp_test_directory;
+
+fn write_makefile(subdir: &str, content: &str) -> String {
+    let directory = get_temp_test_directory(subdir);
+    let file = directory.join("Makefile.toml");
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(&file, content);
+
+    file
+}
+
+#[test]
+fn is_sorted_paths() {
+    let to_path =
+        |keys: &[&str]| -> Vec<String> { keys.iter().map(|key| key.to_string()).collect() };
+
+    assert!(is_sorted(&to_path(&[])));
+    assert!(is_sorted(&to_path(&["config"])));
+    assert!(!is_sorted(&to_path(&["env"])));
+    assert!(!is_sorted(&to_path(&["env", "production"])));
+    assert!(!is_sorted(&to_path(&["vars"])));
+    assert!(!is_sorted(&to_path(&["tasks"])));
+    assert!(is_sorted(&to_path(&["tasks", "env"])));
+    assert!(!is_sorted(&to_path(&["tasks", "build", "env"])));
+    assert!(!is_sorted(&to_path(&["tasks", "build", "linux", "env"])));
+    assert!(is_sorted(&to_path(&["tasks", "build", "condition"])));
+}
+
+#[test]
+fn format_sorts_keys_and_sections() {
+    let formatted = format(
+        r#"
+[tasks.build]
+command = "cargo"
+args = ["build"]
+description = "Build"
+
+[config]
+time_summary = true
+
+skip_core_tasks = true
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        formatted,
+        r#"[config]
+skip_core_tasks = true
+time_summary = true
+
+[tasks.build]
+args = ["build"]
+command = "cargo"
+description = "Build"
+"#
+    );
+}
+
+#[test]
+fn format_root_values_first() {
+    let formatted = format(
+        r#"env_files = ["./.env"]
+extend = "base.toml"
+
+[tasks.build]
+command = "cargo"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        formatted,
+        r#"extend = "base.toml"
+env_files = ["./.env"]
+
+[tasks.build]
+command = "cargo"
+"#
+    );
+}
+
+#[test]
+fn format_groups_tasks_by_category() {
+    let formatted = format(
+        r#"
+[tasks.test]
+category = "Test"
+command = "cargo"
+
+[tasks.build]
+category = "Build"
+command = "cargo"
+
+[tasks.default]
+command = "cargo"
+
+[tasks.build-release]
+category = "Build"
+command = "cargo"
+"#,
+    )
+    .unwrap();
+
+    let tasks: Vec<&str> = formatted
+        .lines()
+        .filter(|line| line.starts_with("[tasks."))
+        .collect();
+    assert_eq!(
+        tasks,
+        vec![
+            "[tasks.default]",
+            "[tasks.build]",
+            "[tasks.build-release]",
+            "[tasks.test]",
+        ]
+    );
+}
+
+#[test]
+fn format_keeps_env_order() {
+    let formatted = format(
+        r#"
+[env]
+B = "${A}"
+A = "1"
+
+[tasks.build.env]
+Z = "1"
+Y = "2"
+
+[tasks.build]
+env = { D = "1", C = "2" }
+"#,
+    );
+
+    // env as both a table and inline table is not valid toml
+    assert!(formatted.is_err());
+
+    let formatted = format(
+        r#"
+[env]
+B = "${A}"
+A = "1"
+
+[env.production]
+D = "1"
+C = "2"
+
+[tasks.build]
+env = { F = "1", E = "2" }
+condition = { platforms = ["linux"], channels = ["stable"] }
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        formatted,
+        r#"[env]
+B = "${A}"
+A = "1"
+
+[env.production]
+D = "1"
+C = "2"
+
+[tasks.build]
+condition = { channels = ["stable"], platforms = ["linux"] }
+env = { F = "1", E = "2" }
+"#
+    );
+}
+
+#[test]
+fn format_preserves_comments() {
+    let formatted = format(
+        r#"# header comment
+[tasks.build]
+# command comment
+command = "cargo"   # trailing comment
+args = [
+  "build", # array comment
+]
+# footer comment
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        formatted,
+        r#"# header comment
+[tasks.build]
+args = [
+  "build", # array comment
+]
+# command comment
+command = "cargo" # trailing comment
+
+# footer comment
+"#
+    );
+}
+
+#[test]
+fn format_string_and_array_style() {
+    let formatted = format(
+        r#"
+[tasks.build]
+command = 'cargo'
+args = [ "build",
+  "--release" ]
+dependencies = ["first-long-dependency-name", "second-long-dependency-name", "third-long-dependency-name"]
+script = '''
+echo 'hello'
+'''
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        formatted,
+        r#"[tasks.build]
+args = ["build", "--release"]
+command = "cargo"
+dependencies = [
+  "first-long-dependency-name",
+  "second-long-dependency-name",
+  "third-long-dependency-name",
+]
+script = '''
+echo 'hello'
+'''
+"#
+    );
+}
+
+#[test]
+fn format_idempotent() {
+    let formatted = format(
+        r#"
+# comment
+[tasks.build]
+category = "Build"
+command = "cargo"
+args = ["build"]
+
+[tasks.build.linux]
+args = ["build", "--features", "linux"]
+
+[env]
+A = "1"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(format(&formatted).unwrap(), formatted);
+}
+
+#[test]
+fn format_invalid() {
+    let result = format("[tasks.build");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn run_format_file() {
+    let file = write_makefile(
+        "fmt_run_format_file",
+        r#"[tasks.build]
+command = 'cargo'
+args = ['build']
+"#,
+    );
+
+    run(&file, false).unwrap();
+
+    let content = io::read_text_file(&PathBuf::from(&file)).unwrap();
+    assert_eq!(
+        content,
+        r#"[tasks.build]
+args = ["build"]
+command = "cargo"
+"#
+    );
+
+    run(&file, true).unwrap();
+}
+
+#[test]
+fn run_check_not_formatted() {
+    let original = r#"[tasks.build]
+command = 'cargo'
+"#;
+    let file = write_makefile("fmt_run_check_not_formatted", original);
+
+    let result = run(&file, true);
+
+    match result {
+        Err(CargoMakeError::NotFormatted(name)) => assert_eq!(name, file),
+        _ => panic!("check should fail"),
+    }
+
+    let content = io::read_text_file(&PathBuf::from(&file)).unwrap();
+    assert_eq!(content, original);
+}
+
+#[test]
+fn run_missing_file() {
+    let directory = get_temp_test_directory("fmt_run_missing_file");
+    let file = directory.join("Makefile.toml");
+
+    let result = run(file.to_str().unwrap(), false);
+
+    assert!(result.is_err());
+}
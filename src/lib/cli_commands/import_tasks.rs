@@ -0,0 +1,78 @@
+//! # import_tasks
+//!
+//! Converts the tasks of other task runners (justfiles, GNU Makefiles and package.json
+//! scripts) into a new makefile.
+//!
+
+#[cfg(test)]
+#[path = "import_tasks_test.rs"]
+mod import_tasks_test;
+
+use crate::cli_commands::fmt;
+use crate::descriptor::importer::{self, ImportFormat};
+use crate::error::CargoMakeError;
+use crate::io;
+use std::path::{Path, PathBuf};
+
+/// The default output makefile
+static DEFAULT_OUTPUT_FILE: &str = "Makefile.toml";
+
+/// Returns the TOML makefile content of the tasks imported from the provided file.
+pub(crate) fn get_makefile(file: &str) -> Result<String, CargoMakeError> {
+    let file_path = PathBuf::from(file);
+    if !file_path.is_file() {
+        return Err(CargoMakeError::NotFound(format!(
+            "File: {:#?} not found.",
+            &file_path
+        )));
+    }
+
+    let content = io::read_text_file(&file_path)?;
+    if ImportFormat::from_content(file, &content).is_none() {
+        return Err(CargoMakeError::ImportFailed(
+            file.to_string(),
+            "only justfiles, GNU Makefiles and package.json files can be imported".to_string(),
+        ));
+    }
+
+    let config = importer::import(&content, file)?;
+
+    let makefile = match toml::to_string(&config) {
+        Ok(makefile) => makefile,
+        Err(error) => {
+            return Err(CargoMakeError::ImportFailed(
+                file.to_string(),
+                error.to_string(),
+            ))
+        }
+    };
+    let file_name = file_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    Ok(format!(
+        "# Imported from {} by cargo make --import\n\n{}",
+        file_name,
+        fmt::format(&makefile)?
+    ))
+}
+
+pub(crate) fn run(file: &str, output_file: &Option<String>) -> Result<(), CargoMakeError> {
+    let output_file = output_file
+        .clone()
+        .unwrap_or(DEFAULT_OUTPUT_FILE.to_string());
+
+    if Path::new(&output_file).exists() {
+        return Err(CargoMakeError::ImportFailed(
+            file.to_string(),
+            format!("output file: {} already exists", &output_file),
+        ));
+    }
+
+    let makefile = get_makefile(file)?;
+    io::write_text_file(&output_file, &makefile);
+    info!("Imported tasks from: {} into: {}", file, &output_file);
+
+    Ok(())
+}
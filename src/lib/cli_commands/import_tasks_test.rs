@@ -0,0 +1,81 @@
+use super::*;
+use crate::test::get_temp_test_directory;
+use crate::types::ExternalConfig;
+
+fn write_file(subdir: &str, file_name: &str, content: &str) -> String {
+    let directory = get_temp_test_directory(subdir);
+    let file = directory.join(file_name);
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(&file, content);
+
+    file
+}
+
+#[test]
+fn get_makefile_justfile() {
+    let file = write_file(
+        "import_tasks_get_makefile_justfile",
+        "justfile",
+        "mode := 'debug'\n\n# Builds the app\nbuild: fmt\n    cargo build --{{mode}}\n\nfmt:\n    cargo fmt\n",
+    );
+
+    let makefile = get_makefile(&file).unwrap();
+
+    assert!(makefile.starts_with("# Imported from justfile by cargo make --import\n\n"));
+    let config: ExternalConfig = toml::from_str(&makefile).unwrap();
+    assert_eq!(config.env.unwrap().len(), 1);
+    let tasks = config.tasks.unwrap();
+    assert_eq!(
+        tasks.get("build").unwrap().description,
+        Some("Builds the app".to_string())
+    );
+    assert!(tasks.contains_key("fmt"));
+}
+
+#[test]
+fn get_makefile_unsupported_file() {
+    let file = write_file(
+        "import_tasks_get_makefile_unsupported_file",
+        "Makefile.toml",
+        "[tasks.build]\n",
+    );
+
+    let result = get_makefile(&file);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_makefile_missing_file() {
+    let directory = get_temp_test_directory("import_tasks_get_makefile_missing_file");
+    let file = directory.join("justfile");
+
+    let result = get_makefile(file.to_str().unwrap());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn run_writes_output_file() {
+    let file = write_file(
+        "import_tasks_run_writes_output_file",
+        "Makefile",
+        "build:\n\tcargo build\n",
+    );
+    let output_file = PathBuf::from(&file)
+        .parent()
+        .unwrap()
+        .join("Imported.toml")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    run(&file, &Some(output_file.clone())).unwrap();
+
+    let content = io::read_text_file(&PathBuf::from(&output_file)).unwrap();
+    assert!(content.contains("[tasks.build]"));
+
+    // existing files are not overwritten
+    let result = run(&file, &Some(output_file));
+    assert!(result.is_err());
+}
@@ -0,0 +1,700 @@
+//! # lint
+//!
+//! Statically validates the makefile and all the makefiles it extends without
+//! running any task.<br>
+//! Every finding is reported with the file and line it was found in and the lint
+//! fails if any finding was reported, so it can be used as a CI gate.<br>
+//! The makefiles are merged without invoking their load scripts and without evaluating the
+//! env, so the checks on the merged makefiles are done even if the env contains cycles.<br>
+//! The lint is read only, so makefiles extended from git repositories or crates are not
+//! fetched (and the lock files are not updated) and are therefore not validated.
+//!
+
+#[cfg(test)]
+#[path = "lint_test.rs"]
+mod lint_test;
+
+use crate::descriptor;
+use crate::descriptor::descriptor_deserializer;
+use crate::descriptor::descriptor_deserializer::DescriptorFormat;
+use crate::descriptor::env::merge_env;
+use crate::descriptor::importer::{self, ImportFormat};
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::{
+    Config, ConfigSection, DependencyIdentifier, DeprecationInfo, EnvValue, Extend, ExtendOptions,
+    ExternalConfig, RunTaskInfo, RunTaskName, Task,
+};
+use fsio::path::from_path::FromPath;
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+/// A single lint finding
+pub(crate) struct LintFinding {
+    /// The makefile the finding was found in
+    pub(crate) file: String,
+    /// The line (1 based) of the finding, if it could be located
+    pub(crate) line: Option<usize>,
+    /// The rule name
+    pub(crate) rule: &'static str,
+    /// The finding description
+    pub(crate) message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(formatter, "{}:{}", &self.file, line)?,
+            None => write!(formatter, "{}", &self.file)?,
+        };
+
+        write!(formatter, ": [{}] {}", self.rule, &self.message)
+    }
+}
+
+#[derive(Debug)]
+struct LintFile {
+    path: String,
+    content: String,
+    format: DescriptorFormat,
+    config: Option<ExternalConfig>,
+}
+
+impl LintFile {
+    /// Returns the line of the provided key path (only TOML makefiles are supported).
+    fn get_line(&self, keys: &[&str]) -> Option<usize> {
+        if self.format == DescriptorFormat::Toml {
+            find_line(&self.content, keys)
+        } else {
+            None
+        }
+    }
+
+    fn create_finding(&self, keys: &[&str], rule: &'static str, message: String) -> LintFinding {
+        LintFinding {
+            file: self.path.clone(),
+            line: self.get_line(keys),
+            rule,
+            message,
+        }
+    }
+}
+
+/// Returns the key path of a table header line, for example `[tasks."build"]`
+/// returns `tasks.build`.
+fn get_header(line: &str) -> Option<String> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with('[') {
+        return None;
+    }
+
+    let end = trimmed.find(']')?;
+    let header = trimmed[..end].trim_start_matches('[');
+    let keys: Vec<&str> = header
+        .split('.')
+        .map(|key| key.trim().trim_matches('"').trim_matches('\''))
+        .collect();
+
+    Some(keys.join("."))
+}
+
+fn is_key_line(line: &str, key: &str) -> bool {
+    let trimmed = line.trim_start();
+
+    [
+        key.to_string(),
+        format!("\"{}\"", key),
+        format!("'{}'", key),
+    ]
+    .iter()
+    .any(|prefix| match trimmed.strip_prefix(prefix.as_str()) {
+        Some(rest) => {
+            let rest = rest.trim_start();
+            rest.starts_with('=') || rest.starts_with('.')
+        }
+        None => false,
+    })
+}
+
+/// Returns the line (1 based) in which the provided key path is defined in the TOML makefile.<br>
+/// If the full key path can't be found, the closest defined parent is returned.
+fn find_line(content: &str, keys: &[&str]) -> Option<usize> {
+    for length in (1..=keys.len()).rev() {
+        let path = keys[..length].join(".");
+        let parent = keys[..length - 1].join(".");
+        let key = keys[length - 1];
+
+        let mut current_header = String::new();
+        for (index, line) in content.lines().enumerate() {
+            match get_header(line) {
+                Some(header) => {
+                    if header == path {
+                        return Some(index + 1);
+                    }
+
+                    current_header = header;
+                }
+                None => {
+                    if current_header == parent && is_key_line(line, key) {
+                        return Some(index + 1);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn is_remote_extend(extend_options: &ExtendOptions) -> bool {
+    extend_options.git.is_some() || extend_options.crate_name.is_some()
+}
+
+fn has_remote_extend(file: &LintFile) -> bool {
+    match file
+        .config
+        .as_ref()
+        .and_then(|config| config.extend.as_ref())
+    {
+        Some(Extend::Options(extend_options)) => is_remote_extend(extend_options),
+        Some(Extend::List(extend_list)) => extend_list.iter().any(is_remote_extend),
+        _ => false,
+    }
+}
+
+/// Returns the extend definition without the git and crate extends, as resolving them
+/// fetches the makefiles and updates the lock files.
+fn get_local_extend(extend: Extend) -> Extend {
+    match extend {
+        Extend::Options(extend_options) if is_remote_extend(&extend_options) => {
+            Extend::List(vec![])
+        }
+        Extend::List(extend_list) => Extend::List(
+            extend_list
+                .into_iter()
+                .filter(|extend_options| !is_remote_extend(extend_options))
+                .collect(),
+        ),
+        _ => extend,
+    }
+}
+
+fn load_file(file_path: &Path, files: &mut Vec<LintFile>, findings: &mut Vec<LintFinding>) {
+    let path: String = FromPath::from_path(file_path);
+    let absolute_path = io::canonicalize_to_string(&path);
+    if files.iter().any(|file| file.path == absolute_path) {
+        return;
+    }
+
+    let content = match io::read_text_file(&file_path.to_path_buf()) {
+        Ok(content) => content,
+        Err(error) => {
+            findings.push(LintFinding {
+                file: path,
+                line: None,
+                rule: "missing-makefile",
+                message: format!("Unable to read makefile: {}", error),
+            });
+            return;
+        }
+    };
+
+    let mut file = LintFile {
+        path: absolute_path.clone(),
+        content,
+        format: DescriptorFormat::from_file(&absolute_path),
+        config: None,
+    };
+
+    if ImportFormat::from_content(&absolute_path, &file.content).is_some() {
+        match importer::import(&file.content, &absolute_path) {
+            Ok(config) => file.config = Some(config),
+            Err(error) => findings.push(LintFinding {
+                file: absolute_path.clone(),
+                line: None,
+                rule: "parse-error",
+                message: format!("Unable to import tasks: {}", error),
+            }),
+        };
+    } else {
+        match descriptor_deserializer::load_external_config_with_unknown_keys(
+            &file.content,
+            file.format,
+        ) {
+            Ok((config, unknown_keys)) => {
+                for keys in unknown_keys {
+                    let keys: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+                    findings.push(file.create_finding(
+                        &keys,
+                        "unknown-key",
+                        format!("Unknown key: {}", keys.join(".")),
+                    ));
+                }
+
+                file.config = Some(config);
+            }
+            Err(error) => {
+                findings.push(LintFinding {
+                    file: absolute_path.clone(),
+                    line: error.line,
+                    rule: "parse-error",
+                    message: format!("Unable to parse makefile: {}", error.message),
+                });
+            }
+        };
+    }
+
+    let extend = file
+        .config
+        .as_ref()
+        .and_then(|config| config.extend.clone());
+    files.push(file);
+
+    if let Some(extend) = extend {
+        let parent_path: String = match Path::new(&absolute_path).parent() {
+            Some(directory) => FromPath::from_path(directory),
+            None => ".".to_string(),
+        };
+
+        let extend = get_local_extend(extend);
+        for extended_file in descriptor::get_extended_makefiles(&parent_path, &extend) {
+            load_file(&extended_file, files, findings);
+        }
+    }
+}
+
+fn lint_env(
+    file: &LintFile,
+    env: &Option<IndexMap<String, EnvValue>>,
+    keys: &[&str],
+    findings: &mut Vec<LintFinding>,
+) {
+    if let Some(ref env) = env {
+        if let Err(error) = merge_env(env, &IndexMap::new()) {
+            findings.push(file.create_finding(keys, "env-cycle", error.to_string()));
+        }
+    }
+}
+
+fn lint_run_task(
+    file: &LintFile,
+    task_name: &str,
+    run_task: &Option<RunTaskInfo>,
+    findings: &mut Vec<LintFinding>,
+) {
+    let cleanup_without_fork = match run_task {
+        Some(RunTaskInfo::Details(details)) => {
+            details.cleanup_task.is_some() && !details.fork.unwrap_or(false)
+        }
+        Some(RunTaskInfo::Routing(routing_info)) => routing_info
+            .iter()
+            .any(|info| info.cleanup_task.is_some() && !info.fork.unwrap_or(false)),
+        _ => false,
+    };
+
+    if cleanup_without_fork {
+        findings.push(file.create_finding(
+            &["tasks", task_name, "run_task"],
+            "cleanup-without-fork",
+            format!(
+                "Task: {} defines a run_task cleanup_task without fork, the cleanup task will never be invoked.",
+                task_name
+            ),
+        ));
+    }
+}
+
+fn lint_file(file: &LintFile, findings: &mut Vec<LintFinding>) {
+    let config = match file.config {
+        Some(ref config) => config,
+        None => return,
+    };
+
+    lint_env(file, &config.env, &["env"], findings);
+
+    if let Some(ref tasks) = config.tasks {
+        for (name, task) in tasks {
+            if !task.is_valid() {
+                findings.push(file.create_finding(
+                    &["tasks", name],
+                    "multiple-actions",
+                    format!(
+                        "Task: {} defines more than one action (command, script, run_task, install_crate, ...).",
+                        name
+                    ),
+                ));
+            }
+
+            if task.force.is_some() {
+                findings.push(file.create_finding(
+                    &["tasks", name, "force"],
+                    "deprecated-attribute",
+                    format!(
+                        "Task: {} uses the deprecated attribute 'force', use 'ignore_errors' instead.",
+                        name
+                    ),
+                ));
+            }
+
+            lint_env(file, &task.env, &["tasks", name, "env"], findings);
+            lint_run_task(file, name, &task.run_task, findings);
+
+            let platform_tasks = [
+                ("linux", &task.linux),
+                ("windows", &task.windows),
+                ("mac", &task.mac),
+            ];
+            for (platform, platform_task) in platform_tasks {
+                if let Some(platform_task) = platform_task {
+                    if platform_task.force.is_some() {
+                        findings.push(file.create_finding(
+                            &["tasks", name, platform, "force"],
+                            "deprecated-attribute",
+                            format!(
+                                "Task: {} {} override uses the deprecated attribute 'force', use 'ignore_errors' instead.",
+                                name, platform
+                            ),
+                        ));
+                    }
+
+                    lint_env(
+                        file,
+                        &platform_task.env,
+                        &["tasks", name, platform, "env"],
+                        findings,
+                    );
+                    lint_run_task(file, name, &platform_task.run_task, findings);
+                }
+            }
+        }
+    }
+}
+
+fn add_run_task_references(
+    run_task: &Option<RunTaskInfo>,
+    references: &mut Vec<(&'static str, String)>,
+) {
+    let mut add_names = |name: &RunTaskName, cleanup_task: &Option<String>| {
+        match name {
+            RunTaskName::Single(name) => references.push(("run_task", name.clone())),
+            RunTaskName::Multiple(names) => {
+                for name in names {
+                    references.push(("run_task", name.clone()));
+                }
+            }
+        };
+
+        if let Some(cleanup_task) = cleanup_task {
+            references.push(("run_task", cleanup_task.clone()));
+        }
+    };
+
+    match run_task {
+        Some(RunTaskInfo::Name(name)) => add_names(&RunTaskName::Single(name.clone()), &None),
+        Some(RunTaskInfo::Details(details)) => add_names(&details.name, &details.cleanup_task),
+        Some(RunTaskInfo::Routing(routing_info)) => {
+            for info in routing_info {
+                add_names(&info.name, &info.cleanup_task);
+            }
+        }
+        None => (),
+    }
+}
+
+fn add_dependency_references(
+    dependencies: &Option<Vec<DependencyIdentifier>>,
+    references: &mut Vec<(&'static str, String)>,
+) {
+    if let Some(dependencies) = dependencies {
+        for dependency in dependencies {
+            // dependencies in other makefiles are not validated
+            let external = match dependency {
+                DependencyIdentifier::Definition(identifier) => identifier.path.is_some(),
+                DependencyIdentifier::Name(_) => false,
+            };
+
+            if !external {
+                references.push(("dependencies", dependency.name().to_string()));
+            }
+        }
+    }
+}
+
+/// Returns all the task names referenced by the task, together with the referencing attribute.
+fn get_task_references(task: &Task) -> Vec<(&'static str, String)> {
+    let mut references = vec![];
+
+    add_dependency_references(&task.dependencies, &mut references);
+    add_run_task_references(&task.run_task, &mut references);
+
+    let aliases = [
+        ("alias", &task.alias),
+        ("linux_alias", &task.linux_alias),
+        ("windows_alias", &task.windows_alias),
+        ("mac_alias", &task.mac_alias),
+    ];
+    for (attribute, alias) in aliases {
+        if let Some(alias) = alias {
+            references.push((attribute, alias.clone()));
+        }
+    }
+
+    for platform_task in [&task.linux, &task.windows, &task.mac]
+        .into_iter()
+        .flatten()
+    {
+        add_dependency_references(&platform_task.dependencies, &mut references);
+        add_run_task_references(&platform_task.run_task, &mut references);
+    }
+
+    references
+}
+
+fn get_config_task_references(config: &Config) -> Vec<(&'static str, String)> {
+    let config_tasks = [
+        ("init_task", &config.config.init_task),
+        ("end_task", &config.config.end_task),
+        ("on_error_task", &config.config.on_error_task),
+        (
+            "legacy_migration_task",
+            &config.config.legacy_migration_task,
+        ),
+    ];
+
+    config_tasks
+        .iter()
+        .filter_map(|(attribute, name)| name.as_ref().map(|name| (*attribute, name.clone())))
+        .collect()
+}
+
+fn get_deprecation_message(config: &Config, name: &str) -> Option<String> {
+    match config
+        .tasks
+        .get(name)
+        .and_then(|task| task.deprecated.as_ref())
+    {
+        Some(DeprecationInfo::Boolean(true)) => Some("".to_string()),
+        Some(DeprecationInfo::Message(message)) => Some(format!(" - {}", message)),
+        _ => None,
+    }
+}
+
+fn lint_config(config: &Config, files: &[LintFile], findings: &mut Vec<LintFinding>) {
+    // the makefile which defines each task, the top level makefile overrides the extended ones
+    let mut task_files: IndexMap<String, &LintFile> = IndexMap::new();
+    for file in files {
+        if let Some(tasks) = file
+            .config
+            .as_ref()
+            .and_then(|config| config.tasks.as_ref())
+        {
+            for name in tasks.keys() {
+                if !task_files.contains_key(name) {
+                    task_files.insert(name.clone(), file);
+                }
+            }
+        }
+    }
+
+    let mut referenced = IndexSet::new();
+    for task in config.tasks.values() {
+        for (_, name) in get_task_references(task) {
+            referenced.insert(name);
+        }
+
+        if let Some(ref extend) = task.extend {
+            referenced.insert(extend.clone());
+        }
+    }
+
+    for (attribute, name) in get_config_task_references(config) {
+        if !config.tasks.contains_key(&name) {
+            let file = files.iter().find(|file| match file.config {
+                Some(ref external_config) => external_config
+                    .config
+                    .as_ref()
+                    .is_some_and(|section| is_config_task_defined(section, attribute)),
+                None => false,
+            });
+
+            if let Some(file) = file {
+                findings.push(file.create_finding(
+                    &["config", attribute],
+                    "undefined-task",
+                    format!("Config {} task: {} is not defined.", attribute, name),
+                ));
+            }
+        } else if let Some(message) = get_deprecation_message(config, &name) {
+            if let Some(file) = files.first() {
+                findings.push(file.create_finding(
+                    &["config", attribute],
+                    "deprecated-task",
+                    format!(
+                        "Config {} task: {} is deprecated{}",
+                        attribute, name, message
+                    ),
+                ));
+            }
+        }
+
+        referenced.insert(name);
+    }
+
+    for (name, file) in task_files {
+        let task = match config.tasks.get(&name) {
+            Some(task) => task,
+            None => continue,
+        };
+
+        for (attribute, reference) in get_task_references(task) {
+            if !config.tasks.contains_key(&reference) {
+                findings.push(file.create_finding(
+                    &["tasks", &name, attribute],
+                    "undefined-task",
+                    format!(
+                        "Task: {} {} task: {} is not defined.",
+                        name, attribute, reference
+                    ),
+                ));
+            } else if let Some(message) = get_deprecation_message(config, &reference) {
+                findings.push(file.create_finding(
+                    &["tasks", &name, attribute],
+                    "deprecated-task",
+                    format!(
+                        "Task: {} {} task: {} is deprecated{}",
+                        name, attribute, reference, message
+                    ),
+                ));
+            }
+        }
+
+        if let Some(ref extend) = task.extend {
+            if !config.tasks.contains_key(extend) {
+                findings.push(file.create_finding(
+                    &["tasks", &name, "extend"],
+                    "undefined-extend",
+                    format!("Task: {} extends an undefined task: {}", name, extend),
+                ));
+            }
+        }
+
+        if task.private.unwrap_or(false) && !referenced.contains(&name) {
+            findings.push(file.create_finding(
+                &["tasks", &name],
+                "unreachable-private-task",
+                format!(
+                    "Private task: {} is not referenced by any other task and can never be invoked.",
+                    name
+                ),
+            ));
+        }
+    }
+}
+
+fn is_config_task_defined(section: &ConfigSection, attribute: &str) -> bool {
+    match attribute {
+        "init_task" => section.init_task.is_some(),
+        "end_task" => section.end_task.is_some(),
+        "on_error_task" => section.on_error_task.is_some(),
+        "legacy_migration_task" => section.legacy_migration_task.is_some(),
+        _ => false,
+    }
+}
+
+/// Lints the makefile and all the makefiles it extends and returns all the findings.
+pub(crate) fn lint(build_file: &str, force: bool, experimental: bool) -> Vec<LintFinding> {
+    let mut files = vec![];
+    let mut findings = vec![];
+
+    let file_path = Path::new(build_file);
+    if file_path.is_file() {
+        load_file(file_path, &mut files, &mut findings);
+    } else if force {
+        findings.push(LintFinding {
+            file: build_file.to_string(),
+            line: None,
+            rule: "missing-makefile",
+            message: "Makefile not found.".to_string(),
+        });
+    }
+
+    for file in &files {
+        lint_file(file, &mut findings);
+    }
+
+    let mut remote_extends = false;
+    for file in files.iter().filter(|file| has_remote_extend(file)) {
+        warn!(
+            "Skipping the git and crate makefiles extended by: {}",
+            &file.path
+        );
+        remote_extends = true;
+    }
+
+    // the merged config can't be loaded if any of the makefiles is missing or broken
+    let loadable = findings
+        .iter()
+        .all(|finding| finding.rule != "parse-error" && finding.rule != "missing-makefile");
+    if remote_extends {
+        warn!("Skipping the merged makefile checks as not all extended makefiles are available.");
+    } else if loadable {
+        // the makefiles are merged without running their load scripts and without evaluating
+        // the env, so env cycles do not prevent the merged checks
+        match descriptor::load_static(build_file, force, experimental) {
+            Ok(config) => {
+                // env cycles between the makefiles, the ones within a makefile are already reported
+                if !findings.iter().any(|finding| finding.rule == "env-cycle") {
+                    if let (Err(error), Some(file)) =
+                        (merge_env(&config.env, &IndexMap::new()), files.first())
+                    {
+                        findings.push(file.create_finding(
+                            &["env"],
+                            "env-cycle",
+                            error.to_string(),
+                        ));
+                    }
+                }
+
+                lint_config(&config, &files, &mut findings);
+            }
+            Err(error) => findings.push(LintFinding {
+                file: build_file.to_string(),
+                line: None,
+                rule: "load-error",
+                message: error.to_string(),
+            }),
+        }
+    }
+
+    findings
+}
+
+pub(crate) fn run(
+    build_file: &str,
+    force: bool,
+    experimental: bool,
+    output_file: &Option<String>,
+) -> Result<(), CargoMakeError> {
+    let findings = lint(build_file, force, experimental);
+
+    let mut output = String::new();
+    for finding in &findings {
+        output.push_str(&format!("{}\n", finding));
+    }
+
+    match output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    if findings.is_empty() {
+        info!("No lint findings.");
+        Ok(())
+    } else {
+        Err(CargoMakeError::LintFailed(findings.len()))
+    }
+}
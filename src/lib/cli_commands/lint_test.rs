@@ -0,0 +1,442 @@
+use super::*;
+use crate::test::get_temp_test_directory;
+use std::path::PathBuf;
+
+fn write_makefile(directory: &PathBuf, name: &str, content: &str) -> String {
+    let file = directory.join(name);
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(&file, content);
+
+    file
+}
+
+fn lint_makefile(subdir: &str, content: &str) -> Vec<LintFinding> {
+    let directory = get_temp_test_directory(subdir);
+    let file = write_makefile(&directory, "Makefile.toml", content);
+
+    lint(&file, true, false)
+}
+
+fn get_rules(findings: &[LintFinding]) -> Vec<(&'static str, Option<usize>)> {
+    findings
+        .iter()
+        .map(|finding| (finding.rule, finding.line))
+        .collect()
+}
+
+#[test]
+fn get_header_variations() {
+    assert_eq!(get_header("[tasks.build]"), Some("tasks.build".to_string()));
+    assert_eq!(
+        get_header("  [ tasks . \"build.x\" ] # comment"),
+        Some("tasks.build.x".to_string())
+    );
+    assert_eq!(
+        get_header("[[tasks.build.list]]"),
+        Some("tasks.build.list".to_string())
+    );
+    assert_eq!(get_header("command = \"[echo]\""), None);
+}
+
+#[test]
+fn find_line_variations() {
+    let content = r#"extend = "base.toml"
+
+[env]
+A = "1"
+
+[tasks.build]
+command = "cargo"
+"force" = true
+
+[tasks.build.env]
+B = "2"
+"#;
+
+    assert_eq!(find_line(content, &["extend"]), Some(1));
+    assert_eq!(find_line(content, &["env"]), Some(3));
+    assert_eq!(find_line(content, &["env", "A"]), Some(4));
+    assert_eq!(find_line(content, &["tasks", "build"]), Some(6));
+    assert_eq!(find_line(content, &["tasks", "build", "force"]), Some(8));
+    assert_eq!(find_line(content, &["tasks", "build", "env"]), Some(10));
+    assert_eq!(find_line(content, &["tasks", "build", "args"]), Some(6));
+    assert_eq!(find_line(content, &["tasks", "test"]), None);
+}
+
+#[test]
+fn lint_no_findings() {
+    let findings = lint_makefile(
+        "lint_no_findings",
+        r#"
+[tasks.build]
+command = "cargo"
+args = ["build"]
+dependencies = ["setup"]
+
+[tasks.setup]
+private = true
+script = "echo setup"
+"#,
+    );
+
+    assert!(findings.is_empty(), "{:#?}", findings);
+}
+
+#[test]
+fn lint_unknown_key() {
+    let findings = lint_makefile(
+        "lint_unknown_key",
+        r#"
+[tasks.build]
+command = "cargo"
+argz = ["build"]
+"#,
+    );
+
+    assert_eq!(get_rules(&findings), vec![("unknown-key", Some(4))]);
+    assert_eq!(findings[0].message, "Unknown key: tasks.build.argz");
+}
+
+#[test]
+fn lint_parse_error() {
+    let findings = lint_makefile(
+        "lint_parse_error",
+        r#"
+[tasks.build]
+command = "cargo
+"#,
+    );
+
+    assert_eq!(get_rules(&findings), vec![("parse-error", Some(3))]);
+}
+
+#[test]
+fn lint_task_findings() {
+    let findings = lint_makefile(
+        "lint_task_findings",
+        r#"
+[tasks.multiple]
+command = "cargo"
+script = "echo test"
+
+[tasks.deprecated]
+command = "cargo"
+force = true
+
+[tasks.cleanup]
+run_task = { name = "multiple", cleanup_task = "deprecated" }
+"#,
+    );
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![
+            ("multiple-actions", Some(2)),
+            ("deprecated-attribute", Some(8)),
+            ("cleanup-without-fork", Some(11)),
+        ]
+    );
+}
+
+#[test]
+fn lint_undefined_references() {
+    let findings = lint_makefile(
+        "lint_undefined_references",
+        r#"
+[tasks.build]
+command = "cargo"
+dependencies = ["missing-dependency"]
+
+[tasks.flow]
+run_task = "missing-run-task"
+
+[tasks.extended]
+extend = "missing-extend"
+
+[tasks.hidden]
+private = true
+command = "cargo"
+"#,
+    );
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![
+            ("undefined-task", Some(4)),
+            ("undefined-task", Some(7)),
+            ("undefined-extend", Some(10)),
+            ("unreachable-private-task", Some(12)),
+        ]
+    );
+    assert_eq!(
+        findings[0].message,
+        "Task: build dependencies task: missing-dependency is not defined."
+    );
+}
+
+#[test]
+fn lint_env_cycle() {
+    let findings = lint_makefile(
+        "lint_env_cycle",
+        r#"
+[env]
+A = "${B}"
+B = "${A}"
+"#,
+    );
+
+    assert_eq!(get_rules(&findings), vec![("env-cycle", Some(2))]);
+}
+
+#[test]
+fn lint_extended_makefile() {
+    let directory = get_temp_test_directory("lint_extended_makefile");
+    let base_file = write_makefile(
+        &directory,
+        "base.toml",
+        r#"
+[tasks.base]
+command = "cargo"
+force = true
+"#,
+    );
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        r#"
+extend = "base.toml"
+
+[tasks.build]
+dependencies = ["base"]
+"#,
+    );
+
+    let findings = lint(&file, true, false);
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![("deprecated-attribute", Some(4))]
+    );
+    assert_eq!(findings[0].file, io::canonicalize_to_string(&base_file));
+}
+
+#[test]
+fn lint_remote_extend_skipped() {
+    let directory = get_temp_test_directory("lint_remote_extend_skipped");
+    write_makefile(
+        &directory,
+        "base.toml",
+        r#"
+[tasks.base]
+command = "cargo"
+force = true
+"#,
+    );
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        r#"
+extend = [
+  { path = "base.toml" },
+  { git = "https://invalid.example.com/makefiles.git", path = "Makefile.toml" },
+  { crate = "invalid-makefiles-crate", path = "Makefile.toml" },
+]
+
+[tasks.build]
+dependencies = ["remote"]
+"#,
+    );
+
+    let findings = lint(&file, true, false);
+
+    // the merged makefile checks (such as undefined-task) are skipped
+    assert_eq!(
+        get_rules(&findings),
+        vec![("deprecated-attribute", Some(4))]
+    );
+    assert!(!directory.join("Makefile.lock").exists());
+}
+
+#[test]
+fn lint_missing_makefile() {
+    let directory = get_temp_test_directory("lint_missing_makefile");
+    let file = directory.join("Makefile.toml");
+
+    let findings = lint(file.to_str().unwrap(), true, false);
+
+    assert_eq!(get_rules(&findings), vec![("missing-makefile", None)]);
+}
+
+#[test]
+fn run_with_findings() {
+    let directory = get_temp_test_directory("lint_run_with_findings");
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        r#"
+[tasks.build]
+command = "cargo"
+argz = ["build"]
+"#,
+    );
+    let output_file = directory.join("lint.txt");
+    let output_file = output_file.to_str().unwrap().to_string();
+
+    let result = run(&file, true, false, &Some(output_file.clone()));
+
+    match result {
+        Err(CargoMakeError::LintFailed(count)) => assert_eq!(count, 1),
+        _ => panic!("lint should fail"),
+    }
+
+    let output = io::read_text_file(&PathBuf::from(&output_file)).unwrap();
+    assert_eq!(
+        output,
+        format!(
+            "{}:4: [unknown-key] Unknown key: tasks.build.argz\n",
+            io::canonicalize_to_string(&file)
+        )
+    );
+}
+
+#[test]
+fn run_no_findings() {
+    let directory = get_temp_test_directory("lint_run_no_findings");
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        r#"
+[tasks.build]
+command = "cargo"
+"#,
+    );
+
+    run(&file, true, false, &None).unwrap();
+}
+
+#[test]
+fn lint_yaml_makefile() {
+    let directory = get_temp_test_directory("lint_yaml_makefile");
+    let file = write_makefile(
+        &directory,
+        "Makefile.yaml",
+        r#"
+tasks:
+  build:
+    command: cargo
+    argz: ["build"]
+    dependencies: ["missing"]
+"#,
+    );
+
+    let findings = lint(&file, true, false);
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![("unknown-key", None), ("undefined-task", None)]
+    );
+}
+
+#[test]
+fn lint_env_cycle_with_undefined_references() {
+    let findings = lint_makefile(
+        "lint_env_cycle_with_undefined_references",
+        r#"
+[env]
+A = "${B}"
+B = "${A}"
+
+[tasks.build]
+command = "cargo"
+dependencies = ["missing-dependency"]
+"#,
+    );
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![("env-cycle", Some(2)), ("undefined-task", Some(8))]
+    );
+}
+
+#[test]
+fn lint_extended_makefile_env_cycle() {
+    let directory = get_temp_test_directory("lint_extended_makefile_env_cycle");
+    write_makefile(
+        &directory,
+        "base.toml",
+        r#"
+[env]
+A = "${B}"
+"#,
+    );
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        r#"
+extend = "base.toml"
+
+[env]
+B = "${A}"
+"#,
+    );
+
+    let findings = lint(&file, true, false);
+
+    assert_eq!(get_rules(&findings), vec![("env-cycle", Some(4))]);
+    assert_eq!(findings[0].file, io::canonicalize_to_string(&file));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn lint_load_script_not_invoked() {
+    let directory = get_temp_test_directory("lint_load_script_not_invoked");
+    let marker = directory.join("load_script_invoked");
+    let file = write_makefile(
+        &directory,
+        "Makefile.toml",
+        &format!(
+            r#"
+[config]
+load_script = "touch {}"
+"#,
+            marker.to_str().unwrap()
+        ),
+    );
+
+    let findings = lint(&file, true, false);
+
+    assert!(findings.is_empty());
+    assert!(!marker.exists());
+}
+
+#[test]
+fn lint_deprecated_findings() {
+    let findings = lint_makefile(
+        "lint_deprecated_findings",
+        r#"
+[tasks.old]
+command = "cargo"
+deprecated = "use new instead"
+
+[tasks.build]
+command = "cargo"
+dependencies = ["old"]
+
+[tasks.build.linux]
+command = "cargo"
+force = true
+"#,
+    );
+
+    assert_eq!(
+        get_rules(&findings),
+        vec![
+            ("deprecated-attribute", Some(12)),
+            ("deprecated-task", Some(8)),
+        ]
+    );
+    assert_eq!(
+        findings[1].message,
+        "Task: build dependencies task: old is deprecated - use new instead"
+    );
+}
@@ -0,0 +1,42 @@
+//! # list_profiles
+//!
+//! Lists all the profiles defined in the env and vars sections of the makefiles.
+//!
+
+#[cfg(test)]
+#[path = "list_profiles_test.rs"]
+mod list_profiles_test;
+
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::profile;
+use crate::types::{Config, EnvValue};
+use std::collections::BTreeSet;
+
+/// Returns the sorted profile names, including the default profile
+pub(crate) fn get_profiles(config: &Config) -> Vec<String> {
+    let mut profiles = BTreeSet::new();
+    profiles.insert(profile::default_profile());
+
+    for (key, value) in config.env.iter().chain(config.vars.iter()) {
+        if let EnvValue::Profile(_) = value {
+            profiles.insert(key.to_string());
+        }
+    }
+
+    profiles.into_iter().collect()
+}
+
+pub(crate) fn run(config: &Config, output_file: &Option<String>) -> Result<(), CargoMakeError> {
+    let mut output = get_profiles(config).join("\n");
+    output.push('\n');
+
+    match output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use super::*;
+use crate::types::ConfigSection;
+use indexmap::IndexMap;
+
+#[test]
+fn get_profiles_default_only() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::from([("TEST".to_string(), EnvValue::Value("1".to_string()))]),
+        vars: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let profiles = get_profiles(&config);
+
+    assert_eq!(profiles, vec!["development"]);
+}
+
+#[test]
+fn get_profiles_env_and_vars() {
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::from([
+            ("TEST".to_string(), EnvValue::Value("1".to_string())),
+            ("production".to_string(), EnvValue::Profile(IndexMap::new())),
+            ("ci".to_string(), EnvValue::Profile(IndexMap::new())),
+        ]),
+        vars: IndexMap::from([
+            ("ci".to_string(), EnvValue::Profile(IndexMap::new())),
+            ("release".to_string(), EnvValue::Profile(IndexMap::new())),
+        ]),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+    };
+
+    let profiles = get_profiles(&config);
+
+    assert_eq!(profiles, vec!["ci", "development", "production", "release"]);
+}
@@ -33,16 +33,22 @@ pub fn run(
     Ok(())
 }
 
-pub(crate) fn create_list(
+/// category -> actual_task -> description
+pub(crate) type CategoryTasks = BTreeMap<String, BTreeMap<String, String>>;
+/// actual_task -> aliases
+pub(crate) type TaskAliases = BTreeMap<String, BTreeSet<String>>;
+
+/// Returns the public tasks (and their description) grouped by category and the aliases
+/// of each task.
+pub(crate) fn get_task_list(
     config: &Config,
-    output_format: &str,
     category_filter: &Option<String>,
     hide_uninteresting: bool,
-) -> Result<String, CargoMakeError> {
+) -> Result<(CategoryTasks, TaskAliases), CargoMakeError> {
     // category -> actual_task -> description
-    let mut categories: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    let mut categories: CategoryTasks = BTreeMap::new();
     // actual_task -> aliases
-    let mut aliases: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut aliases: TaskAliases = BTreeMap::new();
 
     // iterate over all tasks to build categories and aliases
     for key in config.tasks.keys() {
@@ -123,6 +129,17 @@ pub(crate) fn create_list(
         }
     }
 
+    Ok((categories, aliases))
+}
+
+pub(crate) fn create_list(
+    config: &Config,
+    output_format: &str,
+    category_filter: &Option<String>,
+    hide_uninteresting: bool,
+) -> Result<String, CargoMakeError> {
+    let (categories, mut aliases) = get_task_list(config, category_filter, hide_uninteresting)?;
+
     // build the task list output string
     let single_page_markdown = output_format == "markdown-single-page";
     let markdown = single_page_markdown
@@ -52,6 +52,7 @@ fn run_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -77,6 +78,7 @@ fn run_all_public() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -118,6 +120,7 @@ fn run_all_public_hide_uninteresting() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -164,6 +167,7 @@ fn run_aliases() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -202,6 +206,7 @@ fn run_all_public_markdown() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -240,6 +245,7 @@ fn run_all_public_markdown_sub_section() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -278,6 +284,7 @@ fn run_all_public_markdown_single_page() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -320,6 +327,7 @@ fn run_all_private() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(&config, "default", &None, None, false, expect![[""]]);
@@ -354,6 +362,7 @@ fn run_mixed() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -393,6 +402,7 @@ fn run_write_to_file() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let file = "./target/_temp/tasklist.md";
@@ -440,6 +450,7 @@ fn run_category_public() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     check(
@@ -0,0 +1,433 @@
+//! # migrate
+//!
+//! Rewrites deprecated makefile content into its modern equivalent.<br>
+//! Deprecated task attributes are renamed and references to deprecated core tasks
+//! (dependencies, run_task, extend, alias and config tasks) are replaced with the task
+//! defined in their deprecation message. All other content and comments are kept as is.
+//!
+
+#[cfg(test)]
+#[path = "migrate_test.rs"]
+mod migrate_test;
+
+use crate::descriptor::descriptor_deserializer::{self, DescriptorFormat};
+use crate::descriptor::makefiles;
+use crate::error::CargoMakeError;
+use crate::io;
+use crate::types::DeprecationInfo;
+use indexmap::IndexMap;
+use std::path::PathBuf;
+use toml_edit::{DocumentMut, Item, Key, TableLike, Value};
+
+/// The deprecated task attributes and their replacement
+static DEPRECATED_ATTRIBUTES: [(&str, &str); 1] = [("force", "ignore_errors")];
+/// The deprecation message prefix of core tasks which were replaced by another task
+static REPLACED_TASK_PREFIX: &str = "Please use task: ";
+/// Task attributes which hold a single task name
+static TASK_NAME_ATTRIBUTES: [&str; 5] = [
+    "extend",
+    "alias",
+    "linux_alias",
+    "windows_alias",
+    "mac_alias",
+];
+/// Config attributes which hold a single task name
+static CONFIG_TASK_ATTRIBUTES: [&str; 4] = [
+    "init_task",
+    "end_task",
+    "on_error_task",
+    "legacy_migration_task",
+];
+static PLATFORMS: [&str; 3] = ["linux", "windows", "mac"];
+
+/// Returns all deprecated core tasks, mapped to their replacement task (if they have one).
+pub(crate) fn get_deprecated_tasks() -> Result<IndexMap<String, Option<String>>, CargoMakeError> {
+    let config = descriptor_deserializer::load_external_config(makefiles::STABLE, "stable")?;
+
+    let mut deprecated_tasks = IndexMap::new();
+    for (name, task) in config.tasks.unwrap_or_default() {
+        match task.deprecated {
+            Some(DeprecationInfo::Message(message)) => {
+                let replacement = message
+                    .strip_prefix(REPLACED_TASK_PREFIX)
+                    .map(|replacement| replacement.trim().to_string());
+                deprecated_tasks.insert(name, replacement);
+            }
+            Some(DeprecationInfo::Boolean(true)) => {
+                deprecated_tasks.insert(name, None);
+            }
+            _ => (),
+        }
+    }
+
+    Ok(deprecated_tasks)
+}
+
+#[derive(Debug, Default)]
+/// Holds the migration output
+pub(crate) struct Migration {
+    /// The migrated makefile content
+    pub(crate) content: String,
+    /// Description of all changes made
+    pub(crate) changes: Vec<String>,
+    /// Deprecated content which could not be migrated automatically
+    pub(crate) warnings: Vec<String>,
+}
+
+struct MigrationState<'a> {
+    deprecated_tasks: IndexMap<&'a str, Option<&'a str>>,
+    changes: Vec<String>,
+    warnings: Vec<String>,
+}
+
+fn rename_value(value: &mut Value, location: &str, state: &mut MigrationState) {
+    match value {
+        Value::String(name) => match state.deprecated_tasks.get(name.value().as_str()) {
+            Some(Some(replacement)) => {
+                state.changes.push(format!(
+                    "{}: replaced deprecated task: {} with: {}",
+                    location,
+                    name.value(),
+                    replacement
+                ));
+
+                let decor = name.decor().clone();
+                *value = Value::from(*replacement);
+                *value.decor_mut() = decor;
+            }
+            Some(None) => state.warnings.push(format!(
+                "{}: deprecated task: {} has no direct replacement and must be migrated manually",
+                location,
+                name.value()
+            )),
+            None => (),
+        },
+        Value::Array(array) => {
+            for item in array.iter_mut() {
+                rename_value(item, location, state);
+            }
+        }
+        Value::InlineTable(table) => rename_task_reference(table, location, state),
+        _ => (),
+    }
+}
+
+fn rename_item(item: &mut Item, location: &str, state: &mut MigrationState) {
+    match item {
+        Item::Value(value) => rename_value(value, location, state),
+        Item::Table(table) => rename_task_reference(table, location, state),
+        Item::ArrayOfTables(tables) => {
+            for table in tables.iter_mut() {
+                rename_task_reference(table, location, state);
+            }
+        }
+        Item::None => (),
+    }
+}
+
+/// Handles the dependency and run_task table forms.
+fn rename_task_reference(table: &mut dyn TableLike, location: &str, state: &mut MigrationState) {
+    // dependencies from other makefiles are not core tasks
+    if table.contains_key("path") {
+        return;
+    }
+
+    for key in ["name", "cleanup_task"] {
+        if let Some(item) = table.get_mut(key) {
+            rename_item(item, location, state);
+        }
+    }
+}
+
+/// Renames the attribute while keeping its position and formatting.
+fn rename_attribute(table: &mut dyn TableLike, old_key: &str, new_key: &str) -> bool {
+    if !table.contains_key(old_key) || table.contains_key(new_key) {
+        return false;
+    }
+
+    let keys: Vec<Key> = table.iter().map(|(key, _)| Key::new(key)).collect();
+    for key in keys {
+        let key = table.key(key.get()).cloned().unwrap_or(key);
+        if let Some(item) = table.remove(key.get()) {
+            let key = if key.get() == old_key {
+                Key::new(new_key).with_leaf_decor(key.leaf_decor().clone())
+            } else {
+                key
+            };
+            table.entry_format(&key).or_insert(item);
+        }
+    }
+
+    true
+}
+
+fn migrate_task(task: &mut dyn TableLike, location: &str, state: &mut MigrationState) {
+    for (old_key, new_key) in DEPRECATED_ATTRIBUTES {
+        if rename_attribute(task, old_key, new_key) {
+            state.changes.push(format!(
+                "{}: replaced deprecated attribute: {} with: {}",
+                location, old_key, new_key
+            ));
+        } else if task.contains_key(old_key) {
+            state.warnings.push(format!(
+                "{}: deprecated attribute: {} was not replaced as: {} is already defined and must be removed manually",
+                location, old_key, new_key
+            ));
+        }
+    }
+
+    for key in ["dependencies", "run_task"] {
+        if let Some(item) = task.get_mut(key) {
+            rename_item(item, location, state);
+        }
+    }
+    for key in TASK_NAME_ATTRIBUTES {
+        if let Some(Item::Value(value)) = task.get_mut(key) {
+            rename_value(value, location, state);
+        }
+    }
+
+    for platform in PLATFORMS {
+        if let Some(platform_task) = task
+            .get_mut(platform)
+            .and_then(|item| item.as_table_like_mut())
+        {
+            let platform_location = format!("{}.{}", location, platform);
+            migrate_task(platform_task, &platform_location, state);
+        }
+    }
+}
+
+fn migrate_tasks(document: &mut DocumentMut, section: &str, state: &mut MigrationState) {
+    if let Some(tasks) = document
+        .get_mut(section)
+        .and_then(|item| item.as_table_like_mut())
+    {
+        for (name, item) in tasks.iter_mut() {
+            if let Some(task) = item.as_table_like_mut() {
+                let location = format!("{}.{}", section, name.get());
+                migrate_task(task, &location, state);
+            }
+        }
+    }
+}
+
+/// Returns the migrated makefile content and a description of all changes.
+pub(crate) fn migrate(
+    content: &str,
+    deprecated_tasks: &IndexMap<String, Option<String>>,
+) -> Result<Migration, CargoMakeError> {
+    let mut document: DocumentMut = match content.parse() {
+        Ok(document) => document,
+        Err(error) => {
+            return Err(CargoMakeError::DescriptorParseFailed(error.to_string()));
+        }
+    };
+
+    // tasks defined in the makefile itself override the deprecated core tasks
+    let local_tasks: Vec<String> = document
+        .get("tasks")
+        .and_then(|item| item.as_table_like())
+        .map(|tasks| tasks.iter().map(|(name, _)| name.to_string()).collect())
+        .unwrap_or_default();
+    let deprecated_tasks = deprecated_tasks
+        .iter()
+        .filter(|(name, _)| !local_tasks.contains(name))
+        .map(|(name, replacement)| (name.as_str(), replacement.as_deref()))
+        .collect();
+
+    let mut state = MigrationState {
+        deprecated_tasks,
+        changes: vec![],
+        warnings: vec![],
+    };
+
+    if let Some(config) = document
+        .get_mut("config")
+        .and_then(|item| item.as_table_like_mut())
+    {
+        for key in CONFIG_TASK_ATTRIBUTES {
+            if let Some(Item::Value(value)) = config.get_mut(key) {
+                rename_value(value, "config", &mut state);
+            }
+        }
+    }
+    migrate_tasks(&mut document, "templates", &mut state);
+    migrate_tasks(&mut document, "tasks", &mut state);
+
+    Ok(Migration {
+        content: document.to_string(),
+        changes: state.changes,
+        warnings: state.warnings,
+    })
+}
+
+/// The number of unchanged lines printed around every change in the diff
+static DIFF_CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Returns the line by line changes between the two texts (based on the longest common
+/// subsequence of lines).
+fn get_diff_lines<'a>(original: &'a str, migrated: &'a str) -> Vec<DiffLine<'a>> {
+    let original: Vec<&str> = original.lines().collect();
+    let migrated: Vec<&str> = migrated.lines().collect();
+
+    let mut common = vec![vec![0usize; migrated.len() + 1]; original.len() + 1];
+    for original_index in (0..original.len()).rev() {
+        for migrated_index in (0..migrated.len()).rev() {
+            common[original_index][migrated_index] =
+                if original[original_index] == migrated[migrated_index] {
+                    common[original_index + 1][migrated_index + 1] + 1
+                } else {
+                    common[original_index + 1][migrated_index]
+                        .max(common[original_index][migrated_index + 1])
+                };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut original_index, mut migrated_index) = (0, 0);
+    while original_index < original.len() || migrated_index < migrated.len() {
+        if original_index < original.len()
+            && migrated_index < migrated.len()
+            && original[original_index] == migrated[migrated_index]
+        {
+            lines.push(DiffLine::Unchanged(original[original_index]));
+            original_index += 1;
+            migrated_index += 1;
+        } else if original_index < original.len()
+            && (migrated_index == migrated.len()
+                || common[original_index + 1][migrated_index]
+                    >= common[original_index][migrated_index + 1])
+        {
+            lines.push(DiffLine::Removed(original[original_index]));
+            original_index += 1;
+        } else {
+            lines.push(DiffLine::Added(migrated[migrated_index]));
+            migrated_index += 1;
+        }
+    }
+
+    lines
+}
+
+/// Returns the unified diff between the original and migrated makefile content.
+fn create_diff(file_name: &str, original: &str, migrated: &str) -> String {
+    let lines = get_diff_lines(original, migrated);
+
+    // the line ranges (with their context) of all changes, merged when overlapping
+    let mut ranges: Vec<(usize, usize)> = vec![];
+    for (index, line) in lines.iter().enumerate() {
+        if let DiffLine::Unchanged(_) = line {
+            continue;
+        }
+
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + DIFF_CONTEXT_LINES + 1).min(lines.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", file_name, file_name);
+    for (start, end) in ranges {
+        let count = |lines: &[DiffLine], removed: bool| {
+            lines
+                .iter()
+                .filter(|line| match line {
+                    DiffLine::Unchanged(_) => true,
+                    DiffLine::Removed(_) => removed,
+                    DiffLine::Added(_) => !removed,
+                })
+                .count()
+        };
+        let hunk_start = |before: usize, hunk_count: usize| {
+            if hunk_count == 0 {
+                before
+            } else {
+                before + 1
+            }
+        };
+
+        let original_before = count(&lines[..start], true);
+        let original_count = count(&lines[start..end], true);
+        let migrated_before = count(&lines[..start], false);
+        let migrated_count = count(&lines[start..end], false);
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk_start(original_before, original_count),
+            original_count,
+            hunk_start(migrated_before, migrated_count),
+            migrated_count
+        ));
+
+        for line in &lines[start..end] {
+            let (prefix, value) = match line {
+                DiffLine::Unchanged(value) => (' ', value),
+                DiffLine::Removed(value) => ('-', value),
+                DiffLine::Added(value) => ('+', value),
+            };
+            diff.push_str(&format!("{}{}\n", prefix, value));
+        }
+    }
+
+    diff
+}
+
+pub(crate) fn run(build_file: &str) -> Result<(), CargoMakeError> {
+    let file_path = PathBuf::from(build_file);
+    if !file_path.is_file() {
+        return Err(CargoMakeError::NotFound(format!(
+            "Descriptor file: {:#?} not found.",
+            &file_path
+        )));
+    }
+
+    if DescriptorFormat::from_file(build_file) != DescriptorFormat::Toml {
+        return Err(CargoMakeError::ParseFileFailed(
+            build_file.to_string(),
+            "Only TOML makefiles can be migrated.".to_string(),
+        ));
+    }
+
+    let content = io::read_text_file(&file_path)?;
+    let deprecated_tasks = get_deprecated_tasks()?;
+    let migration = match migrate(&content, &deprecated_tasks) {
+        Ok(migration) => migration,
+        Err(error) => {
+            return Err(CargoMakeError::ParseFileFailed(
+                build_file.to_string(),
+                error.to_string(),
+            ));
+        }
+    };
+
+    for warning in &migration.warnings {
+        warn!("{}", warning);
+    }
+
+    if migration.changes.is_empty() {
+        info!(
+            "Makefile: {} has no deprecated content to migrate.",
+            build_file
+        );
+        return Ok(());
+    }
+
+    print!("{}", create_diff(build_file, &content, &migration.content));
+    for change in &migration.changes {
+        info!("{}", change);
+    }
+
+    io::write_text_file(build_file, &migration.content);
+    info!("Migrated makefile: {}", build_file);
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use super::*;
+use crate::test::get_temp_test_directory;
+
+fn write_makefile(subdir: &str, file_name: &str, content: &str) -> String {
+    let directory = get_temp_test_directory(subdir);
+    let file = directory.join(file_name);
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(&file, content);
+
+    file
+}
+
+fn migrate_content(content: &str) -> Migration {
+    migrate(content, &get_deprecated_tasks().unwrap()).unwrap()
+}
+
+#[test]
+fn get_deprecated_tasks_replacements() {
+    let deprecated_tasks = get_deprecated_tasks().unwrap();
+
+    assert_eq!(
+        deprecated_tasks.get("conditioned-clippy").unwrap(),
+        &Some("clippy-ci-flow".to_string())
+    );
+    assert_eq!(
+        deprecated_tasks.get("conditioned-check-format").unwrap(),
+        &Some("check-format-ci-flow".to_string())
+    );
+    assert_eq!(deprecated_tasks.get("build-verbose").unwrap(), &None);
+    assert_eq!(deprecated_tasks.get("bintray-upload").unwrap(), &None);
+    assert!(deprecated_tasks.get("build").is_none());
+}
+
+#[test]
+fn migrate_deprecated_attribute() {
+    let migration = migrate_content(
+        r#"
+[tasks.build]
+command = "cargo"
+# keep going on failures
+force = true # inline comment
+args = ["build"]
+
+[tasks.build.linux]
+force = false
+
+[tasks.test]
+force = true
+ignore_errors = false
+"#,
+    );
+
+    assert_eq!(
+        migration.content,
+        r#"
+[tasks.build]
+command = "cargo"
+# keep going on failures
+ignore_errors = true # inline comment
+args = ["build"]
+
+[tasks.build.linux]
+ignore_errors = false
+
+[tasks.test]
+force = true
+ignore_errors = false
+"#
+    );
+    assert_eq!(
+        migration.changes,
+        vec![
+            "tasks.build: replaced deprecated attribute: force with: ignore_errors".to_string(),
+            "tasks.build.linux: replaced deprecated attribute: force with: ignore_errors"
+                .to_string(),
+        ]
+    );
+    assert_eq!(
+        migration.warnings,
+        vec![
+            "tasks.test: deprecated attribute: force was not replaced as: ignore_errors is already defined and must be removed manually"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn migrate_deprecated_task_references() {
+    let migration = migrate_content(
+        r#"
+[config]
+init_task = "conditioned-clippy"
+
+[templates.lint]
+dependencies = ["conditioned-clippy"]
+
+[tasks.ci]
+dependencies = [
+  "conditioned-check-format", # format
+  { name = "conditioned-clippy" },
+  { name = "conditioned-clippy", path = "other" },
+  "build",
+]
+alias = "conditioned-clippy"
+
+[tasks.single]
+run_task = "conditioned-clippy"
+
+[tasks.details]
+run_task = { name = ["conditioned-clippy", "build"], cleanup_task = "conditioned-check-format" }
+
+[[tasks.routing.run_task]]
+name = "conditioned-clippy"
+
+[tasks.platform.windows]
+run_task = "conditioned-check-format"
+"#,
+    );
+
+    assert_eq!(
+        migration.content,
+        r#"
+[config]
+init_task = "clippy-ci-flow"
+
+[templates.lint]
+dependencies = ["clippy-ci-flow"]
+
+[tasks.ci]
+dependencies = [
+  "check-format-ci-flow", # format
+  { name = "clippy-ci-flow" },
+  { name = "conditioned-clippy", path = "other" },
+  "build",
+]
+alias = "clippy-ci-flow"
+
+[tasks.single]
+run_task = "clippy-ci-flow"
+
+[tasks.details]
+run_task = { name = ["clippy-ci-flow", "build"], cleanup_task = "check-format-ci-flow" }
+
+[[tasks.routing.run_task]]
+name = "clippy-ci-flow"
+
+[tasks.platform.windows]
+run_task = "check-format-ci-flow"
+"#
+    );
+    assert_eq!(migration.changes.len(), 10);
+    assert_eq!(
+        migration.changes[0],
+        "config: replaced deprecated task: conditioned-clippy with: clippy-ci-flow"
+    );
+}
+
+#[test]
+fn migrate_locally_defined_task() {
+    let content = r#"
+[tasks.conditioned-clippy]
+command = "cargo"
+
+[tasks.ci]
+dependencies = ["conditioned-clippy"]
+"#;
+
+    let migration = migrate_content(content);
+
+    assert_eq!(migration.content, content);
+    assert!(migration.changes.is_empty());
+}
+
+#[test]
+fn migrate_task_without_replacement() {
+    let content = r#"
+[tasks.ci]
+dependencies = ["build-verbose"]
+"#;
+
+    let migration = migrate_content(content);
+
+    assert_eq!(migration.content, content);
+    assert!(migration.changes.is_empty());
+    assert_eq!(
+        migration.warnings,
+        vec![
+            "tasks.ci: deprecated task: build-verbose has no direct replacement and must be migrated manually"
+                .to_string()
+        ]
+    );
+}
+
+#[test]
+fn migrate_invalid() {
+    let result = migrate("[tasks.test", &IndexMap::new());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn create_diff_changes() {
+    let original = "[tasks.a]\n1\n2\n3\n4\nforce = true\n5\n6\n7\n8\n9\n10\n11\nforce = true\n";
+    let migrated =
+        "[tasks.a]\n1\n2\n3\n4\nignore_errors = true\n5\n6\n7\n8\n9\n10\n11\nignore_errors = true\n";
+
+    let diff = create_diff("Makefile.toml", original, migrated);
+
+    assert_eq!(
+        diff,
+        r#"--- a/Makefile.toml
++++ b/Makefile.toml
+@@ -3,7 +3,7 @@
+ 2
+ 3
+ 4
+-force = true
++ignore_errors = true
+ 5
+ 6
+ 7
+@@ -11,4 +11,4 @@
+ 9
+ 10
+ 11
+-force = true
++ignore_errors = true
+"#
+    );
+}
+
+#[test]
+fn create_diff_added_and_removed_lines() {
+    let diff = create_diff("Makefile.toml", "a\nb\n", "a\nc\nd\n");
+
+    assert_eq!(
+        diff,
+        "--- a/Makefile.toml\n+++ b/Makefile.toml\n@@ -1,2 +1,3 @@\n a\n-b\n+c\n+d\n"
+    );
+}
+
+#[test]
+fn create_diff_empty_original() {
+    let diff = create_diff("Makefile.toml", "", "a\n");
+
+    assert_eq!(
+        diff,
+        "--- a/Makefile.toml\n+++ b/Makefile.toml\n@@ -0,0 +1,1 @@\n+a\n"
+    );
+}
+
+#[test]
+fn create_diff_no_changes() {
+    let diff = create_diff("Makefile.toml", "a\n", "a\n");
+
+    assert_eq!(diff, "--- a/Makefile.toml\n+++ b/Makefile.toml\n");
+}
+
+#[test]
+fn run_migrate_file() {
+    let file = write_makefile(
+        "migrate_run_migrate_file",
+        "Makefile.toml",
+        "[tasks.test]\nforce = true\n",
+    );
+
+    run(&file).unwrap();
+
+    let content = io::read_text_file(&PathBuf::from(&file)).unwrap();
+    assert_eq!(content, "[tasks.test]\nignore_errors = true\n");
+
+    // nothing left to migrate
+    run(&file).unwrap();
+}
+
+#[test]
+fn run_not_toml() {
+    let file = write_makefile(
+        "migrate_run_not_toml",
+        "Makefile.yaml",
+        "tasks:\n  test:\n    force: true\n",
+    );
+
+    let result = run(&file);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn run_missing_file() {
+    let directory = get_temp_test_directory("migrate_run_missing_file");
+    let file = directory.join("Makefile.toml");
+
+    let result = run(file.to_str().unwrap());
+
+    assert!(result.is_err());
+}
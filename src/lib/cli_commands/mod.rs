@@ -4,5 +4,16 @@
 //!
 
 pub(crate) mod diff_steps;
+pub(crate) mod explain_env;
+pub(crate) mod explain_task;
+pub(crate) mod fmt;
+pub(crate) mod import_tasks;
+pub(crate) mod lint;
+pub(crate) mod list_profiles;
 pub mod list_steps;
+pub(crate) mod migrate;
+pub(crate) mod pick_task;
+pub(crate) mod print_config;
+pub(crate) mod print_env;
+pub(crate) mod print_schema;
 pub mod print_steps;
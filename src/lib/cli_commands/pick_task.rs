@@ -0,0 +1,291 @@
+//! # pick_task
+//!
+//! Interactive task picker.<br>
+//! Lists the public tasks grouped by category (with their description, aliases and
+//! dependencies), enables to fuzzy filter them and returns the picked task name.
+//!
+
+#[cfg(test)]
+#[path = "pick_task_test.rs"]
+mod pick_task_test;
+
+use crate::cli_commands::list_steps;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::types::{CliArgs, Config, EnvValue, GlobalConfig};
+use std::io::{BufRead, IsTerminal, Write};
+
+/// Description matches are ranked after all the task name and alias matches
+static DESCRIPTION_MATCH_PENALTY: usize = 10000;
+
+/// The task env value added by cargo-make to tasks defined in the user makefiles
+static TASK_LOCATION_ENV: &str = "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE";
+
+#[derive(Debug, Clone)]
+/// Holds the info of a single task in the picker list
+struct PickItem {
+    /// The task name
+    name: String,
+    /// The task category
+    category: String,
+    /// The task description
+    description: String,
+    /// The task aliases
+    aliases: Vec<String>,
+    /// The task dependencies
+    dependencies: Vec<String>,
+}
+
+/// Returns true if the task is defined in the user makefiles (and not only by the internal
+/// core makefiles)
+fn is_defined_in_makefile(config: &Config, name: &str) -> bool {
+    match config.tasks.get(name) {
+        Some(task) => match task.env {
+            Some(ref env) => matches!(env.get(TASK_LOCATION_ENV), Some(EnvValue::Value(_))),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+fn should_pick_for_args(
+    cli_args: &CliArgs,
+    global_config: &GlobalConfig,
+    config: &Config,
+    interactive: bool,
+) -> bool {
+    if cli_args.pick {
+        true
+    } else if !cli_args.default_task
+        || !interactive
+        || global_config.default_task_name.is_some()
+        || is_defined_in_makefile(config, &cli_args.task)
+    {
+        // a configured or makefile defined default task is always invoked
+        false
+    } else {
+        // only pick if the task is going to be invoked
+        !(cli_args.list_all_steps
+            || cli_args.list_category_steps.is_some()
+            || cli_args.list_profiles
+            || cli_args.diff_execution_plan
+            || cli_args.explain_env.is_some()
+            || cli_args.explain.is_some()
+            || cli_args.print_config
+            || cli_args.print_env
+            || cli_args.print_only)
+    }
+}
+
+/// Returns true if the --pick flag was provided or if no task was provided while running
+/// in an interactive terminal (not on CI) and no default task is defined in the global config
+/// or in the makefiles.
+pub(crate) fn should_pick(
+    cli_args: &CliArgs,
+    global_config: &GlobalConfig,
+    config: &Config,
+) -> bool {
+    let interactive =
+        std::io::stdin().is_terminal() && std::io::stdout().is_terminal() && !ci_info::is_ci();
+
+    should_pick_for_args(cli_args, global_config, config, interactive)
+}
+
+fn get_items(config: &Config) -> Result<Vec<PickItem>, CargoMakeError> {
+    let (categories, mut aliases) = list_steps::get_task_list(config, &None, true)?;
+
+    let mut items = vec![];
+    for (category, tasks) in categories {
+        for (name, description) in tasks {
+            let task = execution_plan::get_normalized_task(config, &name, true)?;
+            let dependencies = match task.dependencies {
+                Some(ref dependencies) => dependencies
+                    .iter()
+                    .map(|dependency| dependency.name().to_string())
+                    .collect(),
+                None => vec![],
+            };
+            let task_aliases = match aliases.remove(&name) {
+                Some(task_aliases) => task_aliases.into_iter().collect(),
+                None => vec![],
+            };
+
+            items.push(PickItem {
+                name,
+                category: category.clone(),
+                description,
+                aliases: task_aliases,
+                dependencies,
+            });
+        }
+    }
+
+    Ok(items)
+}
+
+/// Returns the match score of the query in the text (lower is better) or None if the
+/// query characters do not appear in the text in the same order.
+fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+
+    if let Some(index) = text.find(&query) {
+        return Some(index);
+    }
+
+    let text_chars: Vec<char> = text.chars().collect();
+    let mut first = None;
+    let mut last = 0;
+    let mut position = 0;
+    for query_char in query.chars() {
+        let offset = text_chars[position..]
+            .iter()
+            .position(|text_char| *text_char == query_char)?;
+        let index = position + offset;
+
+        first.get_or_insert(index);
+        last = index;
+        position = index + 1;
+    }
+
+    // scattered matches are ranked after the exact matches and compact matches first
+    first.map(|first| text_chars.len() + last - first)
+}
+
+fn get_item_score(query: &str, item: &PickItem) -> Option<usize> {
+    let name_score = std::iter::once(&item.name)
+        .chain(item.aliases.iter())
+        .filter_map(|text| fuzzy_score(query, text))
+        .min();
+
+    name_score.or_else(|| {
+        item.description
+            .to_lowercase()
+            .find(&query.to_lowercase())
+            .map(|index| DESCRIPTION_MATCH_PENALTY + index)
+    })
+}
+
+/// Returns the items matching the query, best matches first
+fn filter_items<'a>(items: &'a [PickItem], query: &str) -> Vec<&'a PickItem> {
+    if query.is_empty() {
+        return items.iter().collect();
+    }
+
+    let mut scored_items: Vec<(usize, &PickItem)> = items
+        .iter()
+        .filter_map(|item| get_item_score(query, item).map(|score| (score, item)))
+        .collect();
+    scored_items.sort_by_key(|(score, _)| *score);
+
+    scored_items.into_iter().map(|(_, item)| item).collect()
+}
+
+fn render(
+    output: &mut dyn Write,
+    items: &[&PickItem],
+    group_by_category: bool,
+) -> Result<(), CargoMakeError> {
+    let mut category = None;
+    for (index, item) in items.iter().enumerate() {
+        if group_by_category && category != Some(&item.category) {
+            writeln!(output, "\n{}\n----------", &item.category)?;
+            category = Some(&item.category);
+        }
+
+        let aliases = if item.aliases.is_empty() {
+            "".to_string()
+        } else {
+            format!(" [aliases: {}]", item.aliases.join(", "))
+        };
+        let category_suffix = if group_by_category {
+            "".to_string()
+        } else {
+            format!(" [category: {}]", &item.category)
+        };
+        writeln!(
+            output,
+            "{:>4}) {} - {}{}{}",
+            index + 1,
+            &item.name,
+            &item.description,
+            aliases,
+            category_suffix
+        )?;
+
+        if !item.dependencies.is_empty() {
+            writeln!(
+                output,
+                "        depends on: {}",
+                item.dependencies.join(", ")
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Shows the list until a task is picked (by number or by pressing enter when only one task
+/// matches the filter). Returns None if cancelled.
+fn pick(
+    items: &[PickItem],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<Option<String>, CargoMakeError> {
+    let mut query = String::new();
+
+    loop {
+        let visible_items = filter_items(items, &query);
+
+        if visible_items.is_empty() {
+            writeln!(output, "\nNo task matches: {}", &query)?;
+        } else {
+            render(output, &visible_items, query.is_empty())?;
+        }
+
+        if visible_items.len() == 1 {
+            write!(
+                output,
+                "\nPress enter to run {} or type a new filter: ",
+                &visible_items[0].name
+            )?;
+        } else {
+            write!(
+                output,
+                "\nType to filter, enter a task number to run it or press enter to cancel: "
+            )?;
+        }
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim();
+
+        if line.is_empty() {
+            return Ok(if visible_items.len() == 1 {
+                Some(visible_items[0].name.clone())
+            } else {
+                None
+            });
+        }
+
+        if let Ok(number) = line.parse::<usize>() {
+            if number >= 1 && number <= visible_items.len() {
+                return Ok(Some(visible_items[number - 1].name.clone()));
+            }
+        }
+
+        query = line.to_string();
+    }
+}
+
+/// Shows the task picker in the terminal and returns the picked task (None if cancelled)
+pub(crate) fn run(config: &Config) -> Result<Option<String>, CargoMakeError> {
+    let items = get_items(config)?;
+
+    let stdin = std::io::stdin();
+    let mut stdout = std::io::stdout();
+    pick(&items, &mut stdin.lock(), &mut stdout)
+}
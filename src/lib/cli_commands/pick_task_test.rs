@@ -0,0 +1,331 @@
+use super::*;
+use crate::types::{ConfigSection, EnvValue, Task};
+use indexmap::IndexMap;
+use std::io::Cursor;
+
+fn create_item(name: &str, category: &str, description: &str) -> PickItem {
+    PickItem {
+        name: name.to_string(),
+        category: category.to_string(),
+        description: description.to_string(),
+        aliases: vec![],
+        dependencies: vec![],
+    }
+}
+
+fn create_items() -> Vec<PickItem> {
+    let mut build = create_item("build", "Build", "Runs the rust compiler.");
+    build.aliases = vec!["b".to_string()];
+    build.dependencies = vec!["pre-build".to_string(), "format".to_string()];
+
+    vec![
+        build,
+        create_item("docs", "Documentation", "Generates the documentation."),
+        create_item("bench", "Test", "Runs the benchmarks."),
+        create_item("test", "Test", "Runs all the tests."),
+    ]
+}
+
+fn create_config(tasks: IndexMap<String, Task>) -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        vars: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+    }
+}
+
+fn run_pick(input: &str) -> (Option<String>, String) {
+    let mut reader = Cursor::new(input.as_bytes().to_vec());
+    let mut output = vec![];
+
+    let picked = pick(&create_items(), &mut reader, &mut output).unwrap();
+
+    (picked, String::from_utf8(output).unwrap())
+}
+
+#[test]
+fn should_pick_for_args_flag() {
+    let mut cli_args = CliArgs::new();
+    cli_args.pick = true;
+
+    assert!(should_pick_for_args(
+        &cli_args,
+        &GlobalConfig::new(),
+        &create_config(IndexMap::new()),
+        false
+    ));
+}
+
+#[test]
+fn should_pick_for_args_default_task() {
+    let global_config = GlobalConfig::new();
+    let config = create_config(IndexMap::new());
+    let mut cli_args = CliArgs::new();
+    assert!(!should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+
+    cli_args.default_task = true;
+    assert!(should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+    assert!(!should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        false
+    ));
+
+    cli_args.print_only = true;
+    assert!(!should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+}
+
+#[test]
+fn should_pick_for_args_global_default_task() {
+    let mut global_config = GlobalConfig::new();
+    global_config.default_task_name = Some("build".to_string());
+    let config = create_config(IndexMap::new());
+    let mut cli_args = CliArgs::new();
+    cli_args.default_task = true;
+
+    assert!(!should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+
+    cli_args.pick = true;
+    assert!(should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+}
+
+#[test]
+fn should_pick_for_args_makefile_default_task() {
+    let global_config = GlobalConfig::new();
+    let mut cli_args = CliArgs::new();
+    cli_args.default_task = true;
+
+    // the internal default task does not have the makefile location env
+    let mut config = create_config(IndexMap::from([("default".to_string(), Task::new())]));
+    assert!(should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+
+    let mut task = Task::new();
+    task.env = Some(IndexMap::from([(
+        "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE".to_string(),
+        EnvValue::Value("./Makefile.toml".to_string()),
+    )]));
+    config.tasks.insert("default".to_string(), task);
+    assert!(!should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+
+    cli_args.pick = true;
+    assert!(should_pick_for_args(
+        &cli_args,
+        &global_config,
+        &config,
+        true
+    ));
+}
+
+#[test]
+fn get_items_public_tasks() {
+    let mut build = Task::new();
+    build.category = Some("Build".to_string());
+    build.description = Some("Builds.".to_string());
+    build.dependencies = Some(vec!["hidden".into()]);
+    let mut alias = Task::new();
+    alias.alias = Some("build".to_string());
+    let mut hidden = Task::new();
+    hidden.private = Some(true);
+
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        vars: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::from([
+            ("build".to_string(), build),
+            ("b".to_string(), alias),
+            ("hidden".to_string(), hidden),
+            ("other".to_string(), Task::new()),
+        ]),
+        plugins: None,
+    };
+
+    let items = get_items(&config).unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].name, "build");
+    assert_eq!(items[0].category, "Build");
+    assert_eq!(items[0].description, "Builds.");
+    assert_eq!(items[0].aliases, vec!["b"]);
+    assert_eq!(items[0].dependencies, vec!["hidden"]);
+    assert_eq!(items[1].name, "other");
+    assert_eq!(items[1].category, "No Category");
+    assert!(items[1].dependencies.is_empty());
+}
+
+#[test]
+fn fuzzy_score_variations() {
+    assert_eq!(fuzzy_score("build", "build"), Some(0));
+    assert_eq!(fuzzy_score("FLOW", "build-flow"), Some(6));
+    assert_eq!(fuzzy_score("bf", "build-flow"), Some(16));
+    assert!(fuzzy_score("bf", "b-f").unwrap() < fuzzy_score("bf", "build-flow").unwrap());
+    assert_eq!(fuzzy_score("fb", "build-flow"), None);
+}
+
+#[test]
+fn filter_items_empty_query() {
+    let items = create_items();
+
+    let filtered = filter_items(&items, "");
+
+    assert_eq!(filtered.len(), 4);
+}
+
+#[test]
+fn filter_items_ranked() {
+    let items = create_items();
+
+    let names: Vec<String> = filter_items(&items, "te")
+        .iter()
+        .map(|item| item.name.clone())
+        .collect();
+
+    assert_eq!(names, vec!["test", "docs"]);
+}
+
+#[test]
+fn filter_items_description() {
+    let items = create_items();
+
+    let names: Vec<String> = filter_items(&items, "compiler")
+        .iter()
+        .map(|item| item.name.clone())
+        .collect();
+
+    assert_eq!(names, vec!["build"]);
+}
+
+#[test]
+fn render_grouped() {
+    let items = create_items();
+    let mut output = vec![];
+
+    render(&mut output, &items.iter().collect::<Vec<&PickItem>>(), true).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        r#"
+Build
+----------
+   1) build - Runs the rust compiler. [aliases: b]
+        depends on: pre-build, format
+
+Documentation
+----------
+   2) docs - Generates the documentation.
+
+Test
+----------
+   3) bench - Runs the benchmarks.
+   4) test - Runs all the tests.
+"#
+    );
+}
+
+#[test]
+fn render_filtered() {
+    let items = create_items();
+    let mut output = vec![];
+
+    render(&mut output, &vec![&items[3]], false).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "   1) test - Runs all the tests. [category: Test]\n"
+    );
+}
+
+#[test]
+fn pick_by_number() {
+    let (picked, output) = run_pick("2\n");
+
+    assert_eq!(picked, Some("docs".to_string()));
+    assert!(output.contains("press enter to cancel: "));
+}
+
+#[test]
+fn pick_filter_and_number() {
+    let (picked, _) = run_pick("be\n1\n");
+
+    assert_eq!(picked, Some("bench".to_string()));
+}
+
+#[test]
+fn pick_single_match_enter() {
+    let (picked, output) = run_pick("doc\n\n");
+
+    assert_eq!(picked, Some("docs".to_string()));
+    assert!(output.contains("Press enter to run docs or type a new filter: "));
+}
+
+#[test]
+fn pick_no_match_then_filter() {
+    let (picked, output) = run_pick("xyz\ntest\n\n");
+
+    assert_eq!(picked, Some("test".to_string()));
+    assert!(output.contains("No task matches: xyz"));
+}
+
+#[test]
+fn pick_out_of_range_number_is_filter() {
+    let (picked, output) = run_pick("9\n\n");
+
+    assert!(picked.is_none());
+    assert!(output.contains("No task matches: 9"));
+}
+
+#[test]
+fn pick_cancel() {
+    let (picked, _) = run_pick("\n");
+
+    assert!(picked.is_none());
+}
+
+#[test]
+fn pick_end_of_input() {
+    let (picked, _) = run_pick("");
+
+    assert!(picked.is_none());
+}
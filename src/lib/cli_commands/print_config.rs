@@ -0,0 +1,227 @@
+//! # print_config
+//!
+//! Prints the fully merged configuration (config section, env and tasks) after all the
+//! extends, platform overrides, aliases and profiles are resolved.<br>
+//! Env values are annotated with the makefile they came from and tasks with the makefile
+//! which defined them last.
+//!
+
+#[cfg(test)]
+#[path = "print_config_test.rs"]
+mod print_config_test;
+
+use crate::cli_commands::explain_env;
+use crate::cli_commands::fmt;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::io;
+use crate::secrets;
+use crate::types::{Config, EnvOrigin, EnvValue, Task};
+use indexmap::IndexMap;
+use toml_edit::{DocumentMut, Item, Value};
+
+/// The task env values added by cargo-make to track the makefile of each task
+static TASK_LOCATION_ENV: [&str; 2] = [
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE",
+    "CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE_DIRECTORY",
+];
+
+/// Returns the annotation of the origin, for example: makefile: /project/Makefile.toml
+fn describe_origin(origin: &EnvOrigin) -> String {
+    match origin.file {
+        Some(ref file) => format!("{}: {}", &origin.layer, file),
+        None => origin.layer.to_string(),
+    }
+}
+
+/// Returns the env with the active profiles env merged in (in the same order they are
+/// applied at runtime) and the annotation of each value.
+fn resolve_env(
+    env: &IndexMap<String, EnvValue>,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    active_profiles: &[String],
+) -> (IndexMap<String, EnvValue>, IndexMap<String, String>) {
+    let mut resolved_env = IndexMap::new();
+    let mut sources = IndexMap::new();
+
+    let mut add_values = |values: &IndexMap<String, EnvValue>, profile: Option<&String>| {
+        for (key, value) in values {
+            let source = env_origins
+                .get(key)
+                .and_then(|origins| {
+                    origins.iter().rev().find(|origin| {
+                        origin.profile.as_ref() == profile && !origin.layer.starts_with("task: ")
+                    })
+                })
+                .map(describe_origin);
+
+            resolved_env.insert(key.clone(), value.clone());
+            match source {
+                Some(source) => sources.insert(key.clone(), source),
+                None => sources.shift_remove(key),
+            };
+        }
+    };
+
+    let mut profile_env = None;
+    for (key, value) in env {
+        match value {
+            EnvValue::Profile(sub_env) => {
+                if active_profiles.first() == Some(key) {
+                    // the current profile env is applied last
+                    profile_env = Some((key, sub_env));
+                } else if active_profiles.contains(key) {
+                    add_values(sub_env, Some(key));
+                }
+            }
+            _ => add_values(&IndexMap::from([(key.clone(), value.clone())]), None),
+        }
+    }
+    if let Some((profile, sub_env)) = profile_env {
+        add_values(sub_env, Some(profile));
+    }
+
+    (resolved_env, sources)
+}
+
+/// Returns the normalized task (platform overrides and task extend resolved) and the
+/// makefile it came from.
+fn resolve_task(config: &Config, name: &str) -> Result<(Task, String), CargoMakeError> {
+    let mut task = execution_plan::get_normalized_task(config, name, false)?;
+
+    let mut source = "internal".to_string();
+    if let Some(ref mut env) = task.env {
+        if let Some(EnvValue::Value(file)) = env.get(TASK_LOCATION_ENV[0]) {
+            source = file.to_string();
+        }
+
+        for key in TASK_LOCATION_ENV {
+            env.shift_remove(key);
+        }
+        if env.is_empty() {
+            task.env = None;
+        }
+    }
+
+    Ok((task, source))
+}
+
+fn serialize<T: serde::Serialize>(value: &T) -> Result<Item, CargoMakeError> {
+    let content = toml::to_string(value)
+        .map_err(|error| CargoMakeError::DescriptorParseFailed(error.to_string()))?;
+    let document: DocumentMut = content.parse().map_err(|error: toml_edit::TomlError| {
+        CargoMakeError::DescriptorParseFailed(error.to_string())
+    })?;
+
+    Ok(Item::Table(document.as_table().clone()))
+}
+
+/// Sets the comment line of the value (tables get the comment above their header).
+fn annotate(item: &mut Item, key: &str, comment: &str) {
+    let comment = format!("# {}\n", comment);
+
+    if let Some(table) = item.as_table_mut() {
+        if let Some(value_item) = table.get_mut(key) {
+            if let Some(sub_table) = value_item.as_table_mut() {
+                sub_table.decor_mut().set_prefix(comment);
+                return;
+            }
+        }
+        if let Some(mut key) = table.key_mut(key) {
+            key.leaf_decor_mut().set_prefix(comment);
+        }
+    }
+}
+
+/// Returns the merged config output, limited to the provided task (and its alias chain)
+/// if defined.
+pub(crate) fn create_config_output(
+    config: &Config,
+    task: &Option<String>,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    build_file: &str,
+) -> Result<String, CargoMakeError> {
+    let active_profiles = explain_env::get_active_profiles(config);
+    let mut document = DocumentMut::new();
+
+    let mut config_item = serialize(&config.config)?;
+    if let Some(table) = config_item.as_table_mut() {
+        table
+            .decor_mut()
+            .set_prefix("# merged from all makefiles\n");
+    }
+    document.insert("config", config_item);
+
+    let (env, sources) = resolve_env(&config.env, env_origins, &active_profiles);
+    let mut env_item = serialize(&env)?;
+    if let Some(table) = env_item.as_table_mut() {
+        // keep every env value in a single line below its annotation
+        for (_, value) in table.iter_mut() {
+            if let Item::Table(sub_table) = value {
+                *value = Item::Value(Value::InlineTable(sub_table.clone().into_inline_table()));
+            }
+        }
+    }
+    for (key, source) in &sources {
+        annotate(&mut env_item, key, source);
+    }
+    document.insert("env", env_item);
+
+    if !config.vars.is_empty() {
+        document.insert("vars", serialize(&config.vars)?);
+    }
+
+    let task_names = match task {
+        Some(name) => vec![execution_plan::get_actual_task_name(config, name)?],
+        None => config.tasks.keys().cloned().collect(),
+    };
+    let mut tasks = IndexMap::new();
+    let mut task_sources = IndexMap::new();
+    for name in task_names {
+        let (task, source) = resolve_task(config, &name)?;
+        tasks.insert(name.clone(), task);
+        task_sources.insert(name, source);
+    }
+    let mut tasks_item = serialize(&tasks)?;
+    if let Some(table) = tasks_item.as_table_mut() {
+        table.set_implicit(true);
+    }
+    // attributes merged from earlier definitions of the task are not tracked separately
+    for (name, source) in &task_sources {
+        annotate(
+            &mut tasks_item,
+            name,
+            &format!("last defined in: {}", source),
+        );
+    }
+    document.insert("tasks", tasks_item);
+
+    let output = format!(
+        "# Merged config of: {}\n# Profile: {}\n\n{}",
+        build_file,
+        active_profiles.join(", "),
+        fmt::format(&document.to_string())?
+    );
+
+    Ok(secrets::mask(&output))
+}
+
+/// Prints the merged config to the output file or stdout
+pub(crate) fn run(
+    config: &Config,
+    task: &Option<String>,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    build_file: &str,
+    output_file: &Option<String>,
+) -> Result<(), CargoMakeError> {
+    let output = create_config_output(config, task, env_origins, build_file)?;
+
+    match output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use super::*;
+use crate::types::ConfigSection;
+
+fn create_origin(layer: &str, file: Option<&str>, profile: Option<&str>) -> EnvOrigin {
+    EnvOrigin {
+        file: file.map(|value| value.to_string()),
+        layer: layer.to_string(),
+        profile: profile.map(|value| value.to_string()),
+        value: EnvValue::Value("test".to_string()),
+    }
+}
+
+fn create_config(tasks: IndexMap<String, Task>) -> Config {
+    Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::from([("TEST".to_string(), EnvValue::Value("1".to_string()))]),
+        vars: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+    }
+}
+
+fn create_task(file: Option<&str>) -> Task {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+
+    if let Some(file) = file {
+        task.env = Some(IndexMap::from([
+            (
+                TASK_LOCATION_ENV[0].to_string(),
+                EnvValue::Value(file.to_string()),
+            ),
+            (
+                TASK_LOCATION_ENV[1].to_string(),
+                EnvValue::Value("/project".to_string()),
+            ),
+        ]));
+    }
+
+    task
+}
+
+#[test]
+fn describe_origin_no_file() {
+    let output = describe_origin(&create_origin("internal", None, None));
+
+    assert_eq!(output, "internal");
+}
+
+#[test]
+fn describe_origin_with_file() {
+    let output = describe_origin(&create_origin(
+        "makefile",
+        Some("/project/Makefile.toml"),
+        None,
+    ));
+
+    assert_eq!(output, "makefile: /project/Makefile.toml");
+}
+
+#[test]
+fn resolve_env_profiles() {
+    let env = IndexMap::from([
+        ("A".to_string(), EnvValue::Value("base".to_string())),
+        (
+            "development".to_string(),
+            EnvValue::Profile(IndexMap::from([
+                ("A".to_string(), EnvValue::Value("dev".to_string())),
+                ("B".to_string(), EnvValue::Value("dev".to_string())),
+            ])),
+        ),
+        (
+            "ci".to_string(),
+            EnvValue::Profile(IndexMap::from([(
+                "C".to_string(),
+                EnvValue::Value("ci".to_string()),
+            )])),
+        ),
+        (
+            "production".to_string(),
+            EnvValue::Profile(IndexMap::from([(
+                "A".to_string(),
+                EnvValue::Value("prod".to_string()),
+            )])),
+        ),
+    ]);
+    let env_origins = IndexMap::from([
+        (
+            "A".to_string(),
+            vec![
+                create_origin("internal", None, None),
+                create_origin("makefile", Some("Makefile.toml"), None),
+                create_origin("makefile", Some("Makefile.toml"), Some("development")),
+                create_origin("task: build", None, Some("development")),
+            ],
+        ),
+        (
+            "C".to_string(),
+            vec![create_origin("extend", Some("ci.toml"), Some("ci"))],
+        ),
+    ]);
+
+    let (resolved_env, sources) = resolve_env(
+        &env,
+        &env_origins,
+        &["development".to_string(), "ci".to_string()],
+    );
+
+    assert_eq!(
+        resolved_env.keys().collect::<Vec<&String>>(),
+        vec!["A", "C", "B"]
+    );
+    match resolved_env.get("A").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "dev"),
+        _ => panic!("invalid env value"),
+    };
+    assert_eq!(sources.get("A").unwrap(), "makefile: Makefile.toml");
+    assert_eq!(sources.get("C").unwrap(), "extend: ci.toml");
+    assert!(sources.get("B").is_none());
+}
+
+#[test]
+fn resolve_task_with_location_and_override() {
+    let mut task: Task = toml::from_str(
+        r#"
+linux = { command = "override" }
+windows = { command = "override" }
+mac = { command = "override" }
+"#,
+    )
+    .unwrap();
+    task.extend(&create_task(Some("/project/Makefile.toml")));
+    let config = create_config(IndexMap::from([("test".to_string(), task)]));
+
+    let (task, source) = resolve_task(&config, "test").unwrap();
+
+    assert_eq!(source, "/project/Makefile.toml");
+    assert_eq!(task.command.unwrap(), "override");
+    assert!(task.env.is_none());
+    assert!(task.linux.is_none());
+}
+
+#[test]
+fn resolve_task_internal() {
+    let config = create_config(IndexMap::from([("test".to_string(), create_task(None))]));
+
+    let (_, source) = resolve_task(&config, "test").unwrap();
+
+    assert_eq!(source, "internal");
+}
+
+#[test]
+fn create_config_output_all_tasks() {
+    let mut alias_task = Task::new();
+    alias_task.alias = Some("test".to_string());
+    let config = create_config(IndexMap::from([
+        (
+            "test".to_string(),
+            create_task(Some("/project/Makefile.toml")),
+        ),
+        ("alias".to_string(), alias_task),
+    ]));
+    let env_origins = IndexMap::from([(
+        "TEST".to_string(),
+        vec![create_origin(
+            "makefile",
+            Some("/project/Makefile.toml"),
+            None,
+        )],
+    )]);
+
+    let output = create_config_output(&config, &None, &env_origins, "Makefile.toml").unwrap();
+
+    assert!(output.starts_with("# Merged config of: Makefile.toml\n"));
+    assert!(output.contains("[env]\n# makefile: /project/Makefile.toml\nTEST = \"1\"\n"));
+    assert!(output
+        .contains("# last defined in: /project/Makefile.toml\n[tasks.test]\ncommand = \"echo\"\n"));
+    assert!(output.contains("# last defined in: internal\n[tasks.alias]\nalias = \"test\"\n"));
+    assert!(!output.contains("CARGO_MAKE_CURRENT_TASK_INITIAL_MAKEFILE"));
+
+    let printed_config: toml::Value = toml::from_str(&output).unwrap();
+    assert_eq!(printed_config["tasks"].as_table().unwrap().len(), 2);
+}
+
+#[test]
+fn create_config_output_single_task() {
+    let mut alias_task = Task::new();
+    alias_task.alias = Some("test".to_string());
+    let config = create_config(IndexMap::from([
+        (
+            "test".to_string(),
+            create_task(Some("/project/Makefile.toml")),
+        ),
+        ("alias".to_string(), alias_task),
+    ]));
+
+    let output = create_config_output(
+        &config,
+        &Some("alias".to_string()),
+        &IndexMap::new(),
+        "Makefile.toml",
+    )
+    .unwrap();
+
+    let printed_config: toml::Value = toml::from_str(&output).unwrap();
+    let tasks = printed_config["tasks"].as_table().unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert!(tasks.contains_key("test"));
+}
+
+#[test]
+fn create_config_output_task_not_found() {
+    let config = create_config(IndexMap::new());
+
+    let output = create_config_output(
+        &config,
+        &Some("missing".to_string()),
+        &IndexMap::new(),
+        "Makefile.toml",
+    );
+
+    assert!(output.is_err());
+}
@@ -0,0 +1,248 @@
+//! # print_env
+//!
+//! Prints the fully resolved environment of a task in multiple formats.
+//!
+
+#[cfg(test)]
+#[path = "print_env_test.rs"]
+mod print_env_test;
+
+use crate::cli_commands::explain_env;
+use crate::environment;
+use crate::environment::sources;
+use crate::error::CargoMakeError;
+use crate::execution_plan;
+use crate::execution_plan::ExecutionPlanBuilder;
+use crate::io;
+use crate::profile;
+use crate::secrets;
+use crate::types::{CliArgs, Config, CrateInfo, EnvOrigin, Step};
+use indexmap::IndexMap;
+use std::env;
+
+#[derive(Debug, PartialEq)]
+enum EnvFormat {
+    Dotenv,
+    Json,
+    Export,
+}
+
+fn get_format_type(output_format: &str) -> EnvFormat {
+    match output_format {
+        "json" => EnvFormat::Json,
+        "export" => EnvFormat::Export,
+        _ => EnvFormat::Dotenv,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct EnvEntry {
+    name: String,
+    value: String,
+    source: String,
+}
+
+fn escape_double_quoted(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn format_dotenv(entries: &[EnvEntry]) -> String {
+    let mut output = String::new();
+
+    for entry in entries {
+        output.push_str(&format!("# {}\n", &entry.source));
+        output.push_str(&format!(
+            "{}=\"{}\"\n",
+            &entry.name,
+            escape_double_quoted(&entry.value)
+        ));
+    }
+
+    output
+}
+
+fn format_export(entries: &[EnvEntry]) -> String {
+    let mut output = String::new();
+
+    for entry in entries {
+        output.push_str(&format!("# {}\n", &entry.source));
+        output.push_str(&format!(
+            "export {}='{}'\n",
+            &entry.name,
+            entry.value.replace('\'', "'\\''")
+        ));
+    }
+
+    output
+}
+
+fn format_json(entries: &[EnvEntry]) -> String {
+    let mut env_map = serde_json::Map::new();
+
+    for entry in entries {
+        let mut entry_map = serde_json::Map::new();
+        entry_map.insert("value".to_string(), entry.value.clone().into());
+        entry_map.insert("source".to_string(), entry.source.clone().into());
+
+        env_map.insert(entry.name.clone(), entry_map.into());
+    }
+
+    let mut output = serde_json::to_string_pretty(&env_map).unwrap_or_default();
+    output.push('\n');
+
+    output
+}
+
+/// Returns the env entries annotated with their source (makefile env values are annotated
+/// with the makefile which defined them)
+fn create_env_entries(
+    cli_env_keys: &[String],
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    active_profiles: &[String],
+) -> Vec<EnvEntry> {
+    let secret_keys = secrets::get_keys();
+
+    let mut entries: Vec<EnvEntry> = env::vars()
+        .map(|(name, value)| {
+            let source = if cli_env_keys.contains(&name) {
+                "cli (--env)".to_string()
+            } else {
+                let origins = env_origins
+                    .get(&name)
+                    .map(|origins| origins.as_slice())
+                    .unwrap_or_default();
+                explain_env::get_source(&name, &value, origins, active_profiles)
+            };
+
+            let value = if secret_keys.contains(&name) {
+                "***".to_string()
+            } else {
+                value
+            };
+
+            EnvEntry {
+                name,
+                value,
+                source,
+            }
+        })
+        .collect();
+    entries.sort_by(|first, second| first.name.cmp(&second.name));
+
+    entries
+}
+
+fn get_cli_env_keys(cli_env: &Option<Vec<String>>) -> Vec<String> {
+    match cli_env {
+        Some(env) => env
+            .iter()
+            .filter_map(|line| line.split_once('=').map(|(key, _)| key.to_string()))
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Applies the task level env (same as done before invoking the task) so it is part of
+/// the printed env and returns the step which env was applied.
+pub(crate) fn setup_task_env(
+    config: &Config,
+    task: &str,
+    crateinfo: &CrateInfo,
+    disable_workspace: bool,
+) -> Result<Option<Step>, CargoMakeError> {
+    let execution_plan = ExecutionPlanBuilder {
+        crate_info: Some(crateinfo),
+        disable_workspace,
+        ..ExecutionPlanBuilder::new(config, task)
+    }
+    .build()?;
+
+    let actual_task_name = execution_plan::get_actual_task_name(config, task)?;
+    let step = execution_plan
+        .steps
+        .iter()
+        .rev()
+        .find(|step| step.name == actual_task_name);
+
+    if let Some(step) = step {
+        let profile_name = profile::get();
+        let source = format!("task: {} env", &step.name);
+
+        if let Some(ref env) = step.config.env {
+            environment::set_current_task_meta_info_env(env.clone());
+        }
+        envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+
+        if let Some(ref env_files) = step.config.env_files {
+            environment::set_env_files(env_files.clone());
+        }
+        if let Some(ref env) = step.config.env {
+            sources::track(&source, || environment::set_env(env.clone()));
+        }
+
+        profile::set(&profile_name);
+    }
+
+    Ok(step.cloned())
+}
+
+/// Starts tracking the env sources, must be called before the env is setup.
+pub(crate) fn init() {
+    sources::enable();
+}
+
+/// Returns the resolved env of the given task in the requested format
+pub(crate) fn create_env_output(
+    config: &Config,
+    task: &str,
+    output_format: &str,
+    cli_env: &Option<Vec<String>>,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    crateinfo: &CrateInfo,
+    disable_workspace: bool,
+) -> Result<String, CargoMakeError> {
+    setup_task_env(config, task, crateinfo, disable_workspace)?;
+
+    let active_profiles = explain_env::get_active_profiles(config);
+    let entries = create_env_entries(&get_cli_env_keys(cli_env), env_origins, &active_profiles);
+
+    let output = match get_format_type(output_format) {
+        EnvFormat::Dotenv => format_dotenv(&entries),
+        EnvFormat::Json => format_json(&entries),
+        EnvFormat::Export => format_export(&entries),
+    };
+
+    Ok(output)
+}
+
+/// Prints the resolved env of the given task to the output file or stdout
+pub(crate) fn run(
+    config: &Config,
+    task: &str,
+    cli_args: &CliArgs,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+    crateinfo: &CrateInfo,
+) -> Result<(), CargoMakeError> {
+    let output = create_env_output(
+        config,
+        task,
+        &cli_args.output_format,
+        &cli_args.env,
+        env_origins,
+        crateinfo,
+        cli_args.disable_workspace,
+    )?;
+
+    match &cli_args.output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
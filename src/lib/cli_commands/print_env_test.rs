@@ -0,0 +1,197 @@
+use super::*;
+
+use crate::types::{ConfigSection, EnvValue, Task};
+use indexmap::IndexMap;
+
+fn create_entries() -> Vec<EnvEntry> {
+    vec![
+        EnvEntry {
+            name: "KEY1".to_string(),
+            value: "simple".to_string(),
+            source: "makefile env".to_string(),
+        },
+        EnvEntry {
+            name: "KEY2".to_string(),
+            value: "it's \"quoted\"\nvalue".to_string(),
+            source: "process env".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn get_format_type_default() {
+    assert_eq!(get_format_type("default"), EnvFormat::Dotenv);
+}
+
+#[test]
+fn get_format_type_json() {
+    assert_eq!(get_format_type("json"), EnvFormat::Json);
+}
+
+#[test]
+fn get_format_type_export() {
+    assert_eq!(get_format_type("export"), EnvFormat::Export);
+}
+
+#[test]
+fn format_dotenv_valid() {
+    let output = format_dotenv(&create_entries());
+
+    assert_eq!(
+        output,
+        "# makefile env\nKEY1=\"simple\"\n# process env\nKEY2=\"it's \\\"quoted\\\"\\nvalue\"\n"
+    );
+}
+
+#[test]
+fn format_export_valid() {
+    let output = format_export(&create_entries());
+
+    assert_eq!(
+        output,
+        "# makefile env\nexport KEY1='simple'\n# process env\nexport KEY2='it'\\''s \"quoted\"\nvalue'\n"
+    );
+}
+
+#[test]
+fn format_json_valid() {
+    let output = format_json(&create_entries());
+
+    let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(json["KEY1"]["value"], "simple");
+    assert_eq!(json["KEY1"]["source"], "makefile env");
+    assert_eq!(json["KEY2"]["value"], "it's \"quoted\"\nvalue");
+    assert_eq!(json["KEY2"]["source"], "process env");
+}
+
+#[test]
+fn get_cli_env_keys_valid() {
+    let keys = get_cli_env_keys(&Some(vec![
+        "KEY1=value".to_string(),
+        "KEY2=a=b".to_string(),
+        "invalid".to_string(),
+    ]));
+
+    assert_eq!(keys, vec!["KEY1", "KEY2"]);
+}
+
+#[test]
+fn get_cli_env_keys_none() {
+    let keys = get_cli_env_keys(&None);
+
+    assert!(keys.is_empty());
+}
+
+#[test]
+fn create_env_output_with_task_env() {
+    init();
+
+    let mut task_env = IndexMap::new();
+    task_env.insert(
+        "PRINT_ENV_TEST_TASK_ENV".to_string(),
+        EnvValue::Value("task value".to_string()),
+    );
+    let mut task = Task::new();
+    task.env = Some(task_env);
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("test".to_string(), task),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    envmnt::set("PRINT_ENV_TEST_CLI_ENV", "cli value");
+
+    let output = create_env_output(
+        &config,
+        "test",
+        "default",
+        &Some(vec!["PRINT_ENV_TEST_CLI_ENV=cli value".to_string()]),
+        &IndexMap::new(),
+        &CrateInfo::new(),
+        false,
+    )
+    .unwrap();
+
+    assert!(output.contains("# task: test env\nPRINT_ENV_TEST_TASK_ENV=\"task value\"\n"));
+    assert!(output.contains("# cli (--env)\nPRINT_ENV_TEST_CLI_ENV=\"cli value\"\n"));
+}
+
+#[test]
+fn create_env_output_masks_secrets() {
+    envmnt::set("PRINT_ENV_TEST_SECRET", "print-env-secret-value");
+    secrets::add("PRINT_ENV_TEST_SECRET");
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("test".to_string(), Task::new()),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    let output = create_env_output(
+        &config,
+        "test",
+        "export",
+        &None,
+        &IndexMap::new(),
+        &CrateInfo::new(),
+        false,
+    )
+    .unwrap();
+
+    assert!(output.contains("export PRINT_ENV_TEST_SECRET='***'\n"));
+    assert!(!output.contains("print-env-secret-value"));
+}
+
+#[test]
+fn create_env_entries_makefile_source() {
+    init();
+
+    sources::track(environment::MAKEFILE_ENV_SOURCE, || {
+        envmnt::set("PRINT_ENV_TEST_MAKEFILE_SOURCE", "makefile value")
+    });
+    let env_origins = IndexMap::from([(
+        "PRINT_ENV_TEST_MAKEFILE_SOURCE".to_string(),
+        vec![
+            EnvOrigin {
+                file: Some("/project/base.toml".to_string()),
+                layer: "extend".to_string(),
+                profile: None,
+                value: EnvValue::Value("base value".to_string()),
+            },
+            EnvOrigin {
+                file: Some("/project/Makefile.toml".to_string()),
+                layer: "makefile".to_string(),
+                profile: None,
+                value: EnvValue::Value("makefile value".to_string()),
+            },
+        ],
+    )]);
+
+    let entries = create_env_entries(&[], &env_origins, &["development".to_string()]);
+
+    let entry = entries
+        .iter()
+        .find(|entry| entry.name == "PRINT_ENV_TEST_MAKEFILE_SOURCE")
+        .unwrap();
+    assert_eq!(entry.value, "makefile value");
+    assert_eq!(entry.source, "makefile: /project/Makefile.toml");
+}
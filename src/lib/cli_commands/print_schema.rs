@@ -0,0 +1,1352 @@
+//! # print_schema
+//!
+//! Prints the JSON schema of the makefile (Makefile.toml) format.<br>
+//! The schema can be used by editors (for example via Taplo/Even Better TOML) to provide
+//! validation and completion for makefiles.
+//!
+
+#[cfg(test)]
+#[path = "print_schema_test.rs"]
+mod print_schema_test;
+
+use crate::cli::VERSION;
+use crate::error::CargoMakeError;
+use crate::io;
+use serde_json::{json, Map, Value};
+
+static SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn string_list() -> Value {
+    json!({ "type": "array", "items": string() })
+}
+
+fn string_map() -> Value {
+    json!({ "type": "object", "additionalProperties": string() })
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/definitions/{}", name) })
+}
+
+fn list_of(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn map_of(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+fn described(description: &str, schema: Value) -> Value {
+    let mut schema = schema;
+
+    if let Value::Object(ref mut schema_map) = schema {
+        schema_map.insert("description".to_string(), description.into());
+    }
+
+    schema
+}
+
+/// Creates a schema for one of the untagged enum variants
+fn any_of(description: &str, variants: Vec<Value>) -> Value {
+    json!({ "description": description, "anyOf": variants })
+}
+
+/// Creates a schema for a string based enum
+fn enum_of(description: &str, values: &[&str]) -> Value {
+    json!({ "description": description, "type": "string", "enum": values })
+}
+
+/// Creates an object schema from the (name, description, schema) properties.
+fn object(description: &str, properties: Vec<(&str, &str, Value)>, required: &[&str]) -> Value {
+    let mut properties_map = Map::new();
+
+    for (name, property_description, schema) in properties {
+        properties_map.insert(name.to_string(), described(property_description, schema));
+    }
+
+    let mut schema = json!({
+        "description": description,
+        "type": "object",
+        "properties": properties_map,
+        "additionalProperties": false,
+    });
+
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+
+    schema
+}
+
+fn condition_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "ConditionType".to_string(),
+        enum_of(
+            "Control how condition checks are evaluated (And - all conditions must pass, Or - any condition must pass, GroupOr - any condition group must pass)",
+            &["And", "Or", "GroupOr"],
+        ),
+    );
+    definitions.insert(
+        "RustVersionCondition".to_string(),
+        object(
+            "Rust version condition structure",
+            vec![
+                ("min", "min version number", string()),
+                ("max", "max version number", string()),
+                ("equal", "specific version number", string()),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "FilesFilesModifiedCondition".to_string(),
+        object(
+            "Files modified (input/output) condition structure",
+            vec![
+                ("input", "input files", string_list()),
+                ("output", "output files", string_list()),
+            ],
+            &["input", "output"],
+        ),
+    );
+    definitions.insert(
+        "TaskCondition".to_string(),
+        object(
+            "Holds condition attributes",
+            vec![
+                (
+                    "condition_type",
+                    "condition type (AND/OR) by default AND",
+                    reference("ConditionType"),
+                ),
+                ("fail_message", "Failure message", string()),
+                ("profiles", "Profile names (development, ...)", string_list()),
+                ("os", "As defined in the cfg target_os", string_list()),
+                (
+                    "platforms",
+                    "Platform names (linux, windows, mac)",
+                    string_list(),
+                ),
+                (
+                    "channels",
+                    "Channel names (stable, beta, nightly)",
+                    string_list(),
+                ),
+                (
+                    "env_set",
+                    "Environment variables which must be defined",
+                    string_list(),
+                ),
+                (
+                    "env_not_set",
+                    "Environment variables which must not be defined",
+                    string_list(),
+                ),
+                (
+                    "env",
+                    "Environment variables and their values",
+                    string_map(),
+                ),
+                (
+                    "env_not",
+                    "Environment variables and the values which they must not be defined as",
+                    string_map(),
+                ),
+                (
+                    "env_true",
+                    "Environment variables which are defined as true",
+                    string_list(),
+                ),
+                (
+                    "env_false",
+                    "Environment variables which are defined as false",
+                    string_list(),
+                ),
+                (
+                    "env_contains",
+                    "Environment variables and the values which they are required to contain",
+                    string_map(),
+                ),
+                (
+                    "rust_version",
+                    "Rust version condition",
+                    reference("RustVersionCondition"),
+                ),
+                ("files_exist", "Files exist", string_list()),
+                ("files_not_exist", "Files which do not exist", string_list()),
+                (
+                    "files_modified",
+                    "Files modified since last execution",
+                    reference("FilesFilesModifiedCondition"),
+                ),
+                (
+                    "crate_has_feature",
+                    "Crate features which must be defined in the crate manifest",
+                    string_list(),
+                ),
+                (
+                    "crate_has_dependency",
+                    "Crate dependencies (including dev and build dependencies) which must be defined in the crate manifest",
+                    string_list(),
+                ),
+                (
+                    "crate_has_target",
+                    "Crate target kinds (lib, bin, bench, example, test) which must be defined",
+                    string_list(),
+                ),
+                (
+                    "crate_is_workspace_member",
+                    "True/False if the crate must/must not be a workspace member",
+                    boolean(),
+                ),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "ConditionScriptValue".to_string(),
+        any_of(
+            "Condition script value (not as advanced as normal script value)",
+            vec![
+                described("The script text as single line", string()),
+                described("The script text lines", string_list()),
+            ],
+        ),
+    );
+}
+
+fn env_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "EnvFileInfo".to_string(),
+        object(
+            "Env file path and attributes",
+            vec![
+                ("path", "The file path as string", string()),
+                (
+                    "base_path",
+                    "The path base directory (relative paths are from this base path)",
+                    string(),
+                ),
+                (
+                    "profile",
+                    "The profile name this file is relevant to",
+                    string(),
+                ),
+                (
+                    "defaults_only",
+                    "If true, only set the env vars if not already defined",
+                    boolean(),
+                ),
+            ],
+            &["path"],
+        ),
+    );
+    definitions.insert(
+        "EnvFile".to_string(),
+        any_of(
+            "Holds the env file path and attributes",
+            vec![
+                described("The file path as string", string()),
+                reference("EnvFileInfo"),
+            ],
+        ),
+    );
+    definitions.insert(
+        "EnvValueScript".to_string(),
+        object(
+            "Env value provided by a script",
+            vec![
+                (
+                    "script",
+                    "The script to execute to get the env value",
+                    string_list(),
+                ),
+                (
+                    "multi_line",
+                    "True/False to enable multi line env values",
+                    boolean(),
+                ),
+                (
+                    "condition",
+                    "The condition to validate",
+                    reference("TaskCondition"),
+                ),
+                (
+                    "depends_on",
+                    "The explicit environment variables this script depends on",
+                    string_list(),
+                ),
+            ],
+            &["script"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueDecode".to_string(),
+        object(
+            "Env value provided by decoding other values",
+            vec![
+                (
+                    "source",
+                    "The source value (can be an env expression)",
+                    string(),
+                ),
+                (
+                    "default_value",
+                    "The default value in case no decode mapping was found, if not provided it will default to the source value",
+                    string(),
+                ),
+                ("mapping", "The decoding mapping", string_map()),
+                (
+                    "condition",
+                    "The condition to validate",
+                    reference("TaskCondition"),
+                ),
+            ],
+            &["source", "mapping"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueUnset".to_string(),
+        object(
+            "Enables to unset env variables",
+            vec![(
+                "unset",
+                "If true, the env variable will be unset, else ignored",
+                boolean(),
+            )],
+            &["unset"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueSecret".to_string(),
+        object(
+            "Env value which must never be printed out (logs, printed commands, etc...)",
+            vec![
+                ("secret", "True to mask the value in all outputs", boolean()),
+                (
+                    "value",
+                    "The secret value (can be an env expression)",
+                    string(),
+                ),
+                (
+                    "file",
+                    "The file to read the secret value from (can be an env expression)",
+                    string(),
+                ),
+                (
+                    "script",
+                    "The script to execute to get the secret value",
+                    string_list(),
+                ),
+            ],
+            &["secret"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueFromFile".to_string(),
+        object(
+            "Env values loaded from a structured (JSON/TOML/YAML) file",
+            vec![
+                (
+                    "from_file",
+                    "The structured file to load (can be an env expression)",
+                    string(),
+                ),
+                (
+                    "format",
+                    "The file format (json, toml, yaml), if not provided it is based on the file extension",
+                    string(),
+                ),
+                (
+                    "path",
+                    "Dot separated path to the document section to load (for example: services.api)",
+                    string(),
+                ),
+                (
+                    "prefix",
+                    "Optional prefix added to all env variable names",
+                    string(),
+                ),
+                (
+                    "condition",
+                    "The condition to validate",
+                    reference("TaskCondition"),
+                ),
+            ],
+            &["from_file"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueFromScript".to_string(),
+        object(
+            "Env values loaded from a script output (KEY=VALUE lines or JSON)",
+            vec![
+                (
+                    "from_script",
+                    "The script to execute to get the env values",
+                    string_list(),
+                ),
+                (
+                    "format",
+                    "The output format (dotenv, json, toml, yaml), if not provided it is detected from the output",
+                    string(),
+                ),
+                (
+                    "path",
+                    "Dot separated path to the document section to load (not supported for dotenv output)",
+                    string(),
+                ),
+                (
+                    "prefix",
+                    "Optional prefix added to all env variable names",
+                    string(),
+                ),
+                (
+                    "condition",
+                    "The condition to validate",
+                    reference("TaskCondition"),
+                ),
+            ],
+            &["from_script"],
+        ),
+    );
+    definitions.insert(
+        "EnvValueConditioned".to_string(),
+        object(
+            "Env value set if condition is met",
+            vec![
+                (
+                    "value",
+                    "The value to set (can be an env expression)",
+                    string(),
+                ),
+                (
+                    "condition",
+                    "The condition to validate",
+                    reference("TaskCondition"),
+                ),
+            ],
+            &["value"],
+        ),
+    );
+    definitions.insert(
+        "EnvValuePathGlob".to_string(),
+        object(
+            "Env value holding a list of paths based on given glob definitions",
+            vec![
+                ("glob", "The glob used to fetch all paths", string()),
+                (
+                    "include_files",
+                    "True to include files (default is true if undefined)",
+                    boolean(),
+                ),
+                (
+                    "include_dirs",
+                    "True to include directories (default is true if undefined)",
+                    boolean(),
+                ),
+                ("ignore_type", "Enables to respect ignore files", string()),
+            ],
+            &["glob"],
+        ),
+    );
+    definitions.insert(
+        "EnvValue".to_string(),
+        any_of(
+            "Holds the env value or script",
+            vec![
+                described("The value as string", string()),
+                described("The value as boolean", boolean()),
+                described("The value as number", json!({ "type": "integer" })),
+                described("The value as a list of strings", string_list()),
+                reference("EnvValueUnset"),
+                reference("EnvValueSecret"),
+                reference("EnvValueFromFile"),
+                reference("EnvValueFromScript"),
+                reference("EnvValueScript"),
+                reference("EnvValueDecode"),
+                reference("EnvValueConditioned"),
+                reference("EnvValuePathGlob"),
+                described("Profile env", map_of(reference("EnvValue"))),
+            ],
+        ),
+    );
+}
+
+fn install_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "TestArg".to_string(),
+        any_of(
+            "Arguments used to check whether a crate or rustup component is installed",
+            vec![string(), string_list()],
+        ),
+    );
+    definitions.insert(
+        "InstallCargoPluginInfo".to_string(),
+        object(
+            "Holds instructions how to install the cargo plugin",
+            vec![
+                ("crate_name", "The provided crate to install", string()),
+                ("min_version", "Minimal version", string()),
+                (
+                    "install_command",
+                    "Optional alternate 'install' command",
+                    string(),
+                ),
+                (
+                    "force",
+                    "Optional add force flag (if needed), default is true",
+                    boolean(),
+                ),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "InstallCrateInfo".to_string(),
+        object(
+            "Holds instructions how to install the crate",
+            vec![
+                ("crate_name", "The provided crate to install", string()),
+                (
+                    "rustup_component_name",
+                    "If defined, the component to install via rustup",
+                    string(),
+                ),
+                (
+                    "binary",
+                    "The binary file name to be used to test if the crate is already installed",
+                    string(),
+                ),
+                (
+                    "test_arg",
+                    "Test arguments that will be used to check that the crate is installed",
+                    reference("TestArg"),
+                ),
+                ("min_version", "Minimal version", string()),
+                ("version", "Exact version", string()),
+                (
+                    "install_command",
+                    "Optional alternate 'install' command",
+                    string(),
+                ),
+                (
+                    "force",
+                    "Optional add force flag (if needed), default is true",
+                    boolean(),
+                ),
+            ],
+            &["crate_name", "binary", "test_arg"],
+        ),
+    );
+    definitions.insert(
+        "InstallRustupComponentInfo".to_string(),
+        object(
+            "Holds instructions how to install a rustup component",
+            vec![
+                (
+                    "rustup_component_name",
+                    "The component to install via rustup",
+                    string(),
+                ),
+                (
+                    "binary",
+                    "The binary file name to be used to test if the crate is already installed",
+                    string(),
+                ),
+                (
+                    "test_arg",
+                    "Test argument that will be used to check that the crate is installed",
+                    reference("TestArg"),
+                ),
+            ],
+            &["rustup_component_name"],
+        ),
+    );
+    definitions.insert(
+        "InstallCrate".to_string(),
+        any_of(
+            "Install crate name or params",
+            vec![
+                described("Enables to prevent installation flow", boolean()),
+                described("The crate name", string()),
+                reference("InstallCrateInfo"),
+                reference("InstallRustupComponentInfo"),
+                reference("InstallCargoPluginInfo"),
+            ],
+        ),
+    );
+}
+
+fn run_task_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "RunTaskName".to_string(),
+        any_of(
+            "Holds the run task name/s",
+            vec![
+                described("Single task name", string()),
+                described("Multiple task names", string_list()),
+            ],
+        ),
+    );
+
+    let details_properties = || {
+        vec![
+            ("name", "The task name", reference("RunTaskName")),
+            (
+                "fork",
+                "True to fork the task to a new sub process",
+                boolean(),
+            ),
+            (
+                "parallel",
+                "True to run all tasks in parallel (default false)",
+                boolean(),
+            ),
+            ("cleanup_task", "Cleanup task name", string()),
+        ]
+    };
+    definitions.insert(
+        "RunTaskDetails".to_string(),
+        object(
+            "Holds the run task information",
+            details_properties(),
+            &["name"],
+        ),
+    );
+
+    let mut routing_properties = details_properties();
+    routing_properties.extend(vec![
+        (
+            "condition",
+            "if provided all condition values must be met in order for the task to be invoked",
+            reference("TaskCondition"),
+        ),
+        (
+            "condition_script",
+            "if script exit code is not 0, the task will not be invoked",
+            reference("ConditionScriptValue"),
+        ),
+        (
+            "condition_script_runner_args",
+            "The script runner arguments before the script file path",
+            string_list(),
+        ),
+    ]);
+    definitions.insert(
+        "RunTaskRoutingInfo".to_string(),
+        object(
+            "Holds the run task routing information",
+            routing_properties,
+            &["name"],
+        ),
+    );
+    definitions.insert(
+        "RunTaskInfo".to_string(),
+        any_of(
+            "Run task info",
+            vec![
+                described("Task name", string()),
+                reference("RunTaskDetails"),
+                described(
+                    "Task conditional selector",
+                    list_of(reference("RunTaskRoutingInfo")),
+                ),
+            ],
+        ),
+    );
+}
+
+fn task_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "WatchOptions".to_string(),
+        object(
+            "Holds watch options",
+            vec![
+                (
+                    "version",
+                    "Watch version to install if not already installed",
+                    string(),
+                ),
+                (
+                    "postpone",
+                    "Postpone first run until a file changes",
+                    boolean(),
+                ),
+                (
+                    "ignore_pattern",
+                    "Ignore a glob/gitignore-style pattern",
+                    any_of(
+                        "Could be an array or single value",
+                        vec![string(), string_list()],
+                    ),
+                ),
+                ("no_git_ignore", "Do not use .gitignore files", boolean()),
+                ("why", "Show paths that changed", boolean()),
+                (
+                    "watch",
+                    "Select which files/folders to watch",
+                    string_list(),
+                ),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "TaskWatchOptions".to_string(),
+        any_of(
+            "Holds watch options or simple true/false value",
+            vec![
+                described("True/False to enable/disable watch", boolean()),
+                reference("WatchOptions"),
+            ],
+        ),
+    );
+    definitions.insert(
+        "DeprecationInfo".to_string(),
+        any_of(
+            "Holds deprecation info such as true/false/message",
+            vec![
+                described("True/False flag (true is deprecated)", boolean()),
+                described("Deprecation message", string()),
+            ],
+        ),
+    );
+    definitions.insert(
+        "FileScriptValue".to_string(),
+        object(
+            "Script file name",
+            vec![
+                ("file", "Script file name", string()),
+                (
+                    "absolute_path",
+                    "True for absolute path (default false)",
+                    boolean(),
+                ),
+            ],
+            &["file"],
+        ),
+    );
+    definitions.insert(
+        "ScriptSections".to_string(),
+        object(
+            "Script content split to parts to enable a more fine tuned extension capability",
+            vec![
+                ("pre", "Script section", string()),
+                ("main", "Script section", string()),
+                ("post", "Script section", string()),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "ScriptValue".to_string(),
+        any_of(
+            "Script value (text, file name, ...)",
+            vec![
+                described("The script text as single line", string()),
+                described("The script text lines", string_list()),
+                reference("FileScriptValue"),
+                reference("ScriptSections"),
+            ],
+        ),
+    );
+    definitions.insert(
+        "ToolchainBoundedSpecifier".to_string(),
+        object(
+            "A toolchain with a minimum version bound",
+            vec![
+                ("channel", "The channel of the toolchain to use", string()),
+                ("min_version", "The minimum version to match", string()),
+            ],
+            &["channel", "min_version"],
+        ),
+    );
+    definitions.insert(
+        "ToolchainSpecifier".to_string(),
+        any_of(
+            "A toolchain, defined either as a string (following the rustup syntax) or a bounded specifier",
+            vec![
+                described(
+                    "A string specifying the channel name of the toolchain",
+                    string(),
+                ),
+                reference("ToolchainBoundedSpecifier"),
+            ],
+        ),
+    );
+    definitions.insert(
+        "TaskIdentifier".to_string(),
+        object(
+            "An identifier for a task",
+            vec![
+                ("name", "The task name to execute", string()),
+                (
+                    "path",
+                    "The path to the makefile the task resides in",
+                    string(),
+                ),
+            ],
+            &["name"],
+        ),
+    );
+    definitions.insert(
+        "DependencyIdentifier".to_string(),
+        any_of(
+            "A dependency, defined either as a string or as a task identifier object",
+            vec![
+                reference("TaskIdentifier"),
+                described(
+                    "A string dependency definition (its name in the current file)",
+                    string(),
+                ),
+            ],
+        ),
+    );
+    definitions.insert(
+        "Task".to_string(),
+        object(
+            "Holds a single task configuration such as command and dependencies list",
+            create_task_properties(false),
+            &[],
+        ),
+    );
+    definitions.insert(
+        "PlatformOverrideTask".to_string(),
+        object(
+            "Holds a single task configuration for a specific platform as an override of another task",
+            create_task_properties(true),
+            &[],
+        ),
+    );
+}
+
+/// Returns the task properties, platform override tasks do not support the task documentation,
+/// alias and nested platform override attributes.
+fn create_task_properties(platform_override: bool) -> Vec<(&'static str, &'static str, Value)> {
+    let mut properties = vec![(
+        "clear",
+        "if true, it should ignore all data in base task",
+        boolean(),
+    )];
+
+    if !platform_override {
+        properties.push(("description", "Task description", string()));
+        properties.push((
+            "category",
+            "Category name used to document the task",
+            string(),
+        ));
+    }
+
+    properties.extend(vec![
+        (
+            "disabled",
+            "if true, the command/script of this task will not be invoked, dependencies however will be",
+            boolean(),
+        ),
+        (
+            "private",
+            "if true, the task is hidden from the list of available tasks and also cannot be invoked directly from cli",
+            boolean(),
+        ),
+        (
+            "deprecated",
+            "if not false, this task is defined as deprecated",
+            reference("DeprecationInfo"),
+        ),
+        (
+            "extend",
+            "Extend any task based on the defined name",
+            string(),
+        ),
+    ]);
+
+    if !platform_override {
+        properties.push((
+            "template",
+            "Instantiate the task from the template based on the defined name",
+            string(),
+        ));
+        properties.push(("with", "The template parameters values", string_map()));
+        properties.push((
+            "workspace",
+            "set to false to notify cargo-make that this is not a workspace and should not call task for every member (same as --no-workspace CLI flag)",
+            boolean(),
+        ));
+    }
+
+    properties.extend(vec![
+        (
+            "plugin",
+            "Optional plugin used to execute the task",
+            string(),
+        ),
+        (
+            "watch",
+            "set to true to watch for file changes and invoke the task operation",
+            reference("TaskWatchOptions"),
+        ),
+        (
+            "condition",
+            "if provided all condition values must be met in order for the task to be invoked (will not stop dependencies)",
+            reference("TaskCondition"),
+        ),
+        (
+            "condition_script",
+            "if script exit code is not 0, the command/script of this task will not be invoked, dependencies however will be",
+            reference("ConditionScriptValue"),
+        ),
+        (
+            "condition_script_runner_args",
+            "The script runner arguments before the script file path",
+            string_list(),
+        ),
+        (
+            "ignore_errors",
+            "if true, any error while executing the task will be printed but will not break the build",
+            boolean(),
+        ),
+        (
+            "force",
+            "DEPRECATED, replaced with ignore_errors",
+            boolean(),
+        ),
+        (
+            "env_files",
+            "The env files to setup before running the task commands",
+            list_of(reference("EnvFile")),
+        ),
+        (
+            "env",
+            "The env vars to setup before running the task commands",
+            map_of(reference("EnvValue")),
+        ),
+    ]);
+
+    if !platform_override {
+        properties.push((
+            "export_env",
+            "if true, the task env and env files are kept after the task is done and are visible to the next tasks",
+            boolean(),
+        ));
+    }
+
+    properties.extend(vec![
+        (
+            "vars",
+            "The makefile variables to setup before running the task commands (not exported to child processes)",
+            map_of(reference("EnvValue")),
+        ),
+        (
+            "cwd",
+            "The working directory for the task to execute its command/script",
+            string(),
+        ),
+    ]);
+
+    if !platform_override {
+        properties.extend(vec![
+            (
+                "alias",
+                "if defined, task points to another task and all other properties are ignored",
+                string(),
+            ),
+            (
+                "linux_alias",
+                "acts like alias if runtime OS is Linux (takes precedence over alias)",
+                string(),
+            ),
+            (
+                "windows_alias",
+                "acts like alias if runtime OS is Windows (takes precedence over alias)",
+                string(),
+            ),
+            (
+                "mac_alias",
+                "acts like alias if runtime OS is Mac (takes precedence over alias)",
+                string(),
+            ),
+        ]);
+    }
+
+    properties.extend(vec![
+        (
+            "install_crate",
+            "if defined, the provided crate will be installed (if needed) before running the task",
+            reference("InstallCrate"),
+        ),
+        (
+            "install_crate_args",
+            "additional cargo install arguments",
+            string_list(),
+        ),
+        (
+            "install_script",
+            "if defined, the provided script will be executed before running the task",
+            reference("ScriptValue"),
+        ),
+        ("command", "The command to execute", string()),
+        ("args", "The command args", string_list()),
+        (
+            "script",
+            "If command is not defined, and script is defined, the provided script will be executed",
+            reference("ScriptValue"),
+        ),
+        (
+            "script_runner",
+            "The script runner (defaults to cmd in windows and sh for other platforms)",
+            string(),
+        ),
+        (
+            "script_runner_args",
+            "The script runner arguments before the script file path",
+            string_list(),
+        ),
+        ("script_extension", "The script file extension", string()),
+        (
+            "run_task",
+            "The task name to execute",
+            reference("RunTaskInfo"),
+        ),
+        (
+            "dependencies",
+            "A list of tasks to execute before this task",
+            list_of(reference("DependencyIdentifier")),
+        ),
+        (
+            "toolchain",
+            "The rust toolchain used to invoke the command or install the needed crates/components",
+            reference("ToolchainSpecifier"),
+        ),
+    ]);
+
+    if !platform_override {
+        properties.extend(vec![
+            (
+                "linux",
+                "override task if runtime OS is Linux (takes precedence over alias)",
+                reference("PlatformOverrideTask"),
+            ),
+            (
+                "windows",
+                "override task if runtime OS is Windows (takes precedence over alias)",
+                reference("PlatformOverrideTask"),
+            ),
+            (
+                "mac",
+                "override task if runtime OS is Mac (takes precedence over alias)",
+                reference("PlatformOverrideTask"),
+            ),
+        ]);
+    }
+
+    properties
+}
+
+fn config_definitions(definitions: &mut Map<String, Value>) {
+    definitions.insert(
+        "ModifyConfig".to_string(),
+        object(
+            "Holds properties to modify the core tasks",
+            vec![
+                (
+                    "private",
+                    "If true, all core tasks will be set to private (default false)",
+                    boolean(),
+                ),
+                (
+                    "namespace",
+                    "If set to some value, all core tasks are modified to: namespace::name for example default::build",
+                    string(),
+                ),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "UnstableFeature".to_string(),
+        enum_of(
+            "Unstable cargo-make feature (CTRL_C_HANDLING - gracefully shutdown and then kill the running command on Ctrl+C signal)",
+            &["CTRL_C_HANDLING"],
+        ),
+    );
+
+    definitions.insert(
+        "ConfigSection".to_string(),
+        object(
+            "Holds the configuration found in the makefile toml config section",
+            vec![
+                (
+                    "skip_core_tasks",
+                    "If true, the default core tasks will not be loaded",
+                    boolean(),
+                ),
+                (
+                    "modify_core_tasks",
+                    "Modify core tasks config",
+                    reference("ModifyConfig"),
+                ),
+                (
+                    "init_task",
+                    "Init task name which will be invoked at the start of every run",
+                    string(),
+                ),
+                (
+                    "end_task",
+                    "End task name which will be invoked at the end of every run",
+                    string(),
+                ),
+                (
+                    "on_error_task",
+                    "The name of the task to run in case of any error during the invocation of the flow",
+                    string(),
+                ),
+                (
+                    "legacy_migration_task",
+                    "The name of the task which runs legacy migration flows",
+                    string(),
+                ),
+                (
+                    "additional_profiles",
+                    "Additional profile names to load",
+                    string_list(),
+                ),
+                (
+                    "min_version",
+                    "Minimum cargo-make/makers version",
+                    string(),
+                ),
+                (
+                    "default_to_workspace",
+                    "The task.workspace default value",
+                    boolean(),
+                ),
+                (
+                    "skip_git_env_info",
+                    "do not load git env info (save on perf)",
+                    boolean(),
+                ),
+                (
+                    "skip_rust_env_info",
+                    "do not load rust env info (save on perf)",
+                    boolean(),
+                ),
+                (
+                    "skip_crate_env_info",
+                    "do not load current crate env info (save on perf)",
+                    boolean(),
+                ),
+                (
+                    "reduce_output",
+                    "True to reduce console output for non CI execution",
+                    boolean(),
+                ),
+                (
+                    "time_summary",
+                    "True to print time summary at the end of the flow",
+                    boolean(),
+                ),
+                (
+                    "load_cargo_aliases",
+                    "Automatically load cargo aliases as cargo-make tasks",
+                    boolean(),
+                ),
+                (
+                    "disable_install",
+                    "If true (default false) disable all automatic/defined installation instructions",
+                    boolean(),
+                ),
+                (
+                    "main_project_member",
+                    "The project information member (used by workspaces)",
+                    string(),
+                ),
+                (
+                    "load_script",
+                    "Invoked while loading the descriptor file but before loading any extended descriptor",
+                    reference("ScriptValue"),
+                ),
+                (
+                    "linux_load_script",
+                    "acts like load_script if runtime OS is Linux (takes precedence over load_script)",
+                    reference("ScriptValue"),
+                ),
+                (
+                    "windows_load_script",
+                    "acts like load_script if runtime OS is Windows (takes precedence over load_script)",
+                    reference("ScriptValue"),
+                ),
+                (
+                    "mac_load_script",
+                    "acts like load_script if runtime OS is Mac (takes precedence over load_script)",
+                    reference("ScriptValue"),
+                ),
+                (
+                    "unstable_features",
+                    "Enables unstable cargo-make features",
+                    list_of(reference("UnstableFeature")),
+                ),
+            ],
+            &[],
+        ),
+    );
+    definitions.insert(
+        "ExtendOptions".to_string(),
+        object(
+            "Extend with more fine tuning options",
+            vec![
+                ("path", "Path to another makefile", string()),
+                (
+                    "optional",
+                    "Enable optional extend (default to false)",
+                    boolean(),
+                ),
+                (
+                    "relative",
+                    "Relative to option, sub as current makefile, git root, crate root, workspace root, etc... Possible values: (makefile, git, crate, workspace)",
+                    string(),
+                ),
+                (
+                    "git",
+                    "Git repository url to extend from, the path is relative to the repository root",
+                    string(),
+                ),
+                (
+                    "rev",
+                    "Git revision (commit, tag or branch) to checkout, defaults to the default branch",
+                    string(),
+                ),
+                (
+                    "crate",
+                    "Crate dependency to extend from, the path is relative to the crate root",
+                    string(),
+                ),
+                (
+                    "namespace",
+                    "If set to some value, all the extended tasks are modified to: namespace::name",
+                    string(),
+                ),
+                (
+                    "private",
+                    "If true, all the extended tasks will be set to private (default false)",
+                    boolean(),
+                ),
+            ],
+            &["path"],
+        ),
+    );
+    definitions.insert(
+        "Extend".to_string(),
+        any_of(
+            "Holds makefile extend value",
+            vec![
+                described("Path to another makefile", string()),
+                reference("ExtendOptions"),
+                described("Multiple extends list", list_of(reference("ExtendOptions"))),
+            ],
+        ),
+    );
+    definitions.insert(
+        "Plugins".to_string(),
+        object(
+            "Holds the entire plugin config and implementation structure",
+            vec![
+                ("aliases", "The plugin name aliases", string_map()),
+                (
+                    "impl",
+                    "All plugin definitions",
+                    map_of(object(
+                        "Holds a plugin implementation",
+                        vec![("script", "The plugin script content", string())],
+                        &["script"],
+                    )),
+                ),
+            ],
+            &[],
+        ),
+    );
+}
+
+/// Returns the JSON schema of the makefile format
+pub(crate) fn create_schema() -> Value {
+    let mut definitions = Map::new();
+    condition_definitions(&mut definitions);
+    env_definitions(&mut definitions);
+    install_definitions(&mut definitions);
+    run_task_definitions(&mut definitions);
+    task_definitions(&mut definitions);
+    config_definitions(&mut definitions);
+
+    let mut schema = object(
+        &format!("cargo-make {} makefile (Makefile.toml)", VERSION),
+        vec![
+            (
+                "extend",
+                "Path to another toml file to extend",
+                reference("Extend"),
+            ),
+            ("config", "Runtime config", reference("ConfigSection")),
+            (
+                "env_files",
+                "The env files to setup before running the flow",
+                list_of(reference("EnvFile")),
+            ),
+            (
+                "env",
+                "The env vars to setup before running the flow",
+                map_of(reference("EnvValue")),
+            ),
+            (
+                "vars",
+                "The makefile variables to setup before running the flow (not exported to child processes)",
+                map_of(reference("EnvValue")),
+            ),
+            (
+                "env_scripts",
+                "The env scripts to execute before running the flow",
+                string_list(),
+            ),
+            (
+                "templates",
+                "All task templates definitions",
+                map_of(reference("Task")),
+            ),
+            (
+                "tasks",
+                "All task definitions",
+                map_of(reference("Task")),
+            ),
+            ("plugins", "All plugin definitions", reference("Plugins")),
+        ],
+        &[],
+    );
+    schema["$schema"] = SCHEMA_DRAFT.into();
+    schema["title"] = "cargo-make makefile".into();
+    schema["definitions"] = Value::Object(definitions);
+
+    schema
+}
+
+/// Prints the makefile JSON schema to the output file or stdout
+pub(crate) fn run(output_file: &Option<String>) -> Result<(), CargoMakeError> {
+    let mut output = serde_json::to_string_pretty(&create_schema()).unwrap_or_default();
+    output.push('\n');
+
+    match output_file {
+        Some(file) => {
+            io::write_text_file(file, &output);
+        }
+        None => print!("{}", output),
+    };
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use super::*;
+
+use crate::plugin::types::Plugins;
+use crate::test::get_temp_test_directory;
+use crate::types::{
+    ConfigSection, ExternalConfig, InstallCargoPluginInfo, ModifyConfig, PlatformOverrideTask,
+    RustVersionCondition, ScriptSections, Task, TaskCondition, WatchOptions,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn get_serialized_keys<T: Serialize>(value: &T) -> Vec<String> {
+    let mut keys: Vec<String> = match serde_json::to_value(value).unwrap() {
+        Value::Object(map) => map.keys().cloned().collect(),
+        _ => panic!("value is not serialized as an object"),
+    };
+    keys.sort();
+
+    keys
+}
+
+fn get_empty_serialized_keys<T: Serialize + DeserializeOwned>() -> Vec<String> {
+    let value: T = toml::from_str("").unwrap();
+
+    get_serialized_keys(&value)
+}
+
+fn get_schema_keys(schema: &Value) -> Vec<String> {
+    let mut keys: Vec<String> = schema["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect();
+    keys.sort();
+
+    keys
+}
+
+fn get_definition_keys(name: &str) -> Vec<String> {
+    get_schema_keys(&create_schema()["definitions"][name])
+}
+
+fn collect_references(value: &Value, references: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                match item {
+                    Value::String(reference) if key == "$ref" => {
+                        references.push(reference.to_string())
+                    }
+                    _ => collect_references(item, references),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_references(item, references);
+            }
+        }
+        _ => (),
+    }
+}
+
+#[test]
+fn create_schema_header() {
+    let schema = create_schema();
+
+    assert_eq!(schema["$schema"], SCHEMA_DRAFT);
+    assert_eq!(schema["title"], "cargo-make makefile");
+    assert!(schema["description"].as_str().unwrap().contains(VERSION));
+}
+
+#[test]
+fn create_schema_references_defined() {
+    let schema = create_schema();
+
+    let mut references = vec![];
+    collect_references(&schema, &mut references);
+    assert!(!references.is_empty());
+
+    for reference in references {
+        let name = reference.strip_prefix("#/definitions/").unwrap();
+        assert!(
+            schema["definitions"].get(name).is_some(),
+            "missing definition: {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn create_schema_env_value_variants() {
+    let schema = create_schema();
+
+    let variants = schema["definitions"]["EnvValue"]["anyOf"]
+        .as_array()
+        .unwrap();
+    assert_eq!(variants.len(), 13);
+}
+
+#[test]
+fn create_schema_in_sync_external_config() {
+    let schema = create_schema();
+
+    assert_eq!(
+        get_schema_keys(&schema),
+        get_serialized_keys(&ExternalConfig::new())
+    );
+}
+
+#[test]
+fn create_schema_in_sync_task() {
+    assert_eq!(
+        get_definition_keys("Task"),
+        get_serialized_keys(&Task::new())
+    );
+}
+
+#[test]
+fn create_schema_in_sync_platform_override_task() {
+    assert_eq!(
+        get_definition_keys("PlatformOverrideTask"),
+        get_empty_serialized_keys::<PlatformOverrideTask>()
+    );
+}
+
+#[test]
+fn create_schema_in_sync_task_condition() {
+    assert_eq!(
+        get_definition_keys("TaskCondition"),
+        get_serialized_keys(&TaskCondition::default())
+    );
+}
+
+#[test]
+fn create_schema_in_sync_config_section() {
+    assert_eq!(
+        get_definition_keys("ConfigSection"),
+        get_serialized_keys(&ConfigSection::new())
+    );
+}
+
+#[test]
+fn create_schema_in_sync_plugins() {
+    assert_eq!(
+        get_definition_keys("Plugins"),
+        get_serialized_keys(&Plugins::new())
+    );
+}
+
+#[test]
+fn create_schema_in_sync_other_types() {
+    assert_eq!(
+        get_definition_keys("WatchOptions"),
+        get_empty_serialized_keys::<WatchOptions>()
+    );
+    assert_eq!(
+        get_definition_keys("ModifyConfig"),
+        get_empty_serialized_keys::<ModifyConfig>()
+    );
+    assert_eq!(
+        get_definition_keys("RustVersionCondition"),
+        get_empty_serialized_keys::<RustVersionCondition>()
+    );
+    assert_eq!(
+        get_definition_keys("ScriptSections"),
+        get_empty_serialized_keys::<ScriptSections>()
+    );
+    assert_eq!(
+        get_definition_keys("InstallCargoPluginInfo"),
+        get_empty_serialized_keys::<InstallCargoPluginInfo>()
+    );
+}
+
+#[test]
+fn run_output_file() {
+    let file = get_temp_test_directory("print_schema").join("schema.json");
+    let file = file.to_str().unwrap().to_string();
+
+    run(&Some(file.clone())).unwrap();
+
+    let text = crate::io::read_text_file(&std::path::PathBuf::from(&file)).unwrap();
+    let schema: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(schema, create_schema());
+}
+
+#[test]
+fn create_schema_docs_in_sync() {
+    let text = crate::io::read_text_file(&std::path::PathBuf::from("./docs/makefile_schema.json"))
+        .unwrap();
+    let schema: Value = serde_json::from_str(&text).unwrap();
+
+    // regenerate using: cargo make generate-schema
+    assert_eq!(schema, create_schema());
+}
@@ -10,8 +10,11 @@ mod print_steps_test;
 use crate::error::CargoMakeError;
 use std::io;
 
+use crate::environment;
 use crate::execution_plan::ExecutionPlanBuilder;
-use crate::types::{Config, CrateInfo, ExecutionPlan};
+use crate::runner;
+use crate::secrets;
+use crate::types::{Config, CrateInfo, ExecutionPlan, FlowInfo};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -62,7 +65,7 @@ fn print_short_description(
             counter, &step.name, &description
         )?;
 
-        counter = counter + 1;
+        counter += 1;
     }
     Ok(())
 }
@@ -74,16 +77,76 @@ fn print_default(
     writeln!(output_buffer, "{:#?}", &execution_plan)
 }
 
-/// Only prints the execution plan
+fn print_condition_verdicts(
+    output_buffer: &mut impl io::Write,
+    execution_plan: &ExecutionPlan,
+    flow_info: &FlowInfo,
+    evaluate_condition_scripts: bool,
+) -> Result<(), CargoMakeError> {
+    writeln!(output_buffer, "Condition Verdicts:")?;
+    writeln!(
+        output_buffer,
+        "(evaluated with the current env, env set by earlier tasks in the plan is not applied)"
+    )?;
+    if evaluate_condition_scripts {
+        writeln!(
+            output_buffer,
+            "(condition scripts were invoked, failed scripts are reported without their exit code)"
+        )?;
+    } else {
+        writeln!(
+            output_buffer,
+            "(condition scripts are not invoked, use --why-condition-scripts to invoke them)"
+        )?;
+    }
+
+    for (index, step) in execution_plan.steps.iter().enumerate() {
+        let counter = index + 1;
+        if step.config.is_actionable() {
+            let verdict = runner::evaluate_condition(
+                flow_info,
+                &environment::expand_condition_script_runner_arguments(step),
+                evaluate_condition_scripts,
+            )?;
+
+            let status = if verdict.script_skipped {
+                "run if the condition script passes"
+            } else if verdict.passed {
+                "run"
+            } else {
+                "skip"
+            };
+            writeln!(output_buffer, "{}. {} - {}", counter, &step.name, status)?;
+
+            if verdict.reasons.is_empty() {
+                writeln!(output_buffer, "   * no conditions defined")?;
+            } else {
+                for reason in &verdict.reasons {
+                    writeln!(output_buffer, "   * {}", reason)?;
+                }
+            }
+        } else {
+            writeln!(output_buffer, "{}. {} - empty task", counter, &step.name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Only prints the execution plan.<br>
+/// If flow info is provided, the condition verdict of each step is printed as well (condition
+/// scripts are only invoked if evaluate_condition_scripts is true).
 pub fn print(
     output_buffer: &mut impl io::Write,
     config: &Config,
     task: &str,
+    additional_tasks: &[String],
     output_format: &str,
     disable_workspace: bool,
     skip_tasks_pattern: &Option<String>,
     crateinfo: &CrateInfo,
     skip_init_end_tasks: bool,
+    condition_flow_info: Option<&FlowInfo>,
+    evaluate_condition_scripts: bool,
 ) -> Result<(), CargoMakeError> {
     let skip_tasks_pattern_regex = match skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
@@ -101,6 +164,7 @@ pub fn print(
         disable_workspace,
         skip_tasks_pattern: skip_tasks_pattern_regex.as_ref(),
         skip_init_end_tasks,
+        additional_tasks,
         ..ExecutionPlanBuilder::new(&config, &task)
     }
     .build()?;
@@ -108,9 +172,25 @@ pub fn print(
 
     let print_format = get_format_type(&output_format);
 
+    let mut unmasked_buffer = Vec::<u8>::new();
     match print_format {
-        PrintFormat::ShortDescription => print_short_description(output_buffer, &execution_plan)?,
-        PrintFormat::Default => print_default(output_buffer, &execution_plan)?,
+        PrintFormat::ShortDescription => {
+            print_short_description(&mut unmasked_buffer, &execution_plan)?
+        }
+        PrintFormat::Default => print_default(&mut unmasked_buffer, &execution_plan)?,
     };
+
+    if let Some(flow_info) = condition_flow_info {
+        print_condition_verdicts(
+            &mut unmasked_buffer,
+            &execution_plan,
+            flow_info,
+            evaluate_condition_scripts,
+        )?;
+    }
+
+    let output = String::from_utf8_lossy(&unmasked_buffer);
+    write!(output_buffer, "{}", secrets::mask(&output))?;
+
     Ok(())
 }
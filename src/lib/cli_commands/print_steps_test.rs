@@ -1,5 +1,6 @@
 use super::*;
-use crate::types::{ConfigSection, Step, Task};
+use crate::test::create_empty_flow_info;
+use crate::types::{ConditionScriptValue, ConfigSection, Step, Task, TaskCondition};
 use indexmap::IndexMap;
 
 #[test]
@@ -29,6 +30,7 @@ fn print_default_format() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -39,11 +41,14 @@ fn print_default_format() {
         &mut std::io::stdout(),
         &config,
         "test",
+        &[],
         "default",
         false,
         &None,
         &CrateInfo::new(),
         false,
+        None,
+        false,
     )
     .expect("print should succeed");
 }
@@ -58,6 +63,7 @@ fn print_task_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -67,11 +73,14 @@ fn print_task_not_found() {
         &mut std::io::stdout(),
         &config,
         "test",
+        &[],
         "default",
         false,
         &None,
         &CrateInfo::new(),
         false,
+        None,
+        false,
     )
     .expect("print should succeed");
 }
@@ -85,6 +94,7 @@ fn print_task_not_found_but_skipped() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -94,11 +104,14 @@ fn print_task_not_found_but_skipped() {
         &mut std::io::stdout(),
         &config,
         "test",
+        &[],
         "default",
         false,
         &Some("test".to_string()),
         &CrateInfo::new(),
         false,
+        None,
+        false,
     )
     .expect("print should succeed");
 }
@@ -151,6 +164,7 @@ fn print_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut output_bytes = Vec::<u8>::new();
@@ -158,11 +172,14 @@ fn print_skip_init_end_tasks() {
         &mut output_bytes,
         &config,
         "entry",
+        &[],
         "default",
         false,
         &None,
         &CrateInfo::new(),
         true,
+        None,
+        false,
     )
     .expect("print should succeed");
     let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
@@ -179,3 +196,195 @@ fn print_skip_init_end_tasks() {
         end_task_name
     );
 }
+
+#[test]
+fn print_with_condition_verdicts() {
+    let mut skipped_task = Task::new();
+    skipped_task.command = Some("echo".to_string());
+    skipped_task.condition = Some(TaskCondition {
+        env_set: Some(vec!["PRINT_STEPS_TEST_ENV_NOT_DEFINED".to_string()]),
+        ..TaskCondition::default()
+    });
+    let mut invoked_task = Task::new();
+    invoked_task.command = Some("echo".to_string());
+    invoked_task.dependencies = Some(vec!["skipped".into()]);
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("skipped".to_string(), skipped_task),
+        ("entry".to_string(), invoked_task),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    let flow_info = create_empty_flow_info();
+
+    let mut output_bytes = Vec::<u8>::new();
+    print(
+        &mut output_bytes,
+        &config,
+        "entry",
+        &[],
+        "short-description",
+        false,
+        &None,
+        &CrateInfo::new(),
+        true,
+        Some(&flow_info),
+        false,
+    )
+    .expect("print should succeed");
+    let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
+
+    assert!(output.contains("Condition Verdicts:"));
+    assert!(output.contains("1. skipped - skip"));
+    assert!(
+        output.contains("   * env_set condition not met: [\"PRINT_STEPS_TEST_ENV_NOT_DEFINED\"]")
+    );
+    assert!(output.contains("2. entry - run"));
+    assert!(output.contains("   * no conditions defined"));
+}
+
+#[test]
+fn print_with_condition_verdicts_script_not_evaluated() {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.condition_script = Some(ConditionScriptValue::SingleLine("exit 1".to_string()));
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("entry".to_string(), task),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    let flow_info = create_empty_flow_info();
+
+    let mut output_bytes = Vec::<u8>::new();
+    print(
+        &mut output_bytes,
+        &config,
+        "entry",
+        &[],
+        "short-description",
+        false,
+        &None,
+        &CrateInfo::new(),
+        true,
+        Some(&flow_info),
+        false,
+    )
+    .expect("print should succeed");
+    let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
+
+    assert!(output.contains("env set by earlier tasks in the plan is not applied"));
+    assert!(output.contains("use --why-condition-scripts to invoke them"));
+    assert!(output.contains("1. entry - run if the condition script passes"));
+    assert!(output.contains("   * condition script: not evaluated"));
+}
+
+#[test]
+fn print_with_condition_verdicts_script_evaluated() {
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.condition_script = Some(ConditionScriptValue::SingleLine("exit 1".to_string()));
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("entry".to_string(), task),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    let flow_info = create_empty_flow_info();
+
+    let mut output_bytes = Vec::<u8>::new();
+    print(
+        &mut output_bytes,
+        &config,
+        "entry",
+        &[],
+        "short-description",
+        false,
+        &None,
+        &CrateInfo::new(),
+        true,
+        Some(&flow_info),
+        true,
+    )
+    .expect("print should succeed");
+    let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
+
+    assert!(output.contains("failed scripts are reported without their exit code"));
+    assert!(output.contains("1. entry - skip"));
+    assert!(output.contains("   * condition script failed (non zero exit code)"));
+}
+
+#[test]
+fn print_masks_secrets() {
+    envmnt::set("PRINT_STEPS_TEST_SECRET", "print-steps-secret-value");
+    secrets::add("PRINT_STEPS_TEST_SECRET");
+
+    let mut task = Task::new();
+    task.command = Some("echo".to_string());
+    task.args = Some(vec!["print-steps-secret-value".to_string()]);
+
+    let tasks = IndexMap::from([
+        ("init".to_string(), Task::new()),
+        ("end".to_string(), Task::new()),
+        ("test".to_string(), task),
+    ]);
+    let config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks,
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    let mut output_bytes = Vec::<u8>::new();
+    print(
+        &mut output_bytes,
+        &config,
+        "test",
+        &[],
+        "default",
+        false,
+        &None,
+        &CrateInfo::new(),
+        false,
+        None,
+        false,
+    )
+    .expect("print should succeed");
+    let output = std::str::from_utf8(&output_bytes).expect("output must be valid UTF-8 strings");
+
+    assert!(!output.contains("print-steps-secret-value"));
+    assert!(output.contains("***"));
+}
@@ -112,8 +112,23 @@ fn get_args(
     cli_args.allow_private = cli_parsed.arguments.contains("allow-private");
     cli_args.skip_init_end_tasks = cli_parsed.arguments.contains("skip-init-end-tasks");
     cli_args.list_all_steps = cli_parsed.arguments.contains("list-steps");
+    cli_args.list_profiles = cli_parsed.arguments.contains("list-profiles");
     cli_args.diff_execution_plan = cli_parsed.arguments.contains("diff-steps");
     cli_args.hide_uninteresting = cli_parsed.arguments.contains("hide-uninteresting");
+    cli_args.why = cli_parsed.arguments.contains("why");
+    cli_args.why_condition_scripts = cli_parsed.arguments.contains("why-condition-scripts");
+    cli_args.print_env = cli_parsed.arguments.contains("print-env");
+
+    cli_args.explain_env = cli_parsed.get_first_value("explain-env");
+    cli_args.explain = cli_parsed.get_first_value("explain");
+    cli_args.print_config = cli_parsed.arguments.contains("print-config");
+    cli_args.print_schema = cli_parsed.arguments.contains("print-schema");
+    cli_args.lint = cli_parsed.arguments.contains("lint");
+    cli_args.fmt = cli_parsed.arguments.contains("fmt");
+    cli_args.fmt_check = cli_parsed.arguments.contains("check");
+    cli_args.migrate = cli_parsed.arguments.contains("migrate");
+    cli_args.import = cli_parsed.get_first_value("import");
+    cli_args.pick = cli_parsed.arguments.contains("pick");
 
     cli_args.skip_tasks_pattern = match cli_parsed.get_first_value("skip-tasks-pattern") {
         Some(value) => Some(value.to_string()),
@@ -129,13 +144,37 @@ fn get_args(
         .unwrap_or(default_task_name);
     let task_cmd = to_owned_vec(cli_parsed.argument_values.get("TASK_CMD")).unwrap_or(vec![]);
     let task_cmd_slice = task_cmd.as_slice();
-    let (task, arguments) = match task_cmd_slice {
-        &[] => (task, None),
-        &[ref task_name, ref task_args @ ..] => {
+    let tasks: Vec<String> = match cli_parsed.get_first_value("tasks") {
+        Some(value) => value
+            .split(',')
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect(),
+        None => vec![],
+    };
+    let (task, arguments) = match (tasks.as_slice(), task_cmd_slice) {
+        (&[ref task_name, ref additional_tasks @ ..], _) => {
+            // all positional values are task arguments when the tasks are explicitly provided
+            cli_args.additional_tasks = additional_tasks.to_vec();
+            cli_args.tasks_provided = true;
+            let arguments = if task_cmd.is_empty() {
+                None
+            } else {
+                Some(task_cmd.clone())
+            };
+            (task_name.to_string(), arguments)
+        }
+        (_, &[]) => (task, None),
+        (_, &[ref task_name, ref task_args @ ..]) => {
             let args_strings = task_args.iter().map(|item| item.to_string()).collect();
             (task_name.to_string(), Some(args_strings))
         }
     };
+    cli_args.default_task =
+        !cli_parsed.arguments.contains("task") && task_cmd_slice.is_empty() && tasks.is_empty();
+    if cli_args.print_config && !cli_args.default_task {
+        cli_args.print_config_task = Some(task.clone());
+    }
     cli_args.task = task;
     cli_args.arguments = arguments;
 
@@ -170,7 +209,11 @@ pub fn create_cli(global_config: &GlobalConfig, mut spec: CliSpec, default_meta:
     add_arguments(spec, default_task_name, default_log_level)
 }
 
-fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str) -> CliSpec {
+pub(crate) fn add_arguments(
+    spec: CliSpec,
+    default_task_name: &str,
+    default_log_level: &str,
+) -> CliSpec {
     spec
         .add_argument(Argument {
             name: "help".to_string(),
@@ -210,6 +253,17 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "TASK".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "tasks".to_string(),
+            key: vec!["--tasks".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Comma separated list of tasks to execute in a single flow (all positional values are task arguments)".to_string(),
+                "TASKS".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "profile".to_string(),
             key: vec!["--profile".to_string(), "-p".to_string()],
@@ -228,8 +282,8 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
             value_type: ArgumentValueType::Single,
             default_value: None,
             help: Some(ArgumentHelp::TextAndParam(
-                "Will enable completion for the defined tasks for a given shell".to_string(),
-                "COMPLETION".to_string(),
+                "Prints the completion script for a given shell (bash, zsh, fish, powershell, elvish)".to_string(),
+                "SHELL".to_string(),
             )),
         })
         .add_argument(Argument {
@@ -415,7 +469,7 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
             value_type: ArgumentValueType::Single,
             default_value: None,
             help: Some(ArgumentHelp::TextAndParam(
-                "The list steps output file name".to_string(),
+                "The list steps/import output file name".to_string(),
                 "OUTPUT_FILE".to_string(),
             )),
         })
@@ -439,6 +493,139 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "Only prints the steps of the build in the order they will be invoked but without invoking them".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "print-env".to_string(),
+            key: vec!["--print-env".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only prints the resolved environment of the task (output format: dotenv, json, export) without invoking it".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "explain-env".to_string(),
+            key: vec!["--explain-env".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Only explains where the value of the env variable came from without invoking the task".to_string(),
+                "VAR".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "explain".to_string(),
+            key: vec!["--explain".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Only explains how the task was assembled (alias chain, extend ancestors, platform overrides, profile env and dependency tree) without invoking it".to_string(),
+                "TASK".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "print-config".to_string(),
+            key: vec!["--print-config".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only prints the merged config (all tasks or only the provided task) annotated with the makefile each value came from".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "print-schema".to_string(),
+            key: vec!["--print-schema".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only prints the makefile JSON schema (for editor validation and completion)".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "lint".to_string(),
+            key: vec!["--lint".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only validates the makefiles and reports all findings (fails if any finding was found)".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "fmt".to_string(),
+            key: vec!["--fmt".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only rewrites the makefile into the canonical layout".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "check".to_string(),
+            key: vec!["--check".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "With --fmt, only checks the makefile is in the canonical layout (fails if not) without modifying it".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "migrate".to_string(),
+            key: vec!["--migrate".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Only rewrites deprecated attributes and core task names in the makefile into their modern equivalents".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "import".to_string(),
+            key: vec!["--import".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::Single,
+            default_value: None,
+            help: Some(ArgumentHelp::TextAndParam(
+                "Only converts the tasks of a justfile, GNU Makefile or package.json file into a new makefile (written to the --output-file or Makefile.toml)".to_string(),
+                "FILE".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "pick".to_string(),
+            key: vec!["--pick".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Interactively picks the task to run from a filterable list of the public tasks (also done if no task is provided in an interactive terminal)".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "why".to_string(),
+            key: vec!["--why".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Prints the condition checks which caused each task to run or to be skipped".to_string(),
+            )),
+        })
+        .add_argument(Argument {
+            name: "why-condition-scripts".to_string(),
+            key: vec!["--why-condition-scripts".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "With --print-steps --why, also invokes the condition scripts (which may have side effects)".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "list-steps".to_string(),
             key: vec!["--list-all-steps".to_string()],
@@ -460,6 +647,16 @@ fn add_arguments(spec: CliSpec, default_task_name: &str, default_log_level: &str
                 "CATEGORY".to_string(),
             )),
         })
+        .add_argument(Argument {
+            name: "list-profiles".to_string(),
+            key: vec!["--list-profiles".to_string()],
+            argument_occurrence: ArgumentOccurrence::Single,
+            value_type: ArgumentValueType::None,
+            default_value: None,
+            help: Some(ArgumentHelp::Text(
+                "Lists the profiles defined in the makefiles".to_string(),
+            )),
+        })
         .add_argument(Argument {
             name: "diff-steps".to_string(),
             key: vec!["--diff-steps".to_string()],
@@ -33,6 +33,8 @@ fn assert_cli_args(cli_args_ref1: &CliArgs, cli_args_ref2: &CliArgs) {
     assert_eq!(cli_args1.command, cli_args2.command);
     assert_eq!(cli_args1.build_file, cli_args2.build_file);
     assert_eq!(cli_args1.task, cli_args2.task);
+    assert_eq!(cli_args1.additional_tasks, cli_args2.additional_tasks);
+    assert_eq!(cli_args1.tasks_provided, cli_args2.tasks_provided);
     assert_eq!(cli_args1.profile.unwrap(), cli_args2.profile.unwrap());
     assert_eq!(cli_args1.log_level, cli_args2.log_level);
     assert_eq!(cli_args1.disable_color, cli_args2.disable_color);
@@ -50,12 +52,26 @@ fn assert_cli_args(cli_args_ref1: &CliArgs, cli_args_ref2: &CliArgs) {
     );
     assert_eq!(cli_args1.print_only, cli_args2.print_only);
     assert_eq!(cli_args1.list_all_steps, cli_args2.list_all_steps);
+    assert_eq!(cli_args1.list_profiles, cli_args2.list_profiles);
     assert_eq!(cli_args1.diff_execution_plan, cli_args2.diff_execution_plan);
     assert_eq!(cli_args1.experimental, cli_args2.experimental);
     assert_eq!(cli_args1.arguments, cli_args2.arguments);
     assert_eq!(cli_args1.output_format, cli_args2.output_format);
     assert_eq!(cli_args1.output_file, cli_args2.output_file);
     assert_eq!(cli_args1.print_time_summary, cli_args2.print_time_summary);
+    assert_eq!(cli_args1.why, cli_args2.why);
+    assert_eq!(cli_args1.print_env, cli_args2.print_env);
+    assert_eq!(cli_args1.explain_env, cli_args2.explain_env);
+    assert_eq!(cli_args1.explain, cli_args2.explain);
+    assert_eq!(cli_args1.print_schema, cli_args2.print_schema);
+    assert_eq!(cli_args1.lint, cli_args2.lint);
+    assert_eq!(cli_args1.fmt, cli_args2.fmt);
+    assert_eq!(cli_args1.fmt_check, cli_args2.fmt_check);
+    assert_eq!(cli_args1.migrate, cli_args2.migrate);
+    assert_eq!(cli_args1.import, cli_args2.import);
+    assert_eq!(cli_args1.print_config, cli_args2.print_config);
+    assert_eq!(cli_args1.print_config_task, cli_args2.print_config_task);
+    assert_eq!(cli_args1.pick, cli_args2.pick);
 }
 
 #[test]
@@ -420,6 +436,161 @@ fn parse_args_print_steps() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_why() {
+    let cli_args = default_parse_cli_args(vec!["--why"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.why = true;
+
+    assert_cli_args(&cli_args, &expected);
+
+    let cli_args = default_parse_cli_args(vec!["--print-steps", "--why"]).unwrap();
+    expected.print_only = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_print_env() {
+    let cli_args = default_parse_cli_args(vec!["--print-env", "--output-format", "json"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_env = true;
+    expected.output_format = "json".to_string();
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_explain_env() {
+    let cli_args = default_parse_cli_args(vec!["--explain-env", "RUST_LOG"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.explain_env = Some("RUST_LOG".to_string());
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_print_schema() {
+    let cli_args =
+        default_parse_cli_args(vec!["--print-schema", "--output-file", "schema.json"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_schema = true;
+    expected.output_file = Some("schema.json".to_string());
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_lint() {
+    let cli_args = default_parse_cli_args(vec!["--lint"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.lint = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_fmt() {
+    let cli_args = default_parse_cli_args(vec!["--fmt"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.fmt = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_fmt_check() {
+    let cli_args = default_parse_cli_args(vec!["--fmt", "--check"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.fmt = true;
+    expected.fmt_check = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_migrate() {
+    let cli_args = default_parse_cli_args(vec!["--migrate"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.migrate = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_import() {
+    let cli_args = default_parse_cli_args(vec!["--import", "justfile"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.import = Some("justfile".to_string());
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_explain() {
+    let cli_args = default_parse_cli_args(vec!["--explain", "build"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.explain = Some("build".to_string());
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_print_config() {
+    let cli_args = default_parse_cli_args(vec!["--print-config"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_config = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_print_config_task() {
+    let cli_args = default_parse_cli_args(vec!["--print-config", "build"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.print_config = true;
+    expected.print_config_task = Some("build".to_string());
+    expected.task = "build".to_string();
+    expected.arguments = Some(vec![]);
+
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_pick() {
+    let cli_args = default_parse_cli_args(vec!["--pick"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.pick = true;
+
+    assert_cli_args(&cli_args, &expected);
+    assert!(cli_args.default_task);
+}
+
+#[test]
+fn parse_args_default_task() {
+    let mut cli_args = default_parse_cli_args(vec![]).unwrap();
+    assert!(cli_args.default_task);
+
+    cli_args = default_parse_cli_args(vec!["build"]).unwrap();
+    assert!(!cli_args.default_task);
+
+    cli_args = default_parse_cli_args(vec!["--task", "default"]).unwrap();
+    assert!(!cli_args.default_task);
+}
+
 #[test]
 fn parse_args_list_all_steps() {
     let cli_args = default_parse_cli_args(vec!["--list-all-steps"]).unwrap();
@@ -430,6 +601,16 @@ fn parse_args_list_all_steps() {
     assert_cli_args(&cli_args, &expected);
 }
 
+#[test]
+fn parse_args_list_profiles() {
+    let cli_args = default_parse_cli_args(vec!["--list-profiles"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.list_profiles = true;
+
+    assert_cli_args(&cli_args, &expected);
+}
+
 #[test]
 fn parse_args_list_category_steps() {
     let mut cli_args = default_parse_cli_args(vec!["--list-category-steps", "build"]).unwrap();
@@ -475,3 +656,47 @@ fn parse_args_task_cmd() {
     cli_args = default_parse_cli_args(vec!["--", "task1", "arg1", "arg2"]).unwrap();
     assert_cli_args(&cli_args, &expected);
 }
+
+#[test]
+fn parse_args_tasks() {
+    let mut cli_args = default_parse_cli_args(vec!["--tasks", "task1, task2,task3"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.task = "task1".to_string();
+    expected.additional_tasks = vec!["task2".to_string(), "task3".to_string()];
+    expected.tasks_provided = true;
+
+    assert_cli_args(&cli_args, &expected);
+    assert!(!cli_args.default_task);
+
+    cli_args = default_parse_cli_args(vec!["--tasks", "task1"]).unwrap();
+    expected.additional_tasks = vec![];
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_tasks_with_arguments() {
+    let mut cli_args = default_parse_cli_args(vec!["--tasks=task1,task2", "arg1", "arg2"]).unwrap();
+
+    let mut expected = default_parsed_cli_args();
+    expected.task = "task1".to_string();
+    expected.additional_tasks = vec!["task2".to_string()];
+    expected.tasks_provided = true;
+    expected.arguments = Some(vec!["arg1".to_string(), "arg2".to_string()]);
+
+    assert_cli_args(&cli_args, &expected);
+
+    cli_args =
+        default_parse_cli_args(vec!["--tasks", "task1,task2", "--", "arg1", "arg2"]).unwrap();
+    assert_cli_args(&cli_args, &expected);
+}
+
+#[test]
+fn parse_args_why_condition_scripts() {
+    let cli_args =
+        default_parse_cli_args(vec!["--print-steps", "--why", "--why-condition-scripts"]).unwrap();
+
+    assert!(cli_args.print_only);
+    assert!(cli_args.why);
+    assert!(cli_args.why_condition_scripts);
+}
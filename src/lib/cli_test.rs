@@ -1,6 +1,8 @@
 use super::*;
 use crate::cli_parser::create_cli;
+use crate::types::{ConfigSection, Task};
 use cliparser::types::CliSpec;
+use indexmap::IndexMap;
 use std::env;
 use std::path::Path;
 
@@ -15,6 +17,8 @@ fn run_makefile_not_found() {
             command: "cargo make".to_string(),
             build_file: Some("bad.toml".to_string()),
             task: "empty".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -31,6 +35,7 @@ fn run_makefile_not_found() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -38,6 +43,21 @@ fn run_makefile_not_found() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -55,6 +75,8 @@ fn run_empty_task() {
             command: "cargo make".to_string(),
             build_file: None,
             task: "empty".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -71,6 +93,7 @@ fn run_empty_task() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -78,6 +101,21 @@ fn run_empty_task() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -95,6 +133,8 @@ fn print_empty_task() {
             command: "cargo make".to_string(),
             build_file: None,
             task: "empty".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -111,6 +151,7 @@ fn print_empty_task() {
             print_only: true,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -118,6 +159,21 @@ fn print_empty_task() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -135,6 +191,8 @@ fn list_empty_task() {
             command: "cargo make".to_string(),
             build_file: None,
             task: "empty".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -151,6 +209,7 @@ fn list_empty_task() {
             print_only: false,
             list_all_steps: true,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -158,6 +217,21 @@ fn list_empty_task() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -175,6 +249,8 @@ fn run_file_and_task() {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -191,6 +267,7 @@ fn run_file_and_task() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -198,6 +275,21 @@ fn run_file_and_task() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -218,6 +310,8 @@ fn run_cwd_with_file() {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -234,6 +328,7 @@ fn run_cwd_with_file() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -241,6 +336,21 @@ fn run_cwd_with_file() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -259,6 +369,8 @@ fn run_file_not_go_to_project_root() {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -275,6 +387,7 @@ fn run_file_not_go_to_project_root() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -282,6 +395,21 @@ fn run_file_not_go_to_project_root() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -300,6 +428,8 @@ fn run_cwd_go_to_project_root_current_dir() {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -316,6 +446,7 @@ fn run_cwd_go_to_project_root_current_dir() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -323,6 +454,21 @@ fn run_cwd_go_to_project_root_current_dir() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -344,6 +490,8 @@ fn run_cwd_go_to_project_root_child_dir() {
             command: "cargo make".to_string(),
             build_file: Some("./examples/dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -360,6 +508,7 @@ fn run_cwd_go_to_project_root_child_dir() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -367,6 +516,21 @@ fn run_cwd_go_to_project_root_child_dir() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -388,6 +552,8 @@ fn run_cwd_task_not_found() {
             command: "cargo make".to_string(),
             build_file: Some("./dependencies.toml".to_string()),
             task: "A".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "error".to_string(),
             disable_color: true,
@@ -404,6 +570,7 @@ fn run_cwd_task_not_found() {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             experimental: false,
             arguments: None,
@@ -411,6 +578,21 @@ fn run_cwd_task_not_found() {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         },
         &global_config,
         None,
@@ -775,3 +957,92 @@ fn run_set_task_var_args() {
         "abc;-p;foo/bar/;def"
     );
 }
+
+fn create_split_tasks_config() -> Config {
+    let mut config = Config {
+        config: ConfigSection::new(),
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+    config.tasks.insert("build".to_string(), Task::new());
+    config.tasks.insert("test".to_string(), Task::new());
+
+    config
+}
+
+fn create_split_tasks_cli_args(arguments: Vec<&str>) -> CliArgs {
+    let mut cli_args = CliArgs::new();
+    cli_args.task = "build".to_string();
+    cli_args.arguments = Some(arguments.iter().map(|value| value.to_string()).collect());
+
+    cli_args
+}
+
+#[test]
+fn split_positional_tasks_with_arguments() {
+    let cli_args = create_split_tasks_cli_args(vec!["test", "--", "arg1", "arg2"]);
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert_eq!(output.task, "build");
+    assert_eq!(output.additional_tasks, vec!["test".to_string()]);
+    assert_eq!(
+        output.arguments,
+        Some(vec!["arg1".to_string(), "arg2".to_string()])
+    );
+}
+
+#[test]
+fn split_positional_tasks_no_arguments() {
+    let cli_args = create_split_tasks_cli_args(vec!["test", "--"]);
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert_eq!(output.additional_tasks, vec!["test".to_string()]);
+    assert!(output.arguments.is_none());
+}
+
+#[test]
+fn split_positional_tasks_unknown_task() {
+    let cli_args = create_split_tasks_cli_args(vec!["test", "arg1", "--", "arg2"]);
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert!(output.additional_tasks.is_empty());
+    assert_eq!(output.arguments, cli_args.arguments);
+}
+
+#[test]
+fn split_positional_tasks_tasks_provided() {
+    let mut cli_args = create_split_tasks_cli_args(vec!["test", "--", "arg1"]);
+    cli_args.tasks_provided = true;
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert!(output.additional_tasks.is_empty());
+    assert_eq!(output.arguments, cli_args.arguments);
+}
+
+#[test]
+fn split_positional_tasks_no_separator() {
+    let cli_args = create_split_tasks_cli_args(vec!["test", "arg1"]);
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert!(output.additional_tasks.is_empty());
+    assert_eq!(output.arguments, cli_args.arguments);
+}
+
+#[test]
+fn split_positional_tasks_leading_separator() {
+    let cli_args = create_split_tasks_cli_args(vec!["--", "test"]);
+
+    let output = split_positional_tasks(&cli_args, &create_split_tasks_config());
+
+    assert!(output.additional_tasks.is_empty());
+    assert_eq!(output.arguments, cli_args.arguments);
+}
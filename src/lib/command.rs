@@ -9,6 +9,7 @@ mod command_test;
 
 use crate::error::CargoMakeError;
 use crate::logger;
+use crate::process_env;
 use crate::toolchain;
 use crate::types::{CommandSpec, Step, UnstableFeature};
 use run_script::{IoOptions, ScriptError, ScriptOptions};
@@ -139,7 +140,109 @@ pub(crate) fn run_script_get_output(
         options.input_redirection = IoOptions::Pipe;
     }
 
-    run_script::run(script_lines.join("\n").as_str(), cli_arguments, &options)
+    run_script(script_lines.join("\n").as_str(), cli_arguments, &options)
+}
+
+/// Adds the script runner setup lines (exit on error and commands printing), after the
+/// shebang line if defined.
+fn modify_script(script: &str, options: &ScriptOptions) -> String {
+    let mut script_lines: Vec<String> = script
+        .trim()
+        .split("\n")
+        .map(|line| line.to_string())
+        .collect();
+
+    let mut insert_index = if !script_lines.is_empty() && script_lines[0].starts_with("#!") {
+        1
+    } else {
+        0
+    };
+
+    if cfg!(windows) {
+        if !options.print_commands {
+            script_lines.insert(insert_index, "@echo off".to_string());
+        }
+    } else {
+        if options.exit_on_error {
+            script_lines.insert(insert_index, "set -e".to_string());
+            insert_index += 1;
+        }
+
+        if options.print_commands {
+            script_lines.insert(insert_index, "set -x".to_string());
+        }
+    }
+
+    script_lines.push("\n".to_string());
+
+    script_lines.join("\n")
+}
+
+/// Same as run_script::run, but the script process gets the task env and working directory
+/// explicitly instead of inheriting the shared process env.
+fn run_script(
+    script: &str,
+    cli_arguments: &Vec<String>,
+    options: &ScriptOptions,
+) -> Result<(i32, String, String), ScriptError> {
+    let extension = if cfg!(windows) { "bat" } else { "sh" };
+    let file = fsio::path::get_temporary_file_path(extension);
+    if let Err(error) = fsio::file::write_text_file(&file, &modify_script(script, options)) {
+        fsio::file::delete_ignore_error(&file);
+        return Err(ScriptError::FsIOError(error));
+    }
+
+    let runner = match options.runner {
+        Some(ref runner) => runner.to_string(),
+        None => {
+            if cfg!(windows) {
+                "cmd.exe".to_string()
+            } else {
+                "sh".to_string()
+            }
+        }
+    };
+
+    let mut command = Command::new(&runner);
+    if let Some(ref runner_args) = options.runner_args {
+        command.args(runner_args);
+    }
+    if runner == "cmd.exe" || runner == "cmd" {
+        command
+            .arg("/C")
+            .arg(fsio::path::canonicalize_or(&file, &file));
+    } else {
+        command.arg(&file);
+    }
+    command.args(cli_arguments);
+
+    command.stdin(get_stdio(&options.input_redirection));
+    command
+        .stdout(get_stdio(&options.output_redirection))
+        .stderr(get_stdio(&options.output_redirection));
+
+    process_env::apply_to_command(&mut command);
+
+    let output = command.output();
+
+    fsio::file::delete_ignore_error(&file);
+
+    match output {
+        Ok(output) => Ok((
+            get_exit_code(Ok(output.status), true),
+            String::from_utf8_lossy(&output.stdout).into_owned(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        )),
+        Err(error) => Err(ScriptError::IOError(error)),
+    }
+}
+
+fn get_stdio(io_options: &IoOptions) -> Stdio {
+    match io_options {
+        IoOptions::Null => Stdio::null(),
+        IoOptions::Inherit => Stdio::inherit(),
+        IoOptions::Pipe => Stdio::piped(),
+    }
 }
 
 /// Runs the requested script text and panics in case of any script error.
@@ -186,20 +289,21 @@ pub(crate) fn run_command_get_output(
 
     if silent {
         command.stdout(Stdio::null()).stderr(Stdio::null());
-    } else if ctrl_c_handling {
-        if capture_output {
-            command.stdout(Stdio::piped()).stderr(Stdio::piped());
-        }
-    } else if !capture_output {
+    } else if capture_output {
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    } else {
         command.stdout(Stdio::inherit()).stderr(Stdio::inherit());
     }
 
     info!("Execute Command: {:?}", &command);
 
+    // the task env is applied after logging the command as it may contain secrets
+    process_env::apply_to_command(&mut command);
+
     let output = if ctrl_c_handling {
         spawn_command(command)
     } else {
-        command.output()
+        command.spawn().and_then(|child| child.wait_with_output())
     };
 
     debug!("Output: {:#?}", &output);
@@ -250,3 +250,59 @@ fn run_script_get_exit_code_cli_args_error() {
     )
     .unwrap();
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_script_get_exit_code_removed_env() {
+    envmnt::set("COMMAND_TEST_SCRIPT_REMOVED_ENV", "1");
+
+    let mut process_env = process_env::ProcessEnv::default();
+    process_env
+        .env
+        .insert("COMMAND_TEST_SCRIPT_REMOVED_ENV".to_string(), None);
+
+    let exit_code = process_env::run_with(process_env, || {
+        run_script_get_exit_code(
+            &vec!["test -z \"${COMMAND_TEST_SCRIPT_REMOVED_ENV}\"".to_string()],
+            None,
+            &vec![],
+            false,
+        )
+        .unwrap()
+    });
+
+    assert_eq!(exit_code, 0);
+    assert_eq!(envmnt::get_or_panic("COMMAND_TEST_SCRIPT_REMOVED_ENV"), "1");
+
+    envmnt::remove("COMMAND_TEST_SCRIPT_REMOVED_ENV");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn run_script_get_output_task_env_and_cwd() {
+    let mut process_env = process_env::ProcessEnv::default();
+    process_env.env.insert(
+        "COMMAND_TEST_SCRIPT_TASK_ENV".to_string(),
+        Some("test".to_string()),
+    );
+    process_env.cwd = Some(std::env::current_dir().unwrap().join("src"));
+
+    let (exit_code, stdout, _) = process_env::run_with(process_env, || {
+        run_script_get_output(
+            &vec![
+                "#!/bin/sh".to_string(),
+                "echo ${COMMAND_TEST_SCRIPT_TASK_ENV}".to_string(),
+                "basename $(pwd)".to_string(),
+            ],
+            None,
+            &vec![],
+            true,
+            Some(false),
+        )
+        .unwrap()
+    });
+
+    assert_eq!(exit_code, 0);
+    assert_eq!(stdout.trim(), "test\nsrc");
+    assert!(!envmnt::exists("COMMAND_TEST_SCRIPT_TASK_ENV"));
+}
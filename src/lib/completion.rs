@@ -1,112 +1,549 @@
-use log::{error, info};
-use std::io::BufRead;
-use std::path::Path;
-use std::{fs, io};
-
-/// # Completions Module
-///
-/// This module handles the generation of shell completion scripts for the `cargo-make` tool.
-///
-/// ## Functionality
-/// - `generate_completion_zsh`: Generates a Zsh completion script, creates the necessary directory,
-///   and prompts for overwriting existing files.
-///
-/// ## Improvements to Consider
-/// 1. **Modularity**: Separate the completion logic into different modules for different shells
-///    (e.g., Zsh, Bash, Fish) to improve code organization.
-/// 2. **Cross-Platform Support**: Abstract the completion generation into a trait or interface
-///    to facilitate adding support for other shell types.
-/// 3. **Enhanced Error Handling**: Provide more informative error messages for file operations.
-/// 4. **User Input Handling**: Ensure user input is trimmed and handled correctly.
-/// 5. **Testing**: Implement unit tests to verify the correct behavior of completion generation functions.
+//! # completion
+//!
+//! Generates the shell completion scripts (bash, zsh, fish, powershell and elvish).<br>
+//! The flags are taken from the cli spec while the task and profile names are resolved
+//! when completing, by invoking makers in the current directory.<br>
+//! The scripts complete the makers executable as well as the cargo make sub command (and the
+//! cargo-make executable), other cargo sub commands are left to the existing cargo completion.
+//!
 
 #[cfg(test)]
 #[path = "completion_test.rs"]
 mod completion_test;
 
-pub fn generate_completions(shell: &str) -> Result<(), Box<dyn std::error::Error>> {
-    match shell {
-        "zsh" => {
-            generate_completion_zsh(None)?; // Use the `?` operator to propagate errors
-            Ok(()) // Return Ok if no error occurred
-        }
-        _ => {
-            // Return an error for unsupported shell
-            Err(Box::from(format!(
-                "Unsupported shell for completion: {}",
-                shell
-            )))
+use crate::cli::{DEFAULT_LOG_LEVEL, DEFAULT_TASK_NAME};
+use crate::cli_parser;
+use cliparser::types::{ArgumentHelp, ArgumentOccurrence, ArgumentValueType, CliSpec};
+
+/// The shells which completion scripts can be generated for
+pub(crate) static SHELLS: [&str; 5] = ["bash", "zsh", "fish", "powershell", "elvish"];
+
+static LOG_LEVELS: [&str; 4] = ["verbose", "info", "error", "off"];
+
+static OUTPUT_FORMATS: [&str; 9] = [
+    "default",
+    "short-description",
+    "markdown",
+    "markdown-single-page",
+    "markdown-sub-section",
+    "autocomplete",
+    "dotenv",
+    "json",
+    "export",
+];
+
+/// The completed executable
+static COMMAND: &str = "makers";
+
+/// The completed cargo sub command executable
+static CARGO_COMMAND: &str = "cargo-make";
+
+/// Prints the task names (space separated)
+static TASKS_COMMAND: &str =
+    "makers --loglevel error --list-all-steps --output-format autocomplete";
+
+/// Prints the profile names (one per line)
+static PROFILES_COMMAND: &str = "makers --loglevel error --list-profiles";
+
+#[derive(Debug, Clone, PartialEq)]
+/// The completion of a flag value
+enum ValueHint {
+    /// Any text
+    Text,
+    /// The task names
+    Tasks,
+    /// The profile names
+    Profiles,
+    /// File paths
+    Files,
+    /// Directory paths
+    Directories,
+    /// One of the provided values
+    Values(Vec<&'static str>),
+}
+
+#[derive(Debug, Clone)]
+/// Holds the completion info of a single cli argument
+struct Flag {
+    /// All the flag keys, for example: --task and -t
+    keys: Vec<String>,
+    /// The flag help text
+    description: String,
+    /// The value name (for example: task) if the flag accepts a value
+    value_name: Option<String>,
+    /// The value completion if the flag accepts a value
+    value_hint: Option<ValueHint>,
+    /// True if the flag can appear multiple times
+    multiple: bool,
+}
+
+fn get_value_hint(name: &str) -> ValueHint {
+    match name {
+        "task" | "explain" => ValueHint::Tasks,
+        "profile" => ValueHint::Profiles,
+        "makefile" | "envfile" | "output-file" | "import" => ValueHint::Files,
+        "cwd" => ValueHint::Directories,
+        "loglevel" => ValueHint::Values(LOG_LEVELS.to_vec()),
+        "output-format" => ValueHint::Values(OUTPUT_FORMATS.to_vec()),
+        "completion" => ValueHint::Values(SHELLS.to_vec()),
+        _ => ValueHint::Text,
+    }
+}
+
+/// Returns the completion info of all the cli arguments
+fn get_flags() -> Vec<Flag> {
+    let spec = cli_parser::add_arguments(CliSpec::new(), DEFAULT_TASK_NAME, DEFAULT_LOG_LEVEL);
+
+    spec.arguments
+        .iter()
+        .map(|argument| {
+            let (description, param) = match argument.help {
+                Some(ArgumentHelp::Text(ref text)) => (text.to_string(), None),
+                Some(ArgumentHelp::TextAndParam(ref text, ref param)) => {
+                    (text.to_string(), Some(param.to_lowercase()))
+                }
+                None => ("".to_string(), None),
+            };
+
+            let (value_name, value_hint) = match argument.value_type {
+                ArgumentValueType::None => (None, None),
+                _ => (
+                    Some(param.unwrap_or("value".to_string())),
+                    Some(get_value_hint(&argument.name)),
+                ),
+            };
+
+            Flag {
+                keys: argument.key.clone(),
+                description,
+                value_name,
+                value_hint,
+                multiple: argument.argument_occurrence == ArgumentOccurrence::Multiple,
+            }
+        })
+        .collect()
+}
+
+/// Escapes the value for a sh/zsh single quoted string
+fn escape_sh(value: &str) -> String {
+    value.replace('\'', "'\\''")
+}
+
+/// Escapes the value for a fish single quoted string
+fn escape_fish(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+/// Escapes the value for a powershell/elvish single quoted string
+fn escape_doubled_quote(value: &str) -> String {
+    value.replace('\'', "''")
+}
+
+fn create_bash(flags: &[Flag]) -> String {
+    let mut cases = String::new();
+    for flag in flags {
+        let action = match flag.value_hint {
+            Some(ValueHint::Tasks) => format!(
+                "COMPREPLY=($(compgen -W \"$({} 2>/dev/null)\" -- \"${{current}}\"))",
+                TASKS_COMMAND
+            ),
+            Some(ValueHint::Profiles) => format!(
+                "COMPREPLY=($(compgen -W \"$({} 2>/dev/null)\" -- \"${{current}}\"))",
+                PROFILES_COMMAND
+            ),
+            Some(ValueHint::Files) => "COMPREPLY=($(compgen -f -- \"${current}\"))".to_string(),
+            Some(ValueHint::Directories) => {
+                "COMPREPLY=($(compgen -d -- \"${current}\"))".to_string()
+            }
+            Some(ValueHint::Values(ref values)) => format!(
+                "COMPREPLY=($(compgen -W \"{}\" -- \"${{current}}\"))",
+                values.join(" ")
+            ),
+            Some(ValueHint::Text) => "COMPREPLY=()".to_string(),
+            None => continue,
+        };
+
+        cases.push_str(&format!(
+            "        {})\n            {}\n            return\n            ;;\n",
+            flag.keys.join("|"),
+            action
+        ));
+    }
+
+    let keys: Vec<String> = flags.iter().flat_map(|flag| flag.keys.clone()).collect();
+
+    format!(
+        r#"# bash completion for {command} (generated by: {command} --completion bash)
+
+_{command}() {{
+    local current="${{COMP_WORDS[COMP_CWORD]}}"
+    local previous="${{COMP_WORDS[COMP_CWORD-1]}}"
+
+    case "${{previous}}" in
+{cases}    esac
+
+    if [[ "${{current}}" == -* ]]; then
+        COMPREPLY=($(compgen -W "{keys}" -- "${{current}}"))
+    else
+        COMPREPLY=($(compgen -W "$({tasks} 2>/dev/null)" -- "${{current}}"))
+    fi
+}}
+
+complete -o default -F _{command} {command} {cargo_command}
+
+# cargo make completion, other cargo sub commands are delegated to the existing cargo completion
+if [[ "$(complete -p cargo 2>/dev/null)" != *_{command}_cargo* ]]; then
+    _{command}_cargo_completion="$(complete -p cargo 2>/dev/null | sed -n 's/.*-F \([^ ]*\).*/\1/p')"
+fi
+
+_{command}_cargo() {{
+    if [[ ${{COMP_CWORD}} -gt 1 && "${{COMP_WORDS[1]}}" == "make" ]]; then
+        _{command}
+    elif [[ -n "${{_{command}_cargo_completion}}" ]]; then
+        "${{_{command}_cargo_completion}}" "$@"
+    fi
+}}
+
+complete -o default -F _{command}_cargo cargo
+"#,
+        command = COMMAND,
+        cargo_command = CARGO_COMMAND,
+        cases = cases,
+        keys = keys.join(" "),
+        tasks = TASKS_COMMAND
+    )
+}
+
+fn create_zsh(flags: &[Flag]) -> String {
+    let mut specs = String::new();
+    for flag in flags {
+        let description = escape_sh(&flag.description)
+            .replace('[', "\\[")
+            .replace(']', "\\]");
+        let action = match flag.value_hint {
+            Some(ref value_hint) => {
+                let value_name = flag.value_name.clone().unwrap_or_default();
+                let value_action = match value_hint {
+                    ValueHint::Tasks => format!("_{}_tasks", COMMAND),
+                    ValueHint::Profiles => format!("_{}_profiles", COMMAND),
+                    ValueHint::Files => "_files".to_string(),
+                    ValueHint::Directories => "_files -/".to_string(),
+                    ValueHint::Values(ref values) => format!("({})", values.join(" ")),
+                    ValueHint::Text => " ".to_string(),
+                };
+                format!(":{}:{}", value_name, value_action)
+            }
+            None => "".to_string(),
+        };
+        let prefix = if flag.multiple { "*" } else { "" };
+
+        for key in &flag.keys {
+            specs.push_str(&format!(
+                "        '{}{}[{}]{}' \\\n",
+                prefix, key, description, action
+            ));
         }
     }
+
+    format!(
+        r#"#compdef {command} {cargo_command}
+
+# zsh completion for {command} (generated by: {command} --completion zsh)
+
+_{command}_tasks() {{
+    local -a tasks
+    tasks=($({tasks} 2>/dev/null))
+    _wanted tasks expl 'task' compadd -a tasks
+}}
+
+_{command}_profiles() {{
+    local -a profiles
+    profiles=($({profiles} 2>/dev/null))
+    _wanted profiles expl 'profile' compadd -a profiles
+}}
+
+_{command}() {{
+    _arguments -s \
+{specs}        '1:task:_{command}_tasks' \
+        '*::arguments:_default'
+}}
+
+# invoked by the cargo completion for the cargo make sub command
+_{cargo_command}() {{
+    _{command} "$@"
+}}
+
+if [[ "$funcstack[1]" = "_{command}" || "$funcstack[1]" = "_{cargo_command}" ]]; then
+    _{command} "$@"
+else
+    compdef _{command} {command} {cargo_command}
+fi
+"#,
+        command = COMMAND,
+        cargo_command = CARGO_COMMAND,
+        tasks = TASKS_COMMAND,
+        profiles = PROFILES_COMMAND,
+        specs = specs
+    )
 }
 
-// Modify the function to accept an optional input stream
-fn generate_completion_zsh(
-    input: Option<&mut dyn io::Read>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let home_dir = std::env::var("HOME")?;
-    let zfunc_dir = format!("{}/.zfunc", home_dir);
-    let completion_file = format!("{}/_cargo-make", zfunc_dir);
-
-    if !Path::new(&zfunc_dir).exists() {
-        if let Err(e) = fs::create_dir_all(&zfunc_dir) {
-            error!("Failed to create directory {}: {}", zfunc_dir, e);
-            return Err(Box::new(e));
+fn create_fish(flags: &[Flag]) -> String {
+    let targets = [
+        COMMAND.to_string(),
+        CARGO_COMMAND.to_string(),
+        format!("cargo -n '__{}_cargo_make'", COMMAND),
+    ];
+
+    let mut lines = String::new();
+    for (target, flag) in targets
+        .iter()
+        .flat_map(|target| flags.iter().map(move |flag| (target, flag)))
+    {
+        let mut line = format!("complete -c {}", target);
+
+        for key in &flag.keys {
+            if let Some(name) = key.strip_prefix("--") {
+                line.push_str(&format!(" -l {}", name));
+            } else if let Some(name) = key.strip_prefix('-') {
+                let option = if name.len() == 1 { "-s" } else { "-o" };
+                line.push_str(&format!(" {} {}", option, name));
+            }
         }
-        info!("Created directory: {}", zfunc_dir);
+
+        match flag.value_hint {
+            Some(ValueHint::Tasks) => {
+                line.push_str(&format!(" -x -a '(__{}_tasks)'", COMMAND));
+            }
+            Some(ValueHint::Profiles) => {
+                line.push_str(&format!(" -x -a '(__{}_profiles)'", COMMAND));
+            }
+            Some(ValueHint::Files) => line.push_str(" -r -F"),
+            Some(ValueHint::Directories) => {
+                line.push_str(" -x -a '(__fish_complete_directories)'");
+            }
+            Some(ValueHint::Values(ref values)) => {
+                line.push_str(&format!(" -x -a '{}'", values.join(" ")));
+            }
+            Some(ValueHint::Text) => line.push_str(" -x"),
+            None => (),
+        };
+
+        if !flag.description.is_empty() {
+            line.push_str(&format!(" -d '{}'", escape_fish(&flag.description)));
+        }
+
+        lines.push_str(&line);
+        lines.push('\n');
     }
 
-    if Path::new(&completion_file).exists() {
-        let mut input_str = String::new();
-        let reader: Box<dyn io::Read> = match input {
-            Some(input) => Box::new(input),
-            None => Box::new(io::stdin()),
+    format!(
+        r#"# fish completion for {command} (generated by: {command} --completion fish)
+
+function __{command}_tasks
+    {tasks} 2>/dev/null | string split -n ' '
+end
+
+function __{command}_profiles
+    {profiles} 2>/dev/null
+end
+
+function __{command}_cargo_make
+    set -l words (commandline -opc)
+    test (count $words) -gt 1 -a "$words[2]" = make
+end
+
+complete -c {command} -f -a '(__{command}_tasks)'
+complete -c {cargo_command} -f -a '(__{command}_tasks)'
+complete -c cargo -n '__{command}_cargo_make' -f -a '(__{command}_tasks)'
+{lines}"#,
+        command = COMMAND,
+        cargo_command = CARGO_COMMAND,
+        tasks = TASKS_COMMAND,
+        profiles = PROFILES_COMMAND,
+        lines = lines
+    )
+}
+
+fn create_powershell(flags: &[Flag]) -> String {
+    let mut descriptions = String::new();
+    let mut cases = String::new();
+    for flag in flags {
+        for key in &flag.keys {
+            descriptions.push_str(&format!(
+                "    $flags['{}'] = '{}'\n",
+                key,
+                escape_doubled_quote(&flag.description)
+            ));
+        }
+
+        let action = match flag.value_hint {
+            Some(ValueHint::Tasks) => format!("({} 2>$null) -split '\\s+'", TASKS_COMMAND),
+            Some(ValueHint::Profiles) => format!("({} 2>$null) -split '\\s+'", PROFILES_COMMAND),
+            Some(ValueHint::Values(ref values)) => values
+                .iter()
+                .map(|value| format!("'{}'", value))
+                .collect::<Vec<String>>()
+                .join(", "),
+            // fallback to the default (path) completion
+            Some(_) => "return".to_string(),
+            None => continue,
         };
 
-        // Create a BufReader to read from the provided input or stdin
-        let mut buf_reader = io::BufReader::new(reader);
-        println!(
-            "File {} already exists. Overwrite? (y/n): ",
-            completion_file
-        );
-        buf_reader.read_line(&mut input_str)?;
-
-        if input_str.trim().to_lowercase() != "y" {
-            println!("Aborted overwriting the file.");
-            return Ok(());
+        let keys: Vec<String> = flag.keys.iter().map(|key| format!("'{}'", key)).collect();
+        cases.push_str(&format!(
+            "        {{ @({}) -ccontains $_ }} {{ {} }}\n",
+            keys.join(", "),
+            action
+        ));
+    }
+
+    format!(
+        r#"# powershell completion for {command} (generated by: {command} --completion powershell)
+
+# cargo is completed only for the cargo make sub command
+Register-ArgumentCompleter -Native -CommandName '{command}', '{cargo_command}', 'cargo' -ScriptBlock {{
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    if ($commandAst.CommandElements[0].ToString() -eq 'cargo' -and ($commandAst.CommandElements.Count -lt 2 -or $commandAst.CommandElements[1].ToString() -ne 'make')) {{
+        return
+    }}
+
+    # case sensitive since some short flags differ only by case (-v and -V)
+    $flags = [System.Collections.Generic.Dictionary[string, string]]::new([System.StringComparer]::Ordinal)
+{descriptions}
+    $elements = @($commandAst.CommandElements | Where-Object {{ $_.Extent.EndOffset -lt $cursorPosition }} | ForEach-Object {{ $_.ToString() }})
+    $previous = if ($elements.Count -gt 1) {{ $elements[-1] }} else {{ '' }}
+
+    $values = switch ($previous) {{
+{cases}        default {{
+            if ($wordToComplete.StartsWith('-')) {{
+                $flags.Keys
+            }} else {{
+                ({tasks} 2>$null) -split '\s+'
+            }}
+        }}
+    }}
+
+    $values | Where-Object {{ $_ -and $_.StartsWith($wordToComplete) }} | ForEach-Object {{
+        $description = if ($flags.ContainsKey($_) -and $flags[$_]) {{ $flags[$_] }} else {{ $_ }}
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $description)
+    }}
+}}
+"#,
+        command = COMMAND,
+        cargo_command = CARGO_COMMAND,
+        descriptions = descriptions,
+        cases = cases,
+        tasks = TASKS_COMMAND
+    )
+}
+
+fn create_elvish(flags: &[Flag]) -> String {
+    let mut descriptions = String::new();
+    let mut cases = String::new();
+    for flag in flags {
+        for key in &flag.keys {
+            descriptions.push_str(&format!(
+                "        [{} '{}']\n",
+                key,
+                escape_doubled_quote(&flag.description)
+            ));
         }
+
+        let action = match flag.value_hint {
+            Some(ValueHint::Tasks) => "tasks".to_string(),
+            Some(ValueHint::Profiles) => "profiles".to_string(),
+            Some(ValueHint::Files) | Some(ValueHint::Directories) => {
+                "edit:complete-filename $current".to_string()
+            }
+            Some(ValueHint::Values(ref values)) => format!("put {}", values.join(" ")),
+            Some(ValueHint::Text) => "put".to_string(),
+            None => continue,
+        };
+
+        let condition = if cases.is_empty() { "if" } else { "} elif" };
+        cases.push_str(&format!(
+            "    {} (has-value [{}] $previous) {{\n        {}\n",
+            condition,
+            flag.keys.join(" "),
+            action
+        ));
     }
 
-    let completion_script = r#"
-#compdef cargo make cargo-make
+    format!(
+        r#"# elvish completion for {command} (generated by: {command} --completion elvish)
 
-_cargo_make() {
-    local tasks
-    local makefile="Makefile.toml"
-    
-    if [[ ! -f $makefile ]]; then
-        return 1
-    fi
+use str
 
-    tasks=($(awk -F'[\\[\\.\\]]' '/^\[tasks/ {print $3}' "$makefile"))
+var {command}-completer = {{|@words|
+    fn tasks {{ str:fields ({tasks} 2>/dev/null | slurp) }}
+    fn profiles {{ str:fields ({profiles} 2>/dev/null | slurp) }}
 
-    if [[ ${#tasks[@]} -eq 0 ]]; then
-        return 1
-    fi
+    var flags = [
+{descriptions}    ]
+
+    var current = $words[-1]
+    var previous = ''
+    if (> (count $words) 2) {{
+        set previous = $words[-2]
+    }}
+
+{cases}    }} elif (str:has-prefix $current -) {{
+        for flag $flags {{
+            edit:complex-candidate $flag[0] &display=$flag[0]'  '$flag[1]
+        }}
+    }} else {{
+        tasks
+    }}
+}}
 
-    _describe -t tasks 'cargo-make tasks' tasks
+set edit:completion:arg-completer[{command}] = ${command}-completer
+set edit:completion:arg-completer[{cargo_command}] = ${command}-completer
+
+# cargo make completion, other cargo sub commands are delegated to the existing cargo completion
+var {command}-cargo-completer = $nil
+if (has-key $edit:completion:arg-completer cargo) {{
+    set {command}-cargo-completer = $edit:completion:arg-completer[cargo]
+}}
+set edit:completion:arg-completer[cargo] = {{|@words|
+    if (and (> (count $words) 2) (eq $words[1] make)) {{
+        ${command}-completer (all $words[1..])
+    }} elif (not-eq ${command}-cargo-completer $nil) {{
+        ${command}-cargo-completer $@words
+    }}
+}}
+"#,
+        command = COMMAND,
+        cargo_command = CARGO_COMMAND,
+        tasks = TASKS_COMMAND,
+        profiles = PROFILES_COMMAND,
+        descriptions = descriptions,
+        cases = cases
+    )
 }
 
-_cargo_make "$@"
-"#;
+/// Returns the completion script for the provided shell
+pub(crate) fn create_completion(shell: &str) -> Result<String, String> {
+    let flags = get_flags();
 
-    fs::write(&completion_file, completion_script)?;
-    println!("\nWrote tasks completion script to: {}", completion_file);
+    match shell {
+        "bash" => Ok(create_bash(&flags)),
+        "zsh" => Ok(create_zsh(&flags)),
+        "fish" => Ok(create_fish(&flags)),
+        "powershell" => Ok(create_powershell(&flags)),
+        "elvish" => Ok(create_elvish(&flags)),
+        _ => Err(format!(
+            "Unsupported shell for completion: {} (supported: {})",
+            shell,
+            SHELLS.join(", ")
+        )),
+    }
+}
 
-    println!("To enable Zsh completion, add the following lines to your ~/.zshrc:\n");
-    println!("    fpath=(~/.zfunc $fpath)");
-    println!("    autoload -Uz compinit && compinit");
-    println!("\nThen, restart your terminal or run 'source ~/.zshrc'.");
+/// Prints the completion script for the provided shell
+pub fn generate_completions(shell: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let completion = create_completion(shell)?;
+    print!("{}", completion);
 
     Ok(())
 }
@@ -1,113 +1,148 @@
-use std::fs;
-use std::path::Path;
+use super::*;
+
+fn get_flag(flags: &[Flag], key: &str) -> Flag {
+    flags
+        .iter()
+        .find(|flag| flag.keys.contains(&key.to_string()))
+        .unwrap()
+        .clone()
+}
 
-#[cfg(target_os = "linux")]
-use crate::completion::generate_completion_zsh;
+#[test]
+fn get_flags_from_cli_spec() {
+    let flags = get_flags();
+
+    let help = get_flag(&flags, "--help");
+    assert_eq!(help.keys, vec!["--help", "-h"]);
+    assert_eq!(help.description, "Print help information");
+    assert!(help.value_name.is_none());
+    assert!(help.value_hint.is_none());
+
+    let task = get_flag(&flags, "--task");
+    assert_eq!(task.keys, vec!["--task", "-t"]);
+    assert_eq!(task.value_name, Some("task".to_string()));
+    assert_eq!(task.value_hint, Some(ValueHint::Tasks));
+
+    let profile = get_flag(&flags, "--profile");
+    assert_eq!(profile.value_hint, Some(ValueHint::Profiles));
+
+    let env = get_flag(&flags, "--env");
+    assert!(env.multiple);
+    assert_eq!(env.value_hint, Some(ValueHint::Text));
+
+    let loglevel = get_flag(&flags, "--loglevel");
+    assert_eq!(
+        loglevel.value_hint,
+        Some(ValueHint::Values(vec!["verbose", "info", "error", "off"]))
+    );
 
-#[cfg(target_os = "linux")]
-use std::io::Cursor;
+    assert_eq!(
+        get_flag(&flags, "--makefile").value_hint,
+        Some(ValueHint::Files)
+    );
+    assert_eq!(
+        get_flag(&flags, "--cwd").value_hint,
+        Some(ValueHint::Directories)
+    );
+    assert_eq!(
+        get_flag(&flags, "--completion").value_hint,
+        Some(ValueHint::Values(SHELLS.to_vec()))
+    );
+}
 
-// Function to clean up test environment by removing the completion file
-fn cleanup() {
-    if let Ok(home_dir) = std::env::var("HOME") {
-        let completion_file = format!("{}/.zfunc/_cargo-make", home_dir);
-        println!("\n\n\n\n{}\n\n\n\n", completion_file);
+#[test]
+fn escape_sh_single_quote() {
+    assert_eq!(escape_sh("it's"), "it'\\''s");
+}
 
-        if Path::new(&completion_file).exists() {
-            fs::remove_file(&completion_file).expect("Failed to clean up test file");
-        }
-    }
+#[test]
+fn escape_fish_single_quote_and_backslash() {
+    assert_eq!(escape_fish("it's a\\b"), "it\\'s a\\\\b");
 }
 
 #[test]
-#[ignore]
-fn test_generate_completion_zsh_empty_test() {
-    cleanup();
+fn escape_doubled_quote_single_quote() {
+    assert_eq!(escape_doubled_quote("it's"), "it''s");
 }
 
 #[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn test_generate_completion_zsh_overwrite_prompt_yes() {
-    cleanup(); // Clean up before the test
+fn create_completion_all_shells() {
+    for shell in SHELLS {
+        let completion = create_completion(shell).unwrap();
+
+        assert!(completion.contains(TASKS_COMMAND));
+        assert!(completion.contains(PROFILES_COMMAND));
+        assert!(completion.contains("print-config"));
+        assert!(completion.contains("list-profiles"));
+    }
+}
 
-    let input = b"y\n"; // Simulate user input of 'y'
-    let mut reader = Cursor::new(input);
+#[test]
+fn create_completion_bash() {
+    let completion = create_completion("bash").unwrap();
 
-    let result = generate_completion_zsh(Some(&mut reader));
-    assert!(result.is_ok());
+    assert!(completion.contains("        --task|-t)\n"));
+    assert!(completion.contains("compgen -W \"verbose info error off\""));
+    assert!(completion.contains("complete -o default -F _makers makers cargo-make\n"));
+    assert!(completion.contains("complete -o default -F _makers_cargo cargo\n"));
 }
 
 #[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn test_generate_completion_zsh_overwrite_prompt_no() {
-    cleanup(); // Clean up before the test
-    let input = b"n\n"; // Simulate user input of 'n'
-    let mut reader = Cursor::new(input);
-
-    let result = generate_completion_zsh(Some(&mut reader));
-    assert!(result.is_ok());
+fn create_completion_zsh() {
+    let completion = create_completion("zsh").unwrap();
+
+    assert!(completion.starts_with("#compdef makers cargo-make\n"));
+    assert!(completion.contains("_cargo-make() {\n    _makers \"$@\"\n}\n"));
+    assert!(completion.contains("'-t[The task name to execute (can omit the flag if the task name is the last argument)]:task:_makers_tasks' \\\n"));
+    assert!(completion.contains("'*--env[Set environment variables]:env: ' \\\n"));
+    assert!(completion.contains("'--cwd["));
+    assert!(completion.contains(":directory:_files -/' \\\n"));
 }
 
 #[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn test_generate_completion_zsh_creates_directory() {
-    cleanup(); // Clean up before the test
-
-    let input = b"y\n"; // Simulate user input of 'y'
-    let mut reader = Cursor::new(input);
-
-    let result = generate_completion_zsh(Some(&mut reader));
-    assert!(result.is_ok(), "Should succeed in generating completions");
+fn create_completion_fish() {
+    let completion = create_completion("fish").unwrap();
 
-    // Check if the directory was created
-    let home_dir = std::env::var("HOME").expect("Failed to get HOME");
-    let zfunc_dir = format!("{}/.zfunc", home_dir);
+    assert!(completion.contains("complete -c makers -l task -s t -x -a '(__makers_tasks)' -d "));
+    assert!(completion.contains("complete -c makers -l makefile -r -F -d "));
+    assert!(completion.contains("complete -c makers -l no-color -d 'Disables colorful output'\n"));
     assert!(
-        Path::new(&zfunc_dir).exists(),
-        "The zfunc directory should exist"
+        completion.contains("complete -c cargo-make -l no-color -d 'Disables colorful output'\n")
     );
+    assert!(completion.contains(
+        "complete -c cargo -n '__makers_cargo_make' -l no-color -d 'Disables colorful output'\n"
+    ));
+    assert!(completion
+        .contains("complete -c cargo -n '__makers_cargo_make' -f -a '(__makers_tasks)'\n"));
 }
 
 #[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn test_generate_completion_zsh_creates_file() {
-    cleanup(); // Clean up before the test
+fn create_completion_powershell() {
+    let completion = create_completion("powershell").unwrap();
 
-    let input = b"y\n"; // Simulate user input of 'y'
-    let mut reader = Cursor::new(input);
+    assert!(completion.contains("-CommandName 'makers', 'cargo-make', 'cargo'"));
+    assert!(completion.contains("    $flags['--no-color'] = 'Disables colorful output'\n"));
+    assert!(completion
+        .contains("{ @('--loglevel', '-l') -ccontains $_ } { 'verbose', 'info', 'error', 'off' }"));
+}
 
-    let result = generate_completion_zsh(Some(&mut reader));
-    assert!(result.is_ok(), "Should succeed in generating completions");
+#[test]
+fn create_completion_elvish() {
+    let completion = create_completion("elvish").unwrap();
 
-    // Check if the completion file was created
-    let home_dir = std::env::var("HOME").expect("Failed to get HOME");
-    let completion_file = format!("{}/.zfunc/_cargo-make", home_dir);
+    assert!(completion.contains("var makers-completer = {|@words|\n"));
+    assert!(completion.contains("set edit:completion:arg-completer[makers] = $makers-completer\n"));
     assert!(
-        Path::new(&completion_file).exists(),
-        "The completion file should exist"
+        completion.contains("set edit:completion:arg-completer[cargo-make] = $makers-completer\n")
     );
+    assert!(completion.contains("set edit:completion:arg-completer[cargo] = {|@words|\n"));
+    assert!(completion.contains("    if (has-value [--makefile] $previous) {\n"));
+    assert!(completion.contains("    } elif (has-value [--task -t] $previous) {\n        tasks\n"));
 }
 
 #[test]
-#[ignore]
-#[cfg(target_os = "linux")]
-fn test_generate_completion_zsh_overwrite_prompt() {
-    cleanup(); // Clean up before the test
-
-    // Create the directory and file first
-    let input = b"y\n"; // Simulate user input of 'y'
-    let mut reader = Cursor::new(input);
-
-    generate_completion_zsh(Some(&mut reader)).expect("Should succeed in generating completions");
-
-    // Simulate user input for overwrite.
-    let input = b"y\n"; // Simulate user input of 'y' again
-    let mut reader = Cursor::new(input);
+fn create_completion_unsupported() {
+    let output = create_completion("tcsh");
 
-    let result = generate_completion_zsh(Some(&mut reader));
-    assert!(result.is_ok(), "Should handle overwrite prompt gracefully");
+    assert!(output.is_err());
 }
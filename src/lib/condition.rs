@@ -13,8 +13,8 @@ use crate::profile;
 use crate::scriptengine;
 use crate::types;
 use crate::types::{
-    ConditionScriptValue, ConditionType, FlowInfo, RustVersionCondition, ScriptValue, Step,
-    TaskCondition,
+    ConditionScriptValue, ConditionType, ConditionVerdict, CrateInfo, FlowInfo,
+    RustVersionCondition, ScriptValue, Step, TaskCondition,
 };
 use crate::version::{is_newer, is_same};
 use fsio::path::from_path::FromPath;
@@ -23,6 +23,9 @@ use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
 use std::path::Path;
 
+/// All target kinds which are considered as a library for the `crate_has_target` condition.
+static LIB_TARGET_KINDS: &[&str] = &["lib", "rlib", "dylib", "cdylib", "staticlib", "proc-macro"];
+
 /// Enum indicates what kind of env map we are validating. Used in `validate_env_map` function.
 enum EnvMapType {
     /// Validate map for `env` in `TaskCondition`.
@@ -44,9 +47,16 @@ fn validate_env_map(
 
             for (key, current_value) in env_vars.iter() {
                 let valid = match env_map_type {
-                    EnvMapType::Env => envmnt::is_equal(key, current_value),
-                    EnvMapType::EnvContains => envmnt::contains_ignore_case(key, current_value),
-                    EnvMapType::EnvNot => !envmnt::is_equal(key, current_value),
+                    EnvMapType::Env => {
+                        environment::get_var_or_env(key).as_ref() == Some(current_value)
+                    }
+                    EnvMapType::EnvContains => match environment::get_var_or_env(key) {
+                        Some(value) => value.to_lowercase().contains(&current_value.to_lowercase()),
+                        None => false,
+                    },
+                    EnvMapType::EnvNot => {
+                        environment::get_var_or_env(key).as_ref() != Some(current_value)
+                    }
                 };
 
                 if valid {
@@ -89,7 +99,7 @@ fn validate_env_set(condition: &TaskCondition, validate_any: bool) -> bool {
             let mut found_any = env_vars.is_empty();
 
             for key in env_vars.iter() {
-                let exists = envmnt::exists(key);
+                let exists = environment::get_var_or_env(key).is_some();
                 if exists {
                     if validate_any {
                         return true;
@@ -115,7 +125,7 @@ fn validate_env_not_set(condition: &TaskCondition, validate_any: bool) -> bool {
             let mut found_any = env_vars.is_empty();
 
             for key in env_vars.iter() {
-                let exists = envmnt::exists(key);
+                let exists = environment::get_var_or_env(key).is_some();
                 if !exists {
                     if validate_any {
                         return true;
@@ -133,6 +143,13 @@ fn validate_env_not_set(condition: &TaskCondition, validate_any: bool) -> bool {
     }
 }
 
+/// Same as the envmnt bool parsing, empty, 0, false and no values are false
+fn is_truthy(value: &str) -> bool {
+    let value = value.to_lowercase();
+
+    !value.is_empty() && value != "false" && value != "no" && value != "0"
+}
+
 fn validate_env_bool(condition: &TaskCondition, truthy: bool, validate_any: bool) -> bool {
     let env = if truthy {
         condition.env_true.clone()
@@ -145,7 +162,10 @@ fn validate_env_bool(condition: &TaskCondition, truthy: bool, validate_any: bool
             let mut found_any = env_vars.is_empty();
 
             for key in env_vars.iter() {
-                let is_true = envmnt::is_or(key, !truthy);
+                let is_true = match environment::get_var_or_env(key) {
+                    Some(value) => is_truthy(&value),
+                    None => !truthy,
+                };
                 let is_equal = is_true == truthy;
 
                 if is_equal {
@@ -357,11 +377,168 @@ fn validate_files_not_exist(condition: &TaskCondition, validate_any: bool) -> bo
     }
 }
 
-fn validate_files_modified(condition: &TaskCondition) -> bool {
+fn validate_crate_values(
+    required_values: &Option<Vec<String>>,
+    crate_values: &[String],
+    validate_any: bool,
+) -> bool {
+    match required_values {
+        Some(values) => {
+            let mut found_any = values.is_empty();
+
+            for value in values.iter() {
+                let found = crate_values.contains(value);
+
+                if found {
+                    if validate_any {
+                        return true;
+                    }
+
+                    found_any = true;
+                } else if !found && !validate_any {
+                    return false;
+                }
+            }
+
+            found_any
+        }
+        None => true,
+    }
+}
+
+fn get_crate_target_kinds(crate_info: &CrateInfo) -> Vec<String> {
+    let mut kinds = vec![];
+
+    if let Some(ref targets) = crate_info.targets {
+        for target in targets {
+            for kind in &target.kind {
+                let is_lib = LIB_TARGET_KINDS.contains(&kind.as_str());
+                if is_lib && !kinds.contains(&"lib".to_string()) {
+                    kinds.push("lib".to_string());
+                }
+
+                if !kinds.contains(kind) {
+                    kinds.push(kind.clone());
+                }
+            }
+        }
+    }
+
+    kinds
+}
+
+fn validate_crate_has_feature(
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+    validate_any: bool,
+) -> bool {
+    match flow_info_option {
+        Some(flow_info) => {
+            let features = flow_info
+                .env_info
+                .crate_info
+                .features
+                .clone()
+                .unwrap_or(vec![]);
+
+            let valid =
+                validate_crate_values(&condition.crate_has_feature, &features, validate_any);
+            if !valid {
+                debug!(
+                    "Failed crate feature condition, crate features: {:?}",
+                    &features
+                );
+            }
+
+            valid
+        }
+        None => true,
+    }
+}
+
+fn validate_crate_has_dependency(
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+    validate_any: bool,
+) -> bool {
+    match flow_info_option {
+        Some(flow_info) => {
+            let dependencies = flow_info
+                .env_info
+                .crate_info
+                .dependency_names
+                .clone()
+                .unwrap_or(vec![]);
+
+            let valid =
+                validate_crate_values(&condition.crate_has_dependency, &dependencies, validate_any);
+            if !valid {
+                debug!(
+                    "Failed crate dependency condition, crate dependencies: {:?}",
+                    &dependencies
+                );
+            }
+
+            valid
+        }
+        None => true,
+    }
+}
+
+fn validate_crate_has_target(
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+    validate_any: bool,
+) -> bool {
+    match flow_info_option {
+        Some(flow_info) => {
+            let kinds = get_crate_target_kinds(&flow_info.env_info.crate_info);
+
+            let valid = validate_crate_values(&condition.crate_has_target, &kinds, validate_any);
+            if !valid {
+                debug!("Failed crate target condition, crate targets: {:?}", &kinds);
+            }
+
+            valid
+        }
+        None => true,
+    }
+}
+
+fn validate_crate_is_workspace_member(
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+) -> bool {
+    match flow_info_option {
+        Some(flow_info) => match condition.crate_is_workspace_member {
+            Some(expected) => {
+                let workspace_member = flow_info
+                    .env_info
+                    .crate_info
+                    .workspace_member
+                    .unwrap_or(false);
+
+                if workspace_member != expected {
+                    debug!(
+                        "Failed crate workspace member condition, workspace member: {}",
+                        workspace_member
+                    );
+                    false
+                } else {
+                    true
+                }
+            }
+            None => true,
+        },
+        None => true,
+    }
+}
+
+fn evaluate_files_modified(condition: &TaskCondition) -> (bool, String) {
     match &condition.files_modified {
         Some(files_modified) => {
             if files_modified.input.len() == 0 {
-                return true;
+                return (true, "no input files defined".to_string());
             }
 
             let mut latest_binary = 0;
@@ -408,7 +585,7 @@ fn validate_files_modified(condition: &TaskCondition) -> bool {
             }
 
             if latest_binary == 0 {
-                true
+                (true, "no output files found".to_string())
             } else {
                 for glob_pattern in &files_modified.input {
                     let glob_pattern = environment::expand_value(glob_pattern);
@@ -427,7 +604,13 @@ fn validate_files_modified(condition: &TaskCondition) -> bool {
                                             {
                                                 Ok(last_modified_time) => {
                                                     if last_modified_time > latest_binary {
-                                                        return true;
+                                                        return (
+                                                            true,
+                                                            format!(
+                                                                "input file: {} is newer than the output files",
+                                                                &value_string
+                                                            ),
+                                                        );
                                                     }
                                                 }
                                                 Err(error) => {
@@ -462,14 +645,110 @@ fn validate_files_modified(condition: &TaskCondition) -> bool {
                 }
 
                 // all sources (input) are older than binaries (output)
-                false
+                (
+                    false,
+                    "all input files are older than the output files".to_string(),
+                )
             }
         }
-        None => true,
+        None => (true, "".to_string()),
     }
 }
 
-fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondition>) -> bool {
+fn get_channel_name(flow_info_option: Option<&FlowInfo>) -> String {
+    match flow_info_option {
+        Some(flow_info) => match flow_info.env_info.rust_info.channel {
+            Some(RustChannel::Stable) => "stable".to_string(),
+            Some(RustChannel::Beta) => "beta".to_string(),
+            Some(RustChannel::Nightly) => "nightly".to_string(),
+            None => "unknown".to_string(),
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+fn describe_criteria(
+    name: &str,
+    condition: &TaskCondition,
+    flow_info_option: Option<&FlowInfo>,
+) -> String {
+    match name {
+        "os" => format!(
+            "{:?} (current: {})",
+            condition.os.clone().unwrap_or_default(),
+            envmnt::get_or("CARGO_MAKE_RUST_TARGET_OS", "")
+        ),
+        "platforms" => format!(
+            "{:?} (current: {})",
+            condition.platforms.clone().unwrap_or_default(),
+            types::get_platform_name()
+        ),
+        "profiles" => format!(
+            "{:?} (current: {})",
+            condition.profiles.clone().unwrap_or_default(),
+            profile::get()
+        ),
+        "channels" => format!(
+            "{:?} (current: {})",
+            condition.channels.clone().unwrap_or_default(),
+            get_channel_name(flow_info_option)
+        ),
+        "env" => format!("{:?}", condition.env.clone().unwrap_or_default()),
+        "env_not" => format!("{:?}", condition.env_not.clone().unwrap_or_default()),
+        "env_set" => format!("{:?}", condition.env_set.clone().unwrap_or_default()),
+        "env_not_set" => format!("{:?}", condition.env_not_set.clone().unwrap_or_default()),
+        "env_true" => format!("{:?}", condition.env_true.clone().unwrap_or_default()),
+        "env_false" => format!("{:?}", condition.env_false.clone().unwrap_or_default()),
+        "env_contains" => format!("{:?}", condition.env_contains.clone().unwrap_or_default()),
+        "rust_version" => match condition.rust_version {
+            Some(ref rust_version) => format!(
+                "min: {}, max: {}, equal: {}",
+                rust_version.min.clone().unwrap_or("-".to_string()),
+                rust_version.max.clone().unwrap_or("-".to_string()),
+                rust_version.equal.clone().unwrap_or("-".to_string())
+            ),
+            None => "".to_string(),
+        },
+        "files_exist" => format!("{:?}", condition.files_exist.clone().unwrap_or_default()),
+        "files_not_exist" => format!(
+            "{:?}",
+            condition.files_not_exist.clone().unwrap_or_default()
+        ),
+        "crate_has_feature" => format!(
+            "{:?}",
+            condition.crate_has_feature.clone().unwrap_or_default()
+        ),
+        "crate_has_dependency" => format!(
+            "{:?}",
+            condition.crate_has_dependency.clone().unwrap_or_default()
+        ),
+        "crate_has_target" => format!(
+            "{:?}",
+            condition.crate_has_target.clone().unwrap_or_default()
+        ),
+        "crate_is_workspace_member" => format!(
+            "{}",
+            condition.crate_is_workspace_member.unwrap_or_default()
+        ),
+        _ => "".to_string(),
+    }
+}
+
+fn create_criteria_reason(name: &str, valid: bool, description: &str) -> String {
+    let status = if valid { "met" } else { "not met" };
+
+    if description.is_empty() {
+        format!("{} condition {}", name, status)
+    } else {
+        format!("{} condition {}: {}", name, status, description)
+    }
+}
+
+/// Evaluates the condition structure and returns the verdict including the reasons which led to it.
+fn evaluate_criteria(
+    flow_info: Option<&FlowInfo>,
+    condition: &Option<TaskCondition>,
+) -> ConditionVerdict {
     match condition {
         Some(ref condition_struct) => {
             debug!("Checking task condition structure.");
@@ -478,144 +757,169 @@ fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondit
             let validate_any = condition_type == ConditionType::Or;
             let group_or_condition = condition_type == ConditionType::GroupOr || validate_any;
             let mut not_valid_found = false;
+            let mut reasons = vec![];
+
+            // the checks are evaluated lazily and in order, so we can stop on the first decisive one
+            let criteria: Vec<(&str, bool, Box<dyn Fn() -> bool + '_>)> = vec![
+                (
+                    "os",
+                    condition_struct.os.is_some(),
+                    Box::new(|| validate_os(condition_struct)),
+                ),
+                (
+                    "platforms",
+                    condition_struct.platforms.is_some(),
+                    Box::new(|| validate_platform(condition_struct)),
+                ),
+                (
+                    "profiles",
+                    condition_struct.profiles.is_some(),
+                    Box::new(|| validate_profile(condition_struct)),
+                ),
+                (
+                    "channels",
+                    condition_struct.channels.is_some(),
+                    Box::new(|| validate_channel(condition_struct, flow_info)),
+                ),
+                (
+                    "env",
+                    condition_struct.env.is_some(),
+                    Box::new(|| validate_env(condition_struct, validate_any)),
+                ),
+                (
+                    "env_not",
+                    condition_struct.env_not.is_some(),
+                    Box::new(|| validate_env_not(condition_struct, validate_any)),
+                ),
+                (
+                    "env_set",
+                    condition_struct.env_set.is_some(),
+                    Box::new(|| validate_env_set(condition_struct, validate_any)),
+                ),
+                (
+                    "env_not_set",
+                    condition_struct.env_not_set.is_some(),
+                    Box::new(|| validate_env_not_set(condition_struct, validate_any)),
+                ),
+                (
+                    "env_true",
+                    condition_struct.env_true.is_some(),
+                    Box::new(|| validate_env_bool(condition_struct, true, validate_any)),
+                ),
+                (
+                    "env_false",
+                    condition_struct.env_false.is_some(),
+                    Box::new(|| validate_env_bool(condition_struct, false, validate_any)),
+                ),
+                (
+                    "env_contains",
+                    condition_struct.env_contains.is_some(),
+                    Box::new(|| validate_env_contains(condition_struct, validate_any)),
+                ),
+                (
+                    "rust_version",
+                    condition_struct.rust_version.is_some(),
+                    Box::new(|| validate_rust_version(condition_struct)),
+                ),
+                (
+                    "files_exist",
+                    condition_struct.files_exist.is_some(),
+                    Box::new(|| validate_files_exist(condition_struct, validate_any)),
+                ),
+                (
+                    "files_not_exist",
+                    condition_struct.files_not_exist.is_some(),
+                    Box::new(|| validate_files_not_exist(condition_struct, validate_any)),
+                ),
+                (
+                    "crate_has_feature",
+                    condition_struct.crate_has_feature.is_some(),
+                    Box::new(|| {
+                        validate_crate_has_feature(condition_struct, flow_info, validate_any)
+                    }),
+                ),
+                (
+                    "crate_has_dependency",
+                    condition_struct.crate_has_dependency.is_some(),
+                    Box::new(|| {
+                        validate_crate_has_dependency(condition_struct, flow_info, validate_any)
+                    }),
+                ),
+                (
+                    "crate_has_target",
+                    condition_struct.crate_has_target.is_some(),
+                    Box::new(|| {
+                        validate_crate_has_target(condition_struct, flow_info, validate_any)
+                    }),
+                ),
+                (
+                    "crate_is_workspace_member",
+                    condition_struct.crate_is_workspace_member.is_some(),
+                    Box::new(|| validate_crate_is_workspace_member(condition_struct, flow_info)),
+                ),
+            ];
+
+            for (name, defined, validate) in criteria.iter() {
+                let valid = validate();
+
+                if *defined {
+                    let description = describe_criteria(name, condition_struct, flow_info);
+                    reasons.push(create_criteria_reason(name, valid, &description));
+                }
 
-            let mut valid = validate_os(&condition_struct);
-            if group_or_condition && valid && condition_struct.os.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_platform(&condition_struct);
-            if group_or_condition && valid && condition_struct.platforms.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_profile(&condition_struct);
-            if group_or_condition && valid && condition_struct.profiles.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_channel(&condition_struct, flow_info);
-            if group_or_condition && valid && condition_struct.channels.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_not(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_not.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_set(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_set.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_not_set(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_not_set.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_bool(&condition_struct, true, validate_any);
-            if group_or_condition && valid && condition_struct.env_true.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_bool(&condition_struct, false, validate_any);
-            if group_or_condition && valid && condition_struct.env_false.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_env_contains(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.env_contains.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_rust_version(&condition_struct);
-            if group_or_condition && valid && condition_struct.rust_version.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
-            }
-
-            valid = validate_files_exist(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.files_exist.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+                if group_or_condition && valid && *defined {
+                    return ConditionVerdict {
+                        passed: true,
+                        reasons,
+                        script_skipped: false,
+                    };
+                } else if !group_or_condition && !valid {
+                    return ConditionVerdict {
+                        passed: false,
+                        reasons,
+                        script_skipped: false,
+                    };
+                } else if group_or_condition && !valid {
+                    not_valid_found = true;
+                }
             }
 
-            valid = validate_files_not_exist(&condition_struct, validate_any);
-            if group_or_condition && valid && condition_struct.files_not_exist.is_some() {
-                return true;
-            } else if !group_or_condition && !valid {
-                return false;
-            } else if group_or_condition && !valid {
-                not_valid_found = true;
+            let (valid, description) = evaluate_files_modified(condition_struct);
+            if condition_struct.files_modified.is_some() {
+                reasons.push(create_criteria_reason(
+                    "files_modified",
+                    valid,
+                    &description,
+                ));
             }
-
-            valid = validate_files_modified(&condition_struct);
             if !valid {
-                return false;
+                return ConditionVerdict {
+                    passed: false,
+                    reasons,
+                    script_skipped: false,
+                };
             }
 
-            !not_valid_found || !group_or_condition || condition_struct.files_modified.is_some()
+            ConditionVerdict {
+                passed: !not_valid_found
+                    || !group_or_condition
+                    || condition_struct.files_modified.is_some(),
+                reasons,
+                script_skipped: false,
+            }
         }
-        None => true,
+        None => ConditionVerdict {
+            passed: true,
+            reasons: vec![],
+            script_skipped: false,
+        },
     }
 }
 
+fn validate_criteria(flow_info: Option<&FlowInfo>, condition: &Option<TaskCondition>) -> bool {
+    evaluate_criteria(flow_info, condition).passed
+}
+
 pub(crate) fn get_script_text(script: &ConditionScriptValue) -> Vec<String> {
     match script {
         ConditionScriptValue::SingleLine(text) => vec![text.clone()],
@@ -650,41 +954,101 @@ pub(crate) fn validate_conditions_without_context(condition: TaskCondition) -> b
     validate_criteria(None, &Some(condition))
 }
 
-pub(crate) fn validate_conditions(
+/// Evaluates the condition and condition script and returns the verdict including the reasons which led to it.
+pub(crate) fn evaluate_conditions(
     flow_info: &FlowInfo,
     condition: &Option<TaskCondition>,
     condition_script: &Option<ConditionScriptValue>,
     script_runner: Option<String>,
     script_runner_args: Option<Vec<String>>,
-) -> Result<bool, CargoMakeError> {
+) -> Result<ConditionVerdict, CargoMakeError> {
+    evaluate_conditions_with_options(
+        flow_info,
+        condition,
+        condition_script,
+        script_runner,
+        script_runner_args,
+        true,
+    )
+}
+
+/// Evaluates the condition and optionally the condition script.<br>
+/// If the condition script is not evaluated, the verdict is marked as depending on it.
+fn evaluate_conditions_with_options(
+    flow_info: &FlowInfo,
+    condition: &Option<TaskCondition>,
+    condition_script: &Option<ConditionScriptValue>,
+    script_runner: Option<String>,
+    script_runner_args: Option<Vec<String>>,
+    evaluate_script: bool,
+) -> Result<ConditionVerdict, CargoMakeError> {
     let condition_type = match condition {
         Some(ref value) => value.get_condition_type(),
         None => ConditionType::And,
     };
 
-    let criteria_passed = validate_criteria(Some(&flow_info), &condition);
-    if !criteria_passed && condition_type == ConditionType::And {
-        Ok(false)
-    } else if criteria_passed && condition.is_some() && condition_type != ConditionType::And {
-        Ok(true)
+    let mut verdict = evaluate_criteria(Some(&flow_info), &condition);
+    if !verdict.passed && condition_type == ConditionType::And {
+        Ok(verdict)
+    } else if verdict.passed && condition.is_some() && condition_type != ConditionType::And {
+        Ok(verdict)
     } else {
-        if condition_script.is_none() && !criteria_passed {
-            Ok(false)
+        if condition_script.is_none() && !verdict.passed {
+            Ok(verdict)
+        } else if condition_script.is_some() && !evaluate_script {
+            verdict.script_skipped = true;
+            verdict
+                .reasons
+                .push("condition script: not evaluated".to_string());
+
+            Ok(verdict)
         } else {
-            validate_script(&condition_script, script_runner, script_runner_args)
+            verdict.passed = validate_script(&condition_script, script_runner, script_runner_args)?;
+
+            if condition_script.is_some() {
+                let reason = if verdict.passed {
+                    "condition script passed"
+                } else {
+                    "condition script failed (non zero exit code)"
+                };
+                verdict.reasons.push(reason.to_string());
+            }
+
+            Ok(verdict)
         }
     }
 }
 
-pub(crate) fn validate_condition_for_step(
+pub(crate) fn validate_conditions(
     flow_info: &FlowInfo,
-    step: &Step,
+    condition: &Option<TaskCondition>,
+    condition_script: &Option<ConditionScriptValue>,
+    script_runner: Option<String>,
+    script_runner_args: Option<Vec<String>>,
 ) -> Result<bool, CargoMakeError> {
-    validate_conditions(
+    let verdict = evaluate_conditions(
+        flow_info,
+        condition,
+        condition_script,
+        script_runner,
+        script_runner_args,
+    )?;
+
+    Ok(verdict.passed)
+}
+
+/// Evaluates the step condition, the condition script is only invoked if evaluate_script is true
+pub(crate) fn evaluate_condition_for_step(
+    flow_info: &FlowInfo,
+    step: &Step,
+    evaluate_script: bool,
+) -> Result<ConditionVerdict, CargoMakeError> {
+    evaluate_conditions_with_options(
         &flow_info,
         &step.config.condition,
         &step.config.condition_script,
         step.config.script_runner.clone(),
         step.config.condition_script_runner_args.clone(),
+        evaluate_script,
     )
 }
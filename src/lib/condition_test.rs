@@ -1,6 +1,8 @@
 use super::*;
-use crate::test::{get_temp_test_directory, should_test_unstable};
-use crate::types::{Config, ConfigSection, CrateInfo, EnvInfo, FilesFilesModifiedCondition, Task};
+use crate::test::{create_empty_flow_info, get_temp_test_directory, should_test_unstable};
+use crate::types::{
+    Config, ConfigSection, CrateInfo, CrateTarget, EnvInfo, FilesFilesModifiedCondition, Task,
+};
 use git_info::types::GitInfo;
 use std::{thread, time::Duration};
 
@@ -51,6 +53,10 @@ fn validate_env_set_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -78,6 +84,10 @@ fn validate_env_set_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -109,6 +119,10 @@ fn validate_env_set_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -143,6 +157,10 @@ fn validate_env_set_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -170,6 +188,10 @@ fn validate_env_set_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -197,6 +219,10 @@ fn validate_env_set_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -232,6 +258,10 @@ fn validate_env_set_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, false);
@@ -267,6 +297,10 @@ fn validate_env_set_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_set(&condition, true);
@@ -294,6 +328,10 @@ fn validate_env_not_set_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -321,6 +359,10 @@ fn validate_env_not_set_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -349,6 +391,10 @@ fn validate_env_not_set_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -377,6 +423,10 @@ fn validate_env_not_set_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -408,6 +458,10 @@ fn validate_env_not_set_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -439,6 +493,10 @@ fn validate_env_not_set_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -474,6 +532,10 @@ fn validate_env_not_set_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, false);
@@ -509,6 +571,10 @@ fn validate_env_not_set_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not_set(&condition, true);
@@ -536,6 +602,10 @@ fn validate_env_bool_true_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -563,6 +633,10 @@ fn validate_env_bool_true_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -594,6 +668,10 @@ fn validate_env_bool_true_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -625,6 +703,10 @@ fn validate_env_bool_true_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -656,6 +738,10 @@ fn validate_env_bool_true_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -687,6 +773,10 @@ fn validate_env_bool_true_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -718,6 +808,10 @@ fn validate_env_bool_true_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -749,6 +843,10 @@ fn validate_env_bool_true_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -780,6 +878,10 @@ fn validate_env_bool_true_invalid_partial_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, false);
@@ -811,6 +913,10 @@ fn validate_env_bool_true_invalid_partial_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, true, true);
@@ -838,6 +944,10 @@ fn validate_env_bool_false_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -865,6 +975,10 @@ fn validate_env_bool_false_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -896,6 +1010,10 @@ fn validate_env_bool_false_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -927,6 +1045,10 @@ fn validate_env_bool_false_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -958,6 +1080,10 @@ fn validate_env_bool_false_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -989,6 +1115,10 @@ fn validate_env_bool_false_invalid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -1020,6 +1150,10 @@ fn validate_env_bool_false_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -1051,6 +1185,10 @@ fn validate_env_bool_false_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -1082,6 +1220,10 @@ fn validate_env_bool_false_invalid_partial_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, false);
@@ -1113,6 +1255,10 @@ fn validate_env_bool_false_invalid_partial_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_bool(&condition, false, true);
@@ -1140,6 +1286,10 @@ fn validate_env_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1167,6 +1317,10 @@ fn validate_env_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1202,6 +1356,10 @@ fn validate_env_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1237,6 +1395,10 @@ fn validate_env_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1269,6 +1431,10 @@ fn validate_env_invalid_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1301,6 +1467,10 @@ fn validate_env_invalid_not_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1334,6 +1504,10 @@ fn validate_env_invalid_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1367,6 +1541,10 @@ fn validate_env_invalid_not_equal_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1402,6 +1580,10 @@ fn validate_env_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, false);
@@ -1437,6 +1619,10 @@ fn validate_env_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env(&condition, true);
@@ -1464,6 +1650,10 @@ fn validate_env_not_empty() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, false);
@@ -1491,6 +1681,10 @@ fn validate_env_not_empty_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, true);
@@ -1526,6 +1720,10 @@ fn validate_env_not_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, false);
@@ -1563,6 +1761,10 @@ fn validate_env_not_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, true);
@@ -1595,6 +1797,10 @@ fn validate_env_not_valid_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, false);
@@ -1627,6 +1833,10 @@ fn validate_env_not_valid_not_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, true);
@@ -1660,6 +1870,10 @@ fn validate_env_not_invalid_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, false);
@@ -1693,6 +1907,10 @@ fn validate_env_not_invalid_not_equal_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, true);
@@ -1728,6 +1946,10 @@ fn validate_env_not_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, false);
@@ -1763,6 +1985,10 @@ fn validate_env_not_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_not(&condition, true);
@@ -1798,6 +2024,10 @@ fn validate_env_contains_valid_same() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1833,6 +2063,10 @@ fn validate_env_contains_valid_same_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1868,6 +2102,10 @@ fn validate_env_contains_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1903,6 +2141,10 @@ fn validate_env_contains_valid_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -1935,6 +2177,10 @@ fn validate_env_contains_invalid_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -1967,6 +2213,10 @@ fn validate_env_contains_invalid_not_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -2000,6 +2250,10 @@ fn validate_env_contains_invalid_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -2033,6 +2287,10 @@ fn validate_env_contains_invalid_not_equal_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -2068,6 +2326,10 @@ fn validate_env_contains_invalid_partial_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, false);
@@ -2103,6 +2365,10 @@ fn validate_env_contains_invalid_partial_found_with_any() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_env_contains(&condition, true);
@@ -2196,6 +2462,10 @@ fn validate_profile_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_profile(&condition);
@@ -2226,6 +2496,10 @@ fn validate_profile_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_profile(&condition);
@@ -2257,6 +2531,10 @@ fn validate_os_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_os(&condition);
@@ -2284,6 +2562,10 @@ fn validate_os_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_os(&condition);
@@ -2315,6 +2597,10 @@ fn validate_platform_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_platform(&condition);
@@ -2342,6 +2628,10 @@ fn validate_platform_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_platform(&condition);
@@ -2358,10 +2648,12 @@ fn validate_channel_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let mut flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2374,6 +2666,7 @@ fn validate_channel_valid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Stable);
@@ -2399,6 +2692,10 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
     let mut enabled = validate_channel(&condition, Some(&flow_info));
     assert!(enabled);
@@ -2426,6 +2723,10 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
@@ -2454,6 +2755,10 @@ fn validate_channel_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
     enabled = validate_channel(&condition, Some(&flow_info));
 
@@ -2469,10 +2774,12 @@ fn validate_channel_invalid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let mut flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2485,6 +2792,7 @@ fn validate_channel_invalid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Stable);
@@ -2506,12 +2814,506 @@ fn validate_channel_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
     let enabled = validate_channel(&condition, Some(&flow_info));
 
     assert!(!enabled);
 }
 
+#[test]
+fn validate_crate_has_feature_valid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: Some(vec!["tls".to_string(), "default".to_string()]),
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_feature(&condition, Some(&flow_info), false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_has_feature_invalid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: Some(vec!["tls".to_string(), "bad".to_string()]),
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_feature(&condition, Some(&flow_info), false);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_crate_has_feature_valid_with_any() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: Some(vec!["bad".to_string(), "tls".to_string()]),
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_feature(&condition, Some(&flow_info), true);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_has_dependency_valid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: Some(vec!["serde".to_string()]),
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_dependency(&condition, Some(&flow_info), false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_has_dependency_invalid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: Some(vec!["tokio".to_string()]),
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_dependency(&condition, Some(&flow_info), false);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_crate_has_target_valid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: Some(vec!["lib".to_string(), "bin".to_string()]),
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_target(&condition, Some(&flow_info), false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_has_target_invalid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: Some(vec!["bench".to_string()]),
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_target(&condition, Some(&flow_info), false);
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_crate_is_workspace_member_valid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: Some(true),
+    };
+    let enabled = validate_crate_is_workspace_member(&condition, Some(&flow_info));
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_crate_is_workspace_member_invalid() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: Some(false),
+    };
+    let enabled = validate_crate_is_workspace_member(&condition, Some(&flow_info));
+
+    assert!(!enabled);
+}
+
+#[test]
+fn validate_crate_has_feature_no_flow_info() {
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: Some(vec!["bad".to_string()]),
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_crate_has_feature(&condition, None, false);
+
+    assert!(enabled);
+}
+
+#[test]
+fn validate_criteria_invalid_crate_has_target() {
+    let mut flow_info = create_empty_flow_info();
+    flow_info.env_info.crate_info.features = Some(vec!["default".to_string(), "tls".to_string()]);
+    flow_info.env_info.crate_info.dependency_names =
+        Some(vec!["envmnt".to_string(), "serde".to_string()]);
+    flow_info.env_info.crate_info.targets = Some(vec![
+        CrateTarget {
+            name: "mylib".to_string(),
+            kind: vec!["cdylib".to_string()],
+        },
+        CrateTarget {
+            name: "mybin".to_string(),
+            kind: vec!["bin".to_string()],
+        },
+    ]);
+    flow_info.env_info.crate_info.workspace_member = Some(true);
+
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: None,
+        env_not_set: None,
+        env_true: None,
+        env_false: None,
+        env: None,
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: Some(vec!["example".to_string()]),
+        crate_is_workspace_member: None,
+    };
+    let enabled = validate_criteria(Some(&flow_info), &Some(condition));
+
+    assert!(!enabled);
+}
+
 #[test]
 fn validate_file_exists_valid() {
     let condition = TaskCondition {
@@ -2534,6 +3336,10 @@ fn validate_file_exists_valid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2563,6 +3369,10 @@ fn validate_file_exists_valid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2593,6 +3403,10 @@ fn validate_file_exists_partial_invalid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2623,6 +3437,10 @@ fn validate_file_exists_partial_invalid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2652,6 +3470,10 @@ fn validate_file_exists_invalid() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, false);
@@ -2681,6 +3503,10 @@ fn validate_file_exists_invalid_with_any() {
         ]),
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_exist(&condition, true);
@@ -2710,6 +3536,10 @@ fn validate_file_not_exists_valid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2739,6 +3569,10 @@ fn validate_file_not_exists_valid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2769,6 +3603,10 @@ fn validate_file_not_exists_partial_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2799,6 +3637,10 @@ fn validate_file_not_exists_partial_invalid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string(),
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2828,6 +3670,10 @@ fn validate_file_not_exists_invalid() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, false);
@@ -2857,6 +3703,10 @@ fn validate_file_not_exists_invalid_with_any() {
             "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
         ]),
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_files_not_exist(&condition, true);
@@ -2887,9 +3737,13 @@ fn validate_files_modified_all_empty() {
             input: vec![],
             output: vec![],
         }),
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
-    let enabled = validate_files_modified(&condition);
+    let enabled = evaluate_files_modified(&condition).0;
 
     assert!(enabled);
 }
@@ -2917,9 +3771,13 @@ fn validate_files_modified_only_input() {
             input: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
             output: vec![],
         }),
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
-    let enabled = validate_files_modified(&condition);
+    let enabled = evaluate_files_modified(&condition).0;
 
     assert!(enabled);
 }
@@ -2947,9 +3805,13 @@ fn validate_files_modified_only_output() {
             input: vec![],
             output: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
         }),
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
-    let enabled = validate_files_modified(&condition);
+    let enabled = evaluate_files_modified(&condition).0;
 
     assert!(enabled);
 }
@@ -2977,9 +3839,13 @@ fn validate_files_modified_same_timestamp() {
             input: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
             output: vec!["${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()],
         }),
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
-    let enabled = validate_files_modified(&condition);
+    let enabled = evaluate_files_modified(&condition).0;
 
     assert!(!enabled);
 }
@@ -3015,9 +3881,13 @@ fn validate_files_modified_output_newer() {
                 input: vec![src_glob],
                 output: vec![target_glob],
             }),
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         };
 
-        let enabled = validate_files_modified(&condition);
+        let enabled = evaluate_files_modified(&condition).0;
 
         assert!(!enabled);
     }
@@ -3054,9 +3924,13 @@ fn validate_files_modified_input_newer() {
                 input: vec![target_glob],
                 output: vec![src_glob],
             }),
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         };
 
-        let enabled = validate_files_modified(&condition);
+        let enabled = evaluate_files_modified(&condition).0;
 
         assert!(enabled);
     }
@@ -3090,9 +3964,13 @@ fn validate_files_modified_output_newer_env() {
                 input: vec!["${DIR}/src/**/*".to_owned()],
                 output: vec!["${DIR}/target/**/*".to_owned()],
             }),
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         };
 
-        let enabled = validate_files_modified(&condition);
+        let enabled = evaluate_files_modified(&condition).0;
 
         assert!(!enabled);
     }
@@ -3126,9 +4004,13 @@ fn validate_files_modified_input_newer_env() {
                 input: vec!["${DIR}/target/**/*".to_owned()],
                 output: vec!["${DIR}/src/**/*".to_owned()],
             }),
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         };
 
-        let enabled = validate_files_modified(&condition);
+        let enabled = evaluate_files_modified(&condition).0;
 
         assert!(enabled);
     }
@@ -3143,10 +4025,12 @@ fn validate_criteria_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3159,6 +4043,7 @@ fn validate_criteria_empty() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3181,6 +4066,10 @@ fn validate_criteria_empty() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3196,10 +4085,12 @@ fn validate_criteria_valid_os() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3212,6 +4103,7 @@ fn validate_criteria_valid_os() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3238,6 +4130,10 @@ fn validate_criteria_valid_os() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3253,10 +4149,12 @@ fn validate_criteria_invalid_os() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3269,6 +4167,7 @@ fn validate_criteria_invalid_os() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3291,6 +4190,10 @@ fn validate_criteria_invalid_os() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3306,10 +4209,12 @@ fn validate_criteria_valid_platform() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3322,6 +4227,7 @@ fn validate_criteria_valid_platform() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3348,6 +4254,10 @@ fn validate_criteria_valid_platform() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3363,10 +4273,12 @@ fn validate_criteria_invalid_platform() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3379,6 +4291,7 @@ fn validate_criteria_invalid_platform() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3401,6 +4314,10 @@ fn validate_criteria_invalid_platform() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3417,10 +4334,12 @@ fn validate_criteria_valid_profile() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3433,6 +4352,7 @@ fn validate_criteria_valid_profile() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3455,6 +4375,10 @@ fn validate_criteria_valid_profile() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3471,10 +4395,12 @@ fn validate_criteria_invalid_profile() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3487,6 +4413,7 @@ fn validate_criteria_invalid_profile() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3509,6 +4436,10 @@ fn validate_criteria_invalid_profile() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3524,10 +4455,12 @@ fn validate_criteria_valid_channel() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let mut flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3540,6 +4473,7 @@ fn validate_criteria_valid_channel() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Stable);
@@ -3567,6 +4501,10 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3597,6 +4535,10 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3627,6 +4569,10 @@ fn validate_criteria_valid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3642,10 +4588,12 @@ fn validate_criteria_invalid_channel() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let mut flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3658,6 +4606,7 @@ fn validate_criteria_invalid_channel() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     flow_info.env_info.rust_info.channel = Some(RustChannel::Stable);
@@ -3681,6 +4630,10 @@ fn validate_criteria_invalid_channel() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3696,10 +4649,12 @@ fn validate_criteria_valid_file_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3712,6 +4667,7 @@ fn validate_criteria_valid_file_exists() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3736,6 +4692,10 @@ fn validate_criteria_valid_file_exists() {
             ]),
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3751,10 +4711,12 @@ fn validate_criteria_invalid_file_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3767,6 +4729,7 @@ fn validate_criteria_invalid_file_exists() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3791,6 +4754,10 @@ fn validate_criteria_invalid_file_exists() {
             ]),
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3806,10 +4773,12 @@ fn validate_criteria_valid_file_not_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3822,6 +4791,7 @@ fn validate_criteria_valid_file_not_exists() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3846,6 +4816,10 @@ fn validate_criteria_valid_file_not_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo2.toml".to_string()
             ]),
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3861,10 +4835,12 @@ fn validate_criteria_invalid_file_not_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3877,6 +4853,7 @@ fn validate_criteria_invalid_file_not_exists() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let enabled = validate_criteria(
@@ -3901,6 +4878,10 @@ fn validate_criteria_invalid_file_not_exists() {
                 "${CARGO_MAKE_WORKING_DIRECTORY}/Cargo.toml".to_string()
             ]),
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     );
 
@@ -3921,10 +4902,12 @@ fn validate_condition_for_step_both_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3937,6 +4920,7 @@ fn validate_condition_for_step_both_valid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -3961,10 +4945,16 @@ fn validate_condition_for_step_both_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -3983,10 +4973,12 @@ fn validate_condition_for_step_valid_script_invalid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -3999,6 +4991,7 @@ fn validate_condition_for_step_valid_script_invalid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -4023,10 +5016,16 @@ fn validate_condition_for_step_valid_script_invalid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 1".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4045,10 +5044,12 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4061,6 +5062,7 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -4085,10 +5087,16 @@ fn validate_condition_for_step_valid_script_invalid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 1".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4107,10 +5115,12 @@ fn validate_condition_for_step_invalid_script_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4123,6 +5133,7 @@ fn validate_condition_for_step_invalid_script_valid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -4143,10 +5154,16 @@ fn validate_condition_for_step_invalid_script_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4166,10 +5183,12 @@ fn validate_condition_for_step_invalid_env_set() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4182,6 +5201,7 @@ fn validate_condition_for_step_invalid_env_set() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -4202,10 +5222,16 @@ fn validate_condition_for_step_invalid_env_set() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4225,10 +5251,12 @@ fn validate_condition_for_step_invalid_env_not_set() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4241,6 +5269,7 @@ fn validate_condition_for_step_invalid_env_not_set() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "bad");
@@ -4263,10 +5292,16 @@ fn validate_condition_for_step_invalid_env_not_set() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4286,10 +5321,12 @@ fn validate_condition_for_step_valid_env() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4302,6 +5339,7 @@ fn validate_condition_for_step_valid_env() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good1");
@@ -4329,10 +5367,16 @@ fn validate_condition_for_step_valid_env() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4352,10 +5396,12 @@ fn validate_condition_for_step_invalid_env_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4368,6 +5414,7 @@ fn validate_condition_for_step_invalid_env_not_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut env_values = IndexMap::<String, String>::new();
@@ -4392,10 +5439,16 @@ fn validate_condition_for_step_invalid_env_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4415,10 +5468,12 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4431,6 +5486,7 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good");
@@ -4458,10 +5514,16 @@ fn validate_condition_for_step_invalid_env_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4481,10 +5543,12 @@ fn validate_condition_for_step_valid_env_not() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4497,6 +5561,7 @@ fn validate_condition_for_step_valid_env_not() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good1");
@@ -4524,10 +5589,16 @@ fn validate_condition_for_step_valid_env_not() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4547,10 +5618,12 @@ fn validate_condition_for_step_valid_env_not_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4563,6 +5636,7 @@ fn validate_condition_for_step_valid_env_not_not_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut env_values = IndexMap::<String, String>::new();
@@ -4587,10 +5661,16 @@ fn validate_condition_for_step_valid_env_not_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4610,10 +5690,12 @@ fn validate_condition_for_step_invalid_env_not_not_equal() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4626,6 +5708,7 @@ fn validate_condition_for_step_invalid_env_not_not_equal() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good");
@@ -4653,10 +5736,16 @@ fn validate_condition_for_step_invalid_env_not_not_equal() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4676,10 +5765,12 @@ fn validate_condition_for_step_valid_env_contains_same() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4692,6 +5783,7 @@ fn validate_condition_for_step_valid_env_contains_same() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good1");
@@ -4719,10 +5811,16 @@ fn validate_condition_for_step_valid_env_contains_same() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4742,10 +5840,12 @@ fn validate_condition_for_step_valid_env_contains() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4758,6 +5858,7 @@ fn validate_condition_for_step_valid_env_contains() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good1");
@@ -4785,10 +5886,16 @@ fn validate_condition_for_step_valid_env_contains() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -4808,10 +5915,12 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4824,6 +5933,7 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut env_values = IndexMap::<String, String>::new();
@@ -4848,10 +5958,16 @@ fn validate_condition_for_step_invalid_env_contains_not_found() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4871,10 +5987,12 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4887,6 +6005,7 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     envmnt::set("ENV_SET1", "good");
@@ -4914,10 +6033,16 @@ fn validate_condition_for_step_invalid_env_contains_not_contains() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -4936,10 +6061,12 @@ fn validate_condition_for_step_valid_rust_version() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -4952,6 +6079,7 @@ fn validate_condition_for_step_valid_rust_version() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let rustinfo = rust_info::get();
@@ -4979,9 +6107,15 @@ fn validate_condition_for_step_valid_rust_version() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5000,10 +6134,12 @@ fn validate_condition_for_step_invalid_rust_version() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5016,6 +6152,7 @@ fn validate_condition_for_step_invalid_rust_version() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let rustinfo = rust_info::get();
@@ -5044,9 +6181,15 @@ fn validate_condition_for_step_invalid_rust_version() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -5065,10 +6208,12 @@ fn validate_condition_for_step_both_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5081,6 +6226,7 @@ fn validate_condition_for_step_both_valid_and() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5105,10 +6251,16 @@ fn validate_condition_for_step_both_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5127,10 +6279,12 @@ fn validate_condition_for_step_value_partial_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5143,6 +6297,7 @@ fn validate_condition_for_step_value_partial_valid_and() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5170,10 +6325,16 @@ fn validate_condition_for_step_value_partial_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -5192,10 +6353,12 @@ fn validate_condition_for_step_group_partial_valid_and() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5208,6 +6371,7 @@ fn validate_condition_for_step_group_partial_valid_and() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5232,10 +6396,16 @@ fn validate_condition_for_step_group_partial_valid_and() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -5254,10 +6424,12 @@ fn validate_condition_for_step_both_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5270,6 +6442,7 @@ fn validate_condition_for_step_both_valid_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5294,10 +6467,16 @@ fn validate_condition_for_step_both_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5316,10 +6495,12 @@ fn validate_condition_for_step_value_partial_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5332,6 +6513,7 @@ fn validate_condition_for_step_value_partial_valid_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5359,10 +6541,16 @@ fn validate_condition_for_step_value_partial_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5381,10 +6569,12 @@ fn validate_condition_for_step_group_partial_valid_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5397,6 +6587,7 @@ fn validate_condition_for_step_group_partial_valid_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5421,10 +6612,16 @@ fn validate_condition_for_step_group_partial_valid_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5443,10 +6640,12 @@ fn validate_condition_for_step_both_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5459,6 +6658,7 @@ fn validate_condition_for_step_both_valid_group_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5483,10 +6683,16 @@ fn validate_condition_for_step_both_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5505,10 +6711,12 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5521,6 +6729,7 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5548,10 +6757,16 @@ fn validate_condition_for_step_value_partial_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
     step.config.condition_script = Some(ConditionScriptValue::Text(vec!["exit 0".to_string()]));
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(enabled);
 }
@@ -5570,10 +6785,12 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -5586,6 +6803,7 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     step.config.condition = Some(TaskCondition {
@@ -5609,9 +6827,15 @@ fn validate_condition_for_step_group_partial_valid_group_or() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
 
-    let enabled = validate_condition_for_step(&flow_info, &step).unwrap();
+    let enabled = evaluate_condition_for_step(&flow_info, &step, true)
+        .unwrap()
+        .passed;
 
     assert!(!enabled);
 }
@@ -6026,6 +7250,10 @@ fn validate_rust_version_no_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -6060,6 +7288,10 @@ fn validate_rust_version_with_valid_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -6095,6 +7327,10 @@ fn validate_rust_version_with_invalid_condition() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let enabled = validate_rust_version(&condition);
@@ -6119,3 +7355,169 @@ fn get_script_text_vector() {
 
     assert_eq!(output, "line 1\nline 2");
 }
+
+#[test]
+fn evaluate_criteria_none() {
+    let verdict = evaluate_criteria(None, &None);
+
+    assert!(verdict.passed);
+    assert!(verdict.reasons.is_empty());
+}
+
+#[test]
+fn evaluate_criteria_and_stops_on_first_failure() {
+    envmnt::set("EVALUATE_CRITERIA_AND_STOPS_ON_FIRST_FAILURE", "1");
+
+    let condition = TaskCondition {
+        env_set: Some(vec![
+            "EVALUATE_CRITERIA_AND_STOPS_ON_FIRST_FAILURE".to_string()
+        ]),
+        env_not_set: Some(vec![
+            "EVALUATE_CRITERIA_AND_STOPS_ON_FIRST_FAILURE".to_string()
+        ]),
+        files_exist: Some(vec!["./Cargo.toml".to_string()]),
+        ..TaskCondition::default()
+    };
+    let verdict = evaluate_criteria(None, &Some(condition));
+
+    assert!(!verdict.passed);
+    assert_eq!(
+        verdict.reasons,
+        vec![
+            "env_set condition met: [\"EVALUATE_CRITERIA_AND_STOPS_ON_FIRST_FAILURE\"]".to_string(),
+            "env_not_set condition not met: [\"EVALUATE_CRITERIA_AND_STOPS_ON_FIRST_FAILURE\"]"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn evaluate_criteria_or_stops_on_first_success() {
+    let condition = TaskCondition {
+        condition_type: Some(ConditionType::Or),
+        env_set: Some(vec![
+            "EVALUATE_CRITERIA_OR_STOPS_ON_FIRST_SUCCESS".to_string()
+        ]),
+        env_not_set: Some(vec![
+            "EVALUATE_CRITERIA_OR_STOPS_ON_FIRST_SUCCESS".to_string()
+        ]),
+        files_exist: Some(vec!["./Cargo.toml".to_string()]),
+        ..TaskCondition::default()
+    };
+    let verdict = evaluate_criteria(None, &Some(condition));
+
+    assert!(verdict.passed);
+    assert_eq!(verdict.reasons.len(), 2);
+    assert!(verdict.reasons[0].starts_with("env_set condition not met"));
+    assert!(verdict.reasons[1].starts_with("env_not_set condition met"));
+}
+
+#[test]
+fn evaluate_files_modified_input_newer() {
+    let directory = setup_test_dir("evaluate_files_modified_input_newer");
+
+    thread::sleep(Duration::from_millis(10));
+    let file = format!("{}/src/file3.txt", &directory);
+    fsio::file::write_text_file(&file, "test").unwrap();
+
+    let condition = TaskCondition {
+        files_modified: Some(FilesFilesModifiedCondition {
+            input: vec![format!("{}/src/file3.txt", &directory)],
+            output: vec![format!("{}/target/**/*", &directory)],
+        }),
+        ..TaskCondition::default()
+    };
+    let (valid, reason) = evaluate_files_modified(&condition);
+
+    assert!(valid);
+    assert!(reason.contains("file3.txt is newer than the output files"));
+}
+
+#[test]
+fn evaluate_conditions_with_script_reason() {
+    let flow_info = create_empty_flow_info();
+
+    let verdict = evaluate_conditions(
+        &flow_info,
+        &None,
+        &Some(ConditionScriptValue::SingleLine("exit 1".to_string())),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert!(!verdict.passed);
+    assert_eq!(
+        verdict.reasons,
+        vec!["condition script failed (non zero exit code)".to_string()]
+    );
+}
+
+#[test]
+fn evaluate_condition_for_step_script_not_evaluated() {
+    let flow_info = create_empty_flow_info();
+    let mut task = Task::new();
+    task.condition_script = Some(ConditionScriptValue::SingleLine("exit 1".to_string()));
+    let step = Step {
+        name: "test".to_string(),
+        config: task,
+    };
+
+    let verdict = evaluate_condition_for_step(&flow_info, &step, false).unwrap();
+
+    assert!(verdict.passed);
+    assert!(verdict.script_skipped);
+    assert_eq!(
+        verdict.reasons,
+        vec!["condition script: not evaluated".to_string()]
+    );
+}
+
+#[test]
+fn validate_env_var_defined() {
+    envmnt::remove("CONDITION_VAR_DEFINED");
+    envmnt::remove("CONDITION_VAR_TRUE");
+
+    let mut env = IndexMap::new();
+    env.insert("CONDITION_VAR_DEFINED".to_string(), "value".to_string());
+    let condition = TaskCondition {
+        condition_type: None,
+        fail_message: None,
+        profiles: None,
+        os: None,
+        platforms: None,
+        channels: None,
+        env_set: Some(vec!["CONDITION_VAR_DEFINED".to_string()]),
+        env_not_set: None,
+        env_true: Some(vec!["CONDITION_VAR_TRUE".to_string()]),
+        env_false: None,
+        env: Some(env),
+        env_not: None,
+        env_contains: None,
+        rust_version: None,
+        files_exist: None,
+        files_not_exist: None,
+        files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
+    };
+
+    let mut process_env = crate::process_env::ProcessEnv::default();
+    process_env
+        .vars
+        .insert("CONDITION_VAR_DEFINED".to_string(), "value".to_string());
+    process_env
+        .vars
+        .insert("CONDITION_VAR_TRUE".to_string(), "true".to_string());
+
+    let enabled = crate::process_env::run_with(process_env, || {
+        validate_env_set(&condition, false)
+            && validate_env_bool(&condition, true, false)
+            && validate_env(&condition, false)
+    });
+
+    assert!(enabled);
+    assert!(!validate_env_set(&condition, false));
+}
@@ -7,6 +7,7 @@
 #[path = "cargo_alias_test.rs"]
 mod cargo_alias_test;
 
+use crate::descriptor::config_cache;
 use crate::error::CargoMakeError;
 use crate::io;
 use crate::types::{InstallCrate, Task};
@@ -37,7 +38,10 @@ fn load_from_file(file: &str) -> Result<Vec<(String, Task)>, CargoMakeError> {
                 let cargo_config: CargoConfig = match toml::from_str(&text) {
                     Ok(value) => value,
                     Err(error) => {
-                        warn!("Unable to parse cargo config file, {}", error);
+                        config_cache::warn(&format!(
+                            "Unable to parse cargo config file, {}",
+                            error
+                        ));
                         CargoConfig { alias: None }
                     }
                 };
@@ -62,5 +66,8 @@ fn load_from_file(file: &str) -> Result<Vec<(String, Task)>, CargoMakeError> {
 }
 
 pub(crate) fn load() -> Result<Vec<(String, Task)>, CargoMakeError> {
-    load_from_file("./.cargo/config.toml")
+    let file = "./.cargo/config.toml";
+    config_cache::record_file(Path::new(file));
+
+    load_from_file(file)
 }
@@ -0,0 +1,257 @@
+//! # config_cache
+//!
+//! Caches the fully merged config between invocations.<br>
+//! While loading, every file which contributes to the config is recorded and the cache
+//! entry is only used as long as all of these files are unchanged and the cache key
+//! (cargo-make version, internal makefiles, CLI env and flags) matches.<br>
+//! Warnings printed while loading are stored as well and printed again when the entry is used.
+//!
+
+#[cfg(test)]
+#[path = "config_cache_test.rs"]
+mod config_cache_test;
+
+use crate::descriptor::makefiles;
+use crate::storage;
+use crate::types::{Config, EnvOrigin};
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The hash of files which do not exist
+static MISSING_FILE_HASH: &str = "missing";
+/// Env vars which modify the way makefiles are located and loaded
+static KEY_ENV_VARS: [&str; 5] = [
+    "CARGO_MAKE_HOME",
+    "CARGO_MAKE_WORKSPACE_MAKEFILE",
+    "CARGO_MAKE_WORKSPACE_EMULATION",
+    "CARGO_MAKE_WORKSPACE_EMULATION_ROOT_DIRECTORY",
+    "CARGO_MAKE_WORKING_DIRECTORY",
+];
+
+#[derive(Debug, Default)]
+/// Holds the data recorded while loading the config
+pub(crate) struct Recording {
+    /// All files read (or searched for) while loading
+    pub(crate) files: Vec<PathBuf>,
+    /// The env vars set while loading (None if the env var was removed)
+    pub(crate) env: IndexMap<String, Option<String>>,
+    /// The warnings printed while loading
+    pub(crate) warnings: Vec<String>,
+    /// False if the config can not be cached (for example, load scripts were invoked)
+    pub(crate) cacheable: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+/// Holds a single cached config
+pub(crate) struct CacheEntry {
+    /// The cache key
+    pub(crate) key: String,
+    /// All contributing files and their content hash
+    pub(crate) files: Vec<(String, String)>,
+    /// The env vars set while loading, restored when the entry is used
+    pub(crate) env: IndexMap<String, Option<String>>,
+    /// The merged config
+    pub(crate) config: Config,
+    /// The env values origins
+    pub(crate) env_origins: IndexMap<String, Vec<EnvOrigin>>,
+    /// The warnings printed while loading, printed again when the entry is used
+    pub(crate) warnings: Vec<String>,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+fn hash(values: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for value in values {
+        hasher.update(value.as_bytes());
+        hasher.update([0]);
+    }
+
+    hex::encode(hasher.finalize())
+}
+
+fn hash_file(file: &Path) -> String {
+    match fs::read(file) {
+        Ok(content) if file.is_file() => {
+            let mut hasher = Sha256::new();
+            hasher.update(content);
+            hex::encode(hasher.finalize())
+        }
+        _ => MISSING_FILE_HASH.to_string(),
+    }
+}
+
+/// Returns true unless the cache is disabled via the CARGO_MAKE_DISABLE_DESCRIPTOR_CACHE env var.
+pub(crate) fn is_enabled() -> bool {
+    !envmnt::is_or("CARGO_MAKE_DISABLE_DESCRIPTOR_CACHE", false)
+}
+
+/// Starts recording the files which contribute to the loaded config.
+pub(crate) fn start_recording() {
+    RECORDING.with(|recording| {
+        *recording.borrow_mut() = Some(Recording {
+            cacheable: true,
+            ..Default::default()
+        })
+    });
+}
+
+/// Stops the recording and returns the recorded data.
+pub(crate) fn stop_recording() -> Option<Recording> {
+    RECORDING.with(|recording| recording.borrow_mut().take())
+}
+
+fn update_recording<F: FnOnce(&mut Recording)>(update: F) {
+    RECORDING.with(|recording| {
+        if let Some(ref mut recording) = *recording.borrow_mut() {
+            update(recording);
+        }
+    });
+}
+
+/// Records a file which contributes to the config (the file does not have to exist).
+pub(crate) fn record_file(file: &Path) {
+    update_recording(|recording| recording.files.push(file.to_path_buf()));
+}
+
+/// Records the env vars which were set while loading the config.
+pub(crate) fn record_env(env: IndexMap<String, Option<String>>) {
+    update_recording(|recording| recording.env.extend(env));
+}
+
+/// Prints the warning and records it so it is printed again when the cached config is used.
+pub(crate) fn warn(message: &str) {
+    warn!("{}", message);
+
+    update_recording(|recording| recording.warnings.push(message.to_string()));
+}
+
+/// Marks the currently loaded config as not cacheable.
+pub(crate) fn disable_recording() {
+    update_recording(|recording| recording.cacheable = false);
+}
+
+/// Returns the cache key for the provided load arguments.
+pub(crate) fn get_key(
+    file_name: &str,
+    force: bool,
+    env_map: &Option<Vec<String>>,
+    experimental: bool,
+) -> String {
+    let env_values: Vec<String> = KEY_ENV_VARS
+        .iter()
+        .map(|key| format!("{}={}", key, envmnt::get_or(key, "")))
+        .collect();
+    let cli_env = env_map.clone().unwrap_or_default().join("\n");
+    let flags = format!("force={} experimental={}", force, experimental);
+
+    hash(&[
+        VERSION,
+        makefiles::BASE,
+        makefiles::STABLE,
+        makefiles::BETA,
+        file_name,
+        &flags,
+        &cli_env,
+        &env_values.join("\n"),
+    ])
+}
+
+/// Returns the directory which holds the cache files.
+pub(crate) fn get_cache_directory() -> Option<PathBuf> {
+    storage::get_storage_directory(dirs_next::cache_dir(), "descriptors", false)
+        .map(|storage_directory| storage_directory.join("descriptors"))
+}
+
+/// Returns the cache file (in the provided cache directory) for the makefile loaded from the
+/// current working directory.
+pub(crate) fn get_cache_file(cache_directory: &Path, file_name: &str) -> Option<PathBuf> {
+    let directory = env::current_dir().ok()?;
+    let name = hash(&[&directory.to_string_lossy(), file_name]);
+
+    Some(cache_directory.join(format!("{}.json", &name[..32])))
+}
+
+/// Returns the cached entry if the key matches and none of the contributing files changed.
+pub(crate) fn load(cache_file: &Path, key: &str) -> Option<CacheEntry> {
+    let content = fs::read_to_string(cache_file).ok()?;
+    let entry: CacheEntry = match serde_json::from_str(&content) {
+        Ok(entry) => entry,
+        Err(error) => {
+            debug!("Unable to parse descriptor cache file, {}", error);
+            return None;
+        }
+    };
+
+    if entry.key != key {
+        debug!("Descriptor cache key changed.");
+        None
+    } else if let Some((file, _)) = entry
+        .files
+        .iter()
+        .find(|(file, file_hash)| hash_file(Path::new(file)) != *file_hash)
+    {
+        debug!("Descriptor cache file: {} changed.", file);
+        None
+    } else {
+        debug!("Using cached descriptor: {:?}", cache_file);
+        Some(entry)
+    }
+}
+
+/// Stores the loaded config in the cache file.
+pub(crate) fn store(
+    cache_file: &Path,
+    key: &str,
+    recording: &Recording,
+    config: &Config,
+    env_origins: &IndexMap<String, Vec<EnvOrigin>>,
+) {
+    let mut files: Vec<(String, String)> = vec![];
+    for file in &recording.files {
+        let file_name = file.to_string_lossy().to_string();
+        if !files
+            .iter()
+            .any(|(existing_file, _)| existing_file == &file_name)
+        {
+            files.push((file_name, hash_file(file)));
+        }
+    }
+
+    let entry = CacheEntry {
+        key: key.to_string(),
+        files,
+        env: recording.env.clone(),
+        config: config.clone(),
+        env_origins: env_origins.clone(),
+        warnings: recording.warnings.clone(),
+    };
+
+    let content = match serde_json::to_string(&entry) {
+        Ok(content) => content,
+        Err(error) => {
+            debug!("Unable to serialize descriptor cache, {}", error);
+            return;
+        }
+    };
+
+    // write to a temporary file first so parallel invocations never read partial content
+    let temp_file = cache_file.with_extension(format!("{}.tmp", std::process::id()));
+    let result = cache_file
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&temp_file, content))
+        .and_then(|_| fs::rename(&temp_file, cache_file));
+
+    if let Err(error) = result {
+        debug!("Unable to write descriptor cache file, {}", error);
+        fs::remove_file(&temp_file).unwrap_or_default();
+    }
+}
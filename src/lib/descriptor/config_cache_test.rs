@@ -0,0 +1,158 @@
+use super::*;
+use crate::descriptor::load_config;
+use crate::io;
+use crate::test::get_temp_test_directory;
+
+fn create_entry_files(directory: &Path) -> (PathBuf, PathBuf, Recording) {
+    let makefile = directory.join("Makefile.toml");
+    io::write_text_file(&makefile.to_string_lossy(), "[tasks.test]\n");
+    let missing_file = directory.join("missing.toml");
+
+    let recording = Recording {
+        files: vec![makefile.clone(), missing_file.clone(), makefile.clone()],
+        env: IndexMap::from([
+            (
+                "CARGO_MAKE_MAKEFILE_PATH".to_string(),
+                Some(makefile.to_string_lossy().to_string()),
+            ),
+            ("CARGO_MAKE_TEST_REMOVED".to_string(), None),
+        ]),
+        warnings: vec!["Found unknown key: tasks.test.unknown".to_string()],
+        cacheable: true,
+    };
+
+    (makefile, missing_file, recording)
+}
+
+#[test]
+fn recording_flow() {
+    record_file(Path::new("not_recorded.toml"));
+    assert!(stop_recording().is_none());
+
+    start_recording();
+    record_file(Path::new("Makefile.toml"));
+    warn("Found unknown key: tasks.test.unknown");
+    record_env(IndexMap::from([(
+        "CARGO_MAKE_MAKEFILE_PATH".to_string(),
+        Some("/project/Makefile.toml".to_string()),
+    )]));
+
+    let recording = stop_recording().unwrap();
+    assert!(recording.cacheable);
+    assert_eq!(recording.files, vec![PathBuf::from("Makefile.toml")]);
+    assert_eq!(
+        recording.warnings,
+        vec!["Found unknown key: tasks.test.unknown".to_string()]
+    );
+    assert_eq!(
+        recording.env.get("CARGO_MAKE_MAKEFILE_PATH"),
+        Some(&Some("/project/Makefile.toml".to_string()))
+    );
+    assert!(stop_recording().is_none());
+
+    start_recording();
+    disable_recording();
+    assert!(!stop_recording().unwrap().cacheable);
+}
+
+#[test]
+fn get_key_variations() {
+    let key = get_key("Makefile.toml", false, &None, false);
+
+    assert_eq!(key, get_key("Makefile.toml", false, &None, false));
+    assert_ne!(key, get_key("Other.toml", false, &None, false));
+    assert_ne!(key, get_key("Makefile.toml", true, &None, false));
+    assert_ne!(key, get_key("Makefile.toml", false, &None, true));
+    assert_ne!(
+        key,
+        get_key(
+            "Makefile.toml",
+            false,
+            &Some(vec!["A=1".to_string()]),
+            false
+        )
+    );
+}
+
+#[test]
+fn get_cache_file_per_makefile() {
+    let directory = get_temp_test_directory("config_cache_get_cache_file_per_makefile");
+    let cache_file = get_cache_file(&directory, "Makefile.toml").unwrap();
+
+    assert!(cache_file.starts_with(&directory));
+    assert!(cache_file.to_string_lossy().ends_with(".json"));
+    assert_eq!(
+        cache_file,
+        get_cache_file(&directory, "Makefile.toml").unwrap()
+    );
+    assert_ne!(
+        cache_file,
+        get_cache_file(&directory, "Other.toml").unwrap()
+    );
+}
+
+#[test]
+fn store_and_load() {
+    let directory = get_temp_test_directory("config_cache_store_and_load");
+    let (makefile, missing_file, recording) = create_entry_files(&directory);
+    let cache_file = directory.join("cache/entry.json");
+
+    let mut config = Config::default();
+    config.env_scripts = vec!["echo test".to_string()];
+    store(&cache_file, "key", &recording, &config, &IndexMap::new());
+
+    let entry = load(&cache_file, "key").unwrap();
+    assert_eq!(entry.files.len(), 2);
+    assert_eq!(entry.files[1].1, MISSING_FILE_HASH);
+    assert_eq!(entry.env, recording.env);
+    assert_eq!(entry.warnings, recording.warnings);
+    assert_eq!(entry.config.env_scripts, config.env_scripts);
+
+    // different key
+    assert!(load(&cache_file, "other").is_none());
+
+    // modified file
+    io::write_text_file(&makefile.to_string_lossy(), "[tasks.modified]\n");
+    assert!(load(&cache_file, "key").is_none());
+    io::write_text_file(&makefile.to_string_lossy(), "[tasks.test]\n");
+    assert!(load(&cache_file, "key").is_some());
+
+    // new file in a location which was searched
+    io::write_text_file(&missing_file.to_string_lossy(), "[tasks.new]\n");
+    assert!(load(&cache_file, "key").is_none());
+}
+
+#[test]
+fn load_invalid_cache_file() {
+    let directory = get_temp_test_directory("config_cache_load_invalid_cache_file");
+    let cache_file = directory.join("entry.json");
+
+    assert!(load(&cache_file, "key").is_none());
+
+    io::write_text_file(&cache_file.to_string_lossy(), "{");
+    assert!(load(&cache_file, "key").is_none());
+}
+
+#[test]
+fn config_serialization_round_trip() {
+    let (config, env_origins) = load_config("./Makefile.toml", true, None, false).unwrap();
+    let directory = get_temp_test_directory("config_cache_config_serialization_round_trip");
+    let cache_file = directory.join("entry.json");
+
+    let recording = Recording {
+        cacheable: true,
+        ..Default::default()
+    };
+    store(&cache_file, "key", &recording, &config, &env_origins);
+    let entry = load(&cache_file, "key").unwrap();
+
+    // compared as json values since some of the config maps are not ordered
+    assert_eq!(
+        serde_json::to_value(&entry.config).unwrap(),
+        serde_json::to_value(&config).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&entry.env_origins).unwrap(),
+        serde_json::to_value(&env_origins).unwrap()
+    );
+}
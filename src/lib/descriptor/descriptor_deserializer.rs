@@ -1,13 +1,69 @@
 //! # descriptor_deserializer
 //!
-//! Deserializes and validates the configs.
+//! Deserializes and validates the configs.<br>
+//! External makefiles can be written in TOML, YAML or JSON, based on their file extension.
 
 #[cfg(test)]
 #[path = "descriptor_deserializer_test.rs"]
 mod descriptor_deserializer_test;
 
+use crate::descriptor::config_cache;
 use crate::error::CargoMakeError;
 use crate::types::{Config, ExternalConfig};
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The supported makefile formats
+pub(crate) enum DescriptorFormat {
+    /// TOML makefile (default)
+    Toml,
+    /// YAML makefile (.yaml/.yml extension)
+    Yaml,
+    /// JSON makefile (.json extension)
+    Json,
+}
+
+impl DescriptorFormat {
+    /// Returns the makefile format based on the file extension, defaulting to TOML.
+    pub(crate) fn from_file(file: &str) -> DescriptorFormat {
+        let extension = Path::new(file)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+
+        match extension.as_str() {
+            "yaml" | "yml" => DescriptorFormat::Yaml,
+            "json" => DescriptorFormat::Json,
+            _ => DescriptorFormat::Toml,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Holds the makefile parsing error
+pub(crate) struct ParseError {
+    /// The short error message
+    pub(crate) message: String,
+    /// The full error description as provided by the parser
+    pub(crate) description: String,
+    /// The line (1 based) of the error, if known
+    pub(crate) line: Option<usize>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", &self.description)
+    }
+}
+
+fn get_line(descriptor_string: &str, offset: usize) -> usize {
+    descriptor_string[..offset.min(descriptor_string.len())]
+        .lines()
+        .count()
+        .max(1)
+}
 
 pub(crate) fn load_config(
     descriptor_string: &str,
@@ -38,14 +94,86 @@ pub(crate) fn load_config(
     Ok(config)
 }
 
+fn append_path_keys(path: &serde_ignored::Path, keys: &mut Vec<String>) {
+    match path {
+        serde_ignored::Path::Root => (),
+        serde_ignored::Path::Seq { parent, index } => {
+            append_path_keys(parent, keys);
+            keys.push(index.to_string());
+        }
+        serde_ignored::Path::Map { parent, key } => {
+            append_path_keys(parent, keys);
+            keys.push(key.to_string());
+        }
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => append_path_keys(parent, keys),
+    }
+}
+
+/// Parses the external config in the provided format and invokes the callback with the
+/// key path of every unknown key found in it.
+fn deserialize_external_config<F>(
+    descriptor_string: &str,
+    format: DescriptorFormat,
+    mut callback: F,
+) -> Result<ExternalConfig, ParseError>
+where
+    F: FnMut(Vec<String>),
+{
+    let on_unknown_key = |path: serde_ignored::Path| {
+        let mut keys = vec![];
+        append_path_keys(&path, &mut keys);
+        callback(keys);
+    };
+
+    match format {
+        DescriptorFormat::Toml => {
+            let deserializer = toml::de::Deserializer::new(descriptor_string);
+
+            serde_ignored::deserialize(deserializer, on_unknown_key).map_err(|error| ParseError {
+                message: error.message().to_string(),
+                description: error.to_string(),
+                line: error
+                    .span()
+                    .map(|span| get_line(descriptor_string, span.start)),
+            })
+        }
+        DescriptorFormat::Yaml => {
+            let deserializer = serde_yaml_ng::Deserializer::from_str(descriptor_string);
+
+            serde_ignored::deserialize(deserializer, on_unknown_key).map_err(|error| ParseError {
+                message: error.to_string(),
+                description: error.to_string(),
+                line: error.location().map(|location| location.line()),
+            })
+        }
+        DescriptorFormat::Json => {
+            let mut deserializer = serde_json::Deserializer::from_str(descriptor_string);
+
+            serde_ignored::deserialize(&mut deserializer, on_unknown_key)
+                .and_then(|config| deserializer.end().map(|_| config))
+                .map_err(|error| ParseError {
+                    message: error.to_string(),
+                    description: error.to_string(),
+                    line: Some(error.line()),
+                })
+        }
+    }
+}
+
 pub(crate) fn load_external_config(
     descriptor_string: &str,
     file: &str,
 ) -> Result<ExternalConfig, CargoMakeError> {
-    let deserializer = toml::de::Deserializer::new(descriptor_string);
+    let format = DescriptorFormat::from_file(file);
 
-    match serde_ignored::deserialize(deserializer, |path| {
-        warn!("Found unknown key: {} in file: {}", path, file);
+    match deserialize_external_config(descriptor_string, format, |keys| {
+        config_cache::warn(&format!(
+            "Found unknown key: {} in file: {}",
+            keys.join("."),
+            file
+        ));
     }) {
         Ok(value) => Ok(value),
         Err(error) => {
@@ -57,3 +185,30 @@ pub(crate) fn load_external_config(
         }
     }
 }
+
+/// Parses the external config and returns it together with the key path of every
+/// unknown key found in it.
+pub(crate) fn load_external_config_with_unknown_keys(
+    descriptor_string: &str,
+    format: DescriptorFormat,
+) -> Result<(ExternalConfig, Vec<Vec<String>>), ParseError> {
+    let mut unknown_keys = vec![];
+    let config = deserialize_external_config(descriptor_string, format, |keys| {
+        unknown_keys.push(keys);
+    })?;
+
+    Ok((config, unknown_keys))
+}
+
+/// Parses the makefile in the provided format into a generic value.<br>
+/// Returns None in case the makefile is not valid.
+pub(crate) fn load_value(
+    descriptor_string: &str,
+    format: DescriptorFormat,
+) -> Option<serde_json::Value> {
+    match format {
+        DescriptorFormat::Toml => toml::from_str(descriptor_string).ok(),
+        DescriptorFormat::Yaml => serde_yaml_ng::from_str(descriptor_string).ok(),
+        DescriptorFormat::Json => serde_json::from_str(descriptor_string).ok(),
+    }
+}
@@ -77,3 +77,153 @@ category2 = "Tools"
 
     assert!(config.tasks.unwrap().contains_key("empty"));
 }
+
+#[test]
+fn load_external_config_with_unknown_keys_found() {
+    let (config, unknown_keys) = load_external_config_with_unknown_keys(
+        r#"
+unknown = true
+
+[tasks.build]
+command = "cargo"
+argz = ["build"]
+"#,
+        DescriptorFormat::Toml,
+    )
+    .unwrap();
+
+    assert!(config.tasks.unwrap().contains_key("build"));
+    assert_eq!(
+        unknown_keys,
+        vec![
+            vec!["unknown".to_string()],
+            vec!["tasks".to_string(), "build".to_string(), "argz".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn load_external_config_with_unknown_keys_parse_error() {
+    let result = load_external_config_with_unknown_keys(
+        r#"
+[tasks.build]
+command = "cargo
+"#,
+        DescriptorFormat::Toml,
+    );
+
+    let error = result.unwrap_err();
+    assert_eq!(error.line, Some(3));
+}
+
+#[test]
+fn descriptor_format_from_file() {
+    assert_eq!(
+        DescriptorFormat::from_file("Makefile.toml"),
+        DescriptorFormat::Toml
+    );
+    assert_eq!(
+        DescriptorFormat::from_file("Makefile"),
+        DescriptorFormat::Toml
+    );
+    assert_eq!(
+        DescriptorFormat::from_file("./dir/Makefile.yaml"),
+        DescriptorFormat::Yaml
+    );
+    assert_eq!(
+        DescriptorFormat::from_file("Makefile.YML"),
+        DescriptorFormat::Yaml
+    );
+    assert_eq!(
+        DescriptorFormat::from_file("tasks.json"),
+        DescriptorFormat::Json
+    );
+}
+
+#[test]
+fn load_external_config_yaml() {
+    let config = load_external_config(
+        r#"
+env:
+  VALUE: test
+tasks:
+  build:
+    command: cargo
+    args: ["build"]
+"#,
+        "Makefile.yaml",
+    )
+    .unwrap();
+
+    assert!(config.env.unwrap().contains_key("VALUE"));
+    let task = config.tasks.unwrap().get("build").unwrap().clone();
+    assert_eq!(task.command.unwrap(), "cargo");
+    assert_eq!(task.args.unwrap(), vec!["build".to_string()]);
+}
+
+#[test]
+fn load_external_config_json() {
+    let config = load_external_config(
+        r#"{ "tasks": { "build": { "command": "cargo", "args": ["build"] } } }"#,
+        "Makefile.json",
+    )
+    .unwrap();
+
+    let task = config.tasks.unwrap().get("build").unwrap().clone();
+    assert_eq!(task.command.unwrap(), "cargo");
+}
+
+#[test]
+fn load_external_config_with_unknown_keys_yaml_and_json() {
+    let (_, unknown_keys) = load_external_config_with_unknown_keys(
+        "tasks:\n  build:\n    command: cargo\n    argz: []\n",
+        DescriptorFormat::Yaml,
+    )
+    .unwrap();
+    assert_eq!(
+        unknown_keys,
+        vec![vec![
+            "tasks".to_string(),
+            "build".to_string(),
+            "argz".to_string()
+        ]]
+    );
+
+    let (_, unknown_keys) =
+        load_external_config_with_unknown_keys(r#"{ "unknown": true }"#, DescriptorFormat::Json)
+            .unwrap();
+    assert_eq!(unknown_keys, vec![vec!["unknown".to_string()]]);
+}
+
+#[test]
+fn load_external_config_with_unknown_keys_yaml_and_json_errors() {
+    let error =
+        load_external_config_with_unknown_keys("tasks:\n  build: [\n", DescriptorFormat::Yaml)
+            .unwrap_err();
+    assert!(error.line.is_some());
+
+    let error =
+        load_external_config_with_unknown_keys("{\n  \"tasks\": \n}", DescriptorFormat::Json)
+            .unwrap_err();
+    assert_eq!(error.line, Some(3));
+
+    let error = load_external_config_with_unknown_keys("{} {}", DescriptorFormat::Json);
+    assert!(error.is_err());
+}
+
+#[test]
+fn load_value_all_formats() {
+    let toml_value =
+        load_value("[config]\nmin_version = \"1.0.0\"", DescriptorFormat::Toml).unwrap();
+    let yaml_value = load_value("config:\n  min_version: 1.0.0", DescriptorFormat::Yaml).unwrap();
+    let json_value = load_value(
+        r#"{ "config": { "min_version": "1.0.0" } }"#,
+        DescriptorFormat::Json,
+    )
+    .unwrap();
+
+    assert_eq!(toml_value, yaml_value);
+    assert_eq!(toml_value, json_value);
+
+    assert!(load_value("{", DescriptorFormat::Json).is_none());
+}
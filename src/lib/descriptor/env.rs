@@ -1,6 +1,7 @@
 use crate::error::CargoMakeError;
 use crate::types::{
-    EnvFile, EnvValue, EnvValueConditioned, EnvValueDecode, EnvValuePathGlob, EnvValueScript,
+    EnvFile, EnvOrigin, EnvValue, EnvValueConditioned, EnvValueDecode, EnvValueFromFile,
+    EnvValueFromScript, EnvValuePathGlob, EnvValueScript, EnvValueSecret,
 };
 use indexmap::{IndexMap, IndexSet};
 use once_cell::sync::Lazy;
@@ -75,8 +76,12 @@ fn env_depends_on_find_script(val: &EnvValueScript) -> Vec<&str> {
         return depends_on.iter().map(String::as_str).collect();
     }
 
+    env_depends_on_find_script_lines(&val.script)
+}
+
+fn env_depends_on_find_script_lines(lines: &[String]) -> Vec<&str> {
     let mut depends_on = vec![];
-    for line in &val.script {
+    for line in lines {
         for captures in RE_SH_PARAM.captures_iter(line) {
             if let Some(capture) = captures.get(1).or_else(|| captures.get(2)) {
                 depends_on.push(capture.as_str());
@@ -118,6 +123,28 @@ fn env_depends_on(val: &EnvValue) -> Vec<&str> {
         EnvValue::Conditional(EnvValueConditioned { value, .. }) => env_depends_on_find(value),
         EnvValue::PathGlob(EnvValuePathGlob { glob, .. }) => env_depends_on_find(glob),
         EnvValue::Script(script) => env_depends_on_find_script(script),
+        EnvValue::Secret(EnvValueSecret {
+            value,
+            file,
+            script,
+            ..
+        }) => {
+            let mut depends_on = vec![];
+            if let Some(value) = value {
+                depends_on.append(&mut env_depends_on_find(value));
+            }
+            if let Some(file) = file {
+                depends_on.append(&mut env_depends_on_find(file));
+            }
+            if let Some(script) = script {
+                depends_on.append(&mut env_depends_on_find_script_lines(script));
+            }
+            depends_on
+        }
+        EnvValue::FromFile(EnvValueFromFile { from_file, .. }) => env_depends_on_find(from_file),
+        EnvValue::FromScript(EnvValueFromScript { from_script, .. }) => {
+            env_depends_on_find_script_lines(from_script)
+        }
         _ => vec![],
     }
 }
@@ -223,3 +250,53 @@ pub(crate) fn merge_env_files(
 pub(crate) fn merge_env_scripts(base: &mut Vec<String>, extended: &mut Vec<String>) -> Vec<String> {
     [&extended[..], &base[..]].concat()
 }
+
+/// Creates the origins of all the provided env values.<br>
+/// Profile env blocks are flattened so each value is tracked under its own env name.
+pub(crate) fn create_env_origins(
+    env: &IndexMap<String, EnvValue>,
+    file: Option<&str>,
+    layer: &str,
+) -> IndexMap<String, Vec<EnvOrigin>> {
+    let mut origins = IndexMap::<String, Vec<EnvOrigin>>::new();
+
+    for (key, value) in env {
+        match value {
+            EnvValue::Profile(sub_env) => {
+                for (sub_key, sub_value) in sub_env {
+                    origins
+                        .entry(sub_key.to_string())
+                        .or_default()
+                        .push(EnvOrigin {
+                            file: file.map(|value| value.to_string()),
+                            layer: layer.to_string(),
+                            profile: Some(key.to_string()),
+                            value: sub_value.clone(),
+                        });
+                }
+            }
+            _ => origins.entry(key.to_string()).or_default().push(EnvOrigin {
+                file: file.map(|value| value.to_string()),
+                layer: layer.to_string(),
+                profile: None,
+                value: value.clone(),
+            }),
+        }
+    }
+
+    origins
+}
+
+/// Merges the env origins, extended origins are appended after the base origins
+pub(crate) fn merge_env_origins(
+    base: Option<IndexMap<String, Vec<EnvOrigin>>>,
+    extended: Option<IndexMap<String, Vec<EnvOrigin>>>,
+) -> IndexMap<String, Vec<EnvOrigin>> {
+    let mut merged = base.unwrap_or_default();
+
+    for (key, mut origins) in extended.unwrap_or_default() {
+        merged.entry(key).or_default().append(&mut origins);
+    }
+
+    merged
+}
@@ -1,4 +1,4 @@
-use crate::descriptor::env::merge_env;
+use crate::descriptor::env::{create_env_origins, merge_env, merge_env_origins};
 use crate::descriptor::load;
 use crate::environment;
 use crate::types::{EnvValue, EnvValueConditioned, EnvValuePathGlob, EnvValueScript};
@@ -402,7 +402,7 @@ fn load_env_reorder() {
     let toml_file = "./src/lib/test/makefiles/env-reorder.toml";
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let (config, _) = load(toml_file, true, None, false).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("ENV4", "--defined yes"));
@@ -419,7 +419,7 @@ fn load_env_reorder_extend() {
     let toml_file = "./src/lib/test/makefiles/env-reorder-extended.toml";
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4", "ENV5", "ENV6"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let (config, _) = load(toml_file, true, None, false).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("ENV6", "--verbose"));
@@ -440,3 +440,59 @@ fn load_env_reorder_extend() {
 
     envmnt::remove_all(&vec!["ENV1", "ENV2", "ENV3", "ENV4", "ENV5", "ENV6"]);
 }
+
+#[test]
+fn create_env_origins_with_profile() {
+    let mut profile_env = IndexMap::new();
+    profile_env.insert("KEY1".to_string(), EnvValue::Value("profile".to_string()));
+
+    let mut env = IndexMap::new();
+    env.insert("KEY1".to_string(), EnvValue::Value("value".to_string()));
+    env.insert("KEY2".to_string(), EnvValue::Boolean(true));
+    env.insert("production".to_string(), EnvValue::Profile(profile_env));
+
+    let origins = create_env_origins(&env, Some("Makefile.toml"), "makefile");
+
+    assert_eq!(origins.len(), 2);
+    assert!(!origins.contains_key("production"));
+
+    let key1_origins = origins.get("KEY1").unwrap();
+    assert_eq!(key1_origins.len(), 2);
+    assert_eq!(key1_origins[0].file, Some("Makefile.toml".to_string()));
+    assert_eq!(key1_origins[0].layer, "makefile");
+    assert!(key1_origins[0].profile.is_none());
+    assert_eq!(key1_origins[1].profile, Some("production".to_string()));
+    match key1_origins[1].value {
+        EnvValue::Value(ref value) => assert_eq!(value, "profile"),
+        _ => panic!("invalid env value type"),
+    };
+
+    assert_eq!(origins.get("KEY2").unwrap().len(), 1);
+}
+
+#[test]
+fn merge_env_origins_order() {
+    let mut base_env = IndexMap::new();
+    base_env.insert("KEY1".to_string(), EnvValue::Value("base".to_string()));
+    let base = create_env_origins(&base_env, None, "internal");
+
+    let mut extended_env = IndexMap::new();
+    extended_env.insert("KEY1".to_string(), EnvValue::Value("extended".to_string()));
+    extended_env.insert("KEY2".to_string(), EnvValue::Value("extended".to_string()));
+    let extended = create_env_origins(&extended_env, Some("Makefile.toml"), "makefile");
+
+    let merged = merge_env_origins(Some(base), Some(extended));
+
+    let key1_origins = merged.get("KEY1").unwrap();
+    assert_eq!(key1_origins.len(), 2);
+    assert_eq!(key1_origins[0].layer, "internal");
+    assert_eq!(key1_origins[1].layer, "makefile");
+    assert_eq!(merged.get("KEY2").unwrap().len(), 1);
+}
+
+#[test]
+fn merge_env_origins_none() {
+    let merged = merge_env_origins(None, None);
+
+    assert!(merged.is_empty());
+}
@@ -0,0 +1,282 @@
+//! # git_extend
+//!
+//! Resolves makefiles extended from git repositories.<br>
+//! Repositories are cloned into the cargo-make storage directory (one checkout per commit)
+//! and the resolved commits are pinned in a Makefile.lock file next to the makefile which
+//! declares the git extend.<br>
+//! Each clone is done in a unique temporary directory which is moved into place once done, so
+//! concurrent invocations never see (or remove) a partial checkout.
+//!
+
+#[cfg(test)]
+#[path = "git_extend_test.rs"]
+mod git_extend_test;
+
+use crate::error::CargoMakeError;
+use crate::{io, storage};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The lock file name, created next to the makefile which declares the git extend
+pub(crate) static LOCK_FILE: &str = "Makefile.lock";
+/// Serializes the lock file updates of the current process
+static LOCK_FILE_UPDATE: Mutex<()> = Mutex::new(());
+static LOCK_FILE_HEADER: &str =
+    "# This file is generated by cargo-make, it pins the git extended makefiles commits.\n\n";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+/// Holds the lock file content
+pub(crate) struct MakefileLock {
+    #[serde(default)]
+    /// The locked git repositories
+    pub(crate) git: Vec<GitLock>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Holds a single locked git repository revision
+pub(crate) struct GitLock {
+    /// The git repository url
+    pub(crate) url: String,
+    /// The requested revision (none for the default branch)
+    pub(crate) rev: Option<String>,
+    /// The resolved commit
+    pub(crate) commit: String,
+}
+
+impl MakefileLock {
+    fn get_commit(&self, url: &str, rev: &Option<String>) -> Option<String> {
+        self.git
+            .iter()
+            .find(|lock| lock.url == url && &lock.rev == rev)
+            .map(|lock| lock.commit.clone())
+    }
+}
+
+fn create_error(url: &str, message: String) -> CargoMakeError {
+    CargoMakeError::GitExtendFailed(url.to_string(), message)
+}
+
+fn run_git(url: &str, args: &[&str], directory: &Path) -> Result<String, CargoMakeError> {
+    debug!("Running git command: {:?} in: {:?}", args, directory);
+
+    match Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+    {
+        Ok(output) => {
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+            } else {
+                Err(create_error(
+                    url,
+                    format!(
+                        "git {} failed: {}",
+                        args.join(" "),
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ),
+                ))
+            }
+        }
+        Err(error) => Err(create_error(url, format!("unable to run git, {}", error))),
+    }
+}
+
+fn get_repository_name(url: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(url.as_bytes());
+    let hash = hex::encode(hasher.finalize());
+
+    hash[..16].to_string()
+}
+
+/// Returns the default directory in which git repositories are cloned.
+pub(crate) fn get_storage_directory() -> Option<PathBuf> {
+    storage::get_storage_directory(dirs_next::cache_dir(), "git", false)
+        .map(|directory| directory.join("git"))
+}
+
+pub(crate) fn load_lock(lock_file: &Path) -> Result<MakefileLock, CargoMakeError> {
+    if lock_file.exists() {
+        let content = io::read_text_file(&lock_file.to_path_buf())?;
+
+        match toml::from_str(&content) {
+            Ok(lock) => Ok(lock),
+            Err(error) => Err(CargoMakeError::ParseFileFailed(
+                lock_file.to_string_lossy().to_string(),
+                error.to_string(),
+            )),
+        }
+    } else {
+        Ok(MakefileLock::default())
+    }
+}
+
+/// Returns a unique temporary path next to the provided path.
+fn get_temp_path(path: &Path) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let name = path
+        .file_name()
+        .map_or("".to_string(), |name| name.to_string_lossy().to_string());
+    path.with_file_name(format!(
+        ".{}.{}-{}",
+        name,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    ))
+}
+
+fn store_lock(lock_file: &Path, lock: &MakefileLock) -> Result<(), CargoMakeError> {
+    match toml::to_string_pretty(lock) {
+        Ok(content) => {
+            let content = format!("{}{}", LOCK_FILE_HEADER, content);
+
+            // the lock file is replaced at once so it is never read partially written
+            let temp_file = get_temp_path(lock_file);
+            if io::write_text_file(&temp_file.to_string_lossy(), &content) {
+                fs::rename(&temp_file, lock_file)?;
+                Ok(())
+            } else {
+                Err(CargoMakeError::NotFound(format!(
+                    "Unable to write lock file: {:#?}",
+                    lock_file
+                )))
+            }
+        }
+        Err(error) => Err(CargoMakeError::ParseFileFailed(
+            lock_file.to_string_lossy().to_string(),
+            error.to_string(),
+        )),
+    }
+}
+
+fn clone_repository(
+    url: &str,
+    rev: &Option<String>,
+    directory: &Path,
+) -> Result<String, CargoMakeError> {
+    fs::create_dir_all(directory)?;
+
+    run_git(url, &["clone", "--quiet", url, "."], directory)?;
+    if let Some(rev) = rev {
+        run_git(url, &["checkout", "--quiet", "--detach", rev], directory)?;
+    }
+
+    run_git(url, &["rev-parse", "HEAD"], directory)
+}
+
+/// Moves the cloned repository into the commit directory.<br>
+/// If another invocation already created the commit directory, it is used as is.
+fn move_checkout(
+    url: &str,
+    clone_directory: &Path,
+    commit_directory: &Path,
+) -> Result<(), CargoMakeError> {
+    if !commit_directory.join(".git").exists() {
+        // partial checkouts of older versions which cloned in place
+        if commit_directory.exists() {
+            fs::remove_dir_all(commit_directory)?;
+        }
+
+        if fs::rename(clone_directory, commit_directory).is_ok() {
+            return Ok(());
+        }
+    }
+
+    fs::remove_dir_all(clone_directory)?;
+
+    if commit_directory.join(".git").exists() {
+        Ok(())
+    } else {
+        Err(create_error(
+            url,
+            format!("unable to create checkout: {:?}", commit_directory),
+        ))
+    }
+}
+
+/// Clones the repository revision into a unique temporary directory and returns the
+/// directory and the resolved commit.
+fn clone_to_temp_directory(
+    url: &str,
+    rev: &Option<String>,
+    repository_directory: &Path,
+) -> Result<(PathBuf, String), CargoMakeError> {
+    let clone_directory = get_temp_path(&repository_directory.join("clone"));
+
+    match clone_repository(url, rev, &clone_directory) {
+        Ok(commit) => Ok((clone_directory, commit)),
+        Err(error) => {
+            if let Err(remove_error) = fs::remove_dir_all(&clone_directory) {
+                debug!("Unable to remove clone directory, {}", remove_error);
+            }
+
+            Err(error)
+        }
+    }
+}
+
+/// Returns the checkout directory of the requested git repository revision.<br>
+/// A revision pinned in the lock file is always used as is, otherwise the revision
+/// is resolved to a commit and added to the lock file.
+pub(crate) fn get_repository_directory(
+    url: &str,
+    rev: &Option<String>,
+    storage_directory: &Path,
+    lock_file: &Path,
+) -> Result<PathBuf, CargoMakeError> {
+    let repository_directory = storage_directory.join(get_repository_name(url));
+    let lock = load_lock(lock_file)?;
+
+    match lock.get_commit(url, rev) {
+        Some(commit) => {
+            let commit_directory = repository_directory.join(&commit);
+
+            if !commit_directory.join(".git").exists() {
+                debug!("Cloning git repository: {} commit: {}", url, &commit);
+                let (clone_directory, head) =
+                    clone_to_temp_directory(url, &Some(commit.clone()), &repository_directory)?;
+                if head != commit {
+                    fs::remove_dir_all(&clone_directory)?;
+                    return Err(create_error(
+                        url,
+                        format!("locked commit: {} resolved to: {}", &commit, &head),
+                    ));
+                }
+
+                move_checkout(url, &clone_directory, &commit_directory)?;
+            }
+
+            Ok(commit_directory)
+        }
+        None => {
+            debug!("Resolving git repository: {} revision: {:?}", url, rev);
+            let (clone_directory, commit) =
+                clone_to_temp_directory(url, rev, &repository_directory)?;
+
+            let commit_directory = repository_directory.join(&commit);
+            move_checkout(url, &clone_directory, &commit_directory)?;
+
+            // the lock file may have been updated by another invocation in the meantime
+            let _guard = LOCK_FILE_UPDATE
+                .lock()
+                .unwrap_or_else(|error| error.into_inner());
+            let mut lock = load_lock(lock_file)?;
+            if lock.get_commit(url, rev).is_none() {
+                lock.git.push(GitLock {
+                    url: url.to_string(),
+                    rev: rev.clone(),
+                    commit,
+                });
+                store_lock(lock_file, &lock)?;
+            }
+
+            Ok(commit_directory)
+        }
+    }
+}
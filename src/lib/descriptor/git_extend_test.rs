@@ -0,0 +1,210 @@
+use super::*;
+use crate::test::get_temp_test_directory;
+
+fn git(directory: &Path, args: &[&str]) -> String {
+    let mut git_args = vec![
+        "-c",
+        "user.name=cargo-make",
+        "-c",
+        "user.email=cargo-make@test.com",
+        "-c",
+        "commit.gpgsign=false",
+    ];
+    git_args.extend_from_slice(args);
+
+    run_git("test", &git_args, directory).unwrap()
+}
+
+fn commit_makefile(directory: &Path, content: &str) -> String {
+    io::write_text_file(&directory.join("Makefile.toml").to_string_lossy(), content);
+    git(directory, &["add", "-A"]);
+    git(directory, &["commit", "--quiet", "-m", "update"]);
+
+    git(directory, &["rev-parse", "HEAD"])
+}
+
+/// Creates a local git repository and returns its directory and file url.
+fn create_repository(directory: &Path) -> (PathBuf, String) {
+    let repository = directory.join("repository");
+    fs::create_dir_all(&repository).unwrap();
+    git(&repository, &["init", "--quiet"]);
+
+    let url = format!("file://{}", repository.to_string_lossy());
+
+    (repository, url)
+}
+
+fn read_makefile(directory: &Path) -> String {
+    io::read_text_file(&directory.join("Makefile.toml")).unwrap()
+}
+
+#[test]
+fn get_repository_name_hashed() {
+    let name = get_repository_name("https://github.com/sagiegurari/cargo-make.git");
+
+    assert_eq!(name.len(), 16);
+    assert_eq!(
+        name,
+        get_repository_name("https://github.com/sagiegurari/cargo-make.git")
+    );
+    assert_ne!(name, get_repository_name("file:///tmp/repository"));
+}
+
+#[test]
+fn load_lock_not_exists() {
+    let directory = get_temp_test_directory("git_extend_load_lock_not_exists");
+
+    let lock = load_lock(&directory.join(LOCK_FILE)).unwrap();
+
+    assert_eq!(lock, MakefileLock::default());
+}
+
+#[test]
+fn load_lock_invalid() {
+    let directory = get_temp_test_directory("git_extend_load_lock_invalid");
+    let lock_file = directory.join(LOCK_FILE);
+    io::write_text_file(&lock_file.to_string_lossy(), "[[git]]\nurl = 1");
+
+    let result = load_lock(&lock_file);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_repository_directory_resolve_and_lock() {
+    let directory = get_temp_test_directory("git_extend_resolve_and_lock");
+    let (repository, url) = create_repository(&directory);
+    let commit = commit_makefile(&repository, "[tasks.first]\n");
+    git(&repository, &["tag", "v1"]);
+    commit_makefile(&repository, "[tasks.second]\n");
+
+    let storage_directory = directory.join("storage");
+    let lock_file = directory.join(LOCK_FILE);
+
+    let checkout = get_repository_directory(
+        &url,
+        &Some("v1".to_string()),
+        &storage_directory,
+        &lock_file,
+    )
+    .unwrap();
+
+    assert!(checkout.starts_with(&storage_directory));
+    assert!(checkout.ends_with(&commit));
+    assert_eq!(read_makefile(&checkout), "[tasks.first]\n");
+
+    let lock = load_lock(&lock_file).unwrap();
+    assert_eq!(
+        lock.git,
+        vec![GitLock {
+            url: url.clone(),
+            rev: Some("v1".to_string()),
+            commit,
+        }]
+    );
+}
+
+#[test]
+fn get_repository_directory_locked() {
+    let directory = get_temp_test_directory("git_extend_locked");
+    let (repository, url) = create_repository(&directory);
+    let commit = commit_makefile(&repository, "[tasks.first]\n");
+
+    let storage_directory = directory.join("storage");
+    let lock_file = directory.join(LOCK_FILE);
+
+    let checkout = get_repository_directory(&url, &None, &storage_directory, &lock_file).unwrap();
+    assert_eq!(read_makefile(&checkout), "[tasks.first]\n");
+
+    // the default branch moves but the lock file still pins the first commit
+    commit_makefile(&repository, "[tasks.second]\n");
+    fs::remove_dir_all(&storage_directory).unwrap();
+
+    let checkout = get_repository_directory(&url, &None, &storage_directory, &lock_file).unwrap();
+    assert!(checkout.ends_with(&commit));
+    assert_eq!(read_makefile(&checkout), "[tasks.first]\n");
+
+    // removing the lock file resolves the branch again
+    fs::remove_file(&lock_file).unwrap();
+
+    let checkout = get_repository_directory(&url, &None, &storage_directory, &lock_file).unwrap();
+    assert_eq!(read_makefile(&checkout), "[tasks.second]\n");
+    assert_eq!(load_lock(&lock_file).unwrap().git.len(), 1);
+}
+
+#[test]
+fn get_repository_directory_invalid_revision() {
+    let directory = get_temp_test_directory("git_extend_invalid_revision");
+    let (repository, url) = create_repository(&directory);
+    commit_makefile(&repository, "[tasks.first]\n");
+
+    let lock_file = directory.join(LOCK_FILE);
+    let result = get_repository_directory(
+        &url,
+        &Some("missing".to_string()),
+        &directory.join("storage"),
+        &lock_file,
+    );
+
+    match result {
+        Err(CargoMakeError::GitExtendFailed(error_url, _)) => assert_eq!(error_url, url),
+        _ => panic!("invalid revision should fail"),
+    }
+    assert!(!lock_file.exists());
+
+    let repository_directory = directory.join("storage").join(get_repository_name(&url));
+    assert_eq!(fs::read_dir(&repository_directory).unwrap().count(), 0);
+}
+
+#[test]
+fn get_repository_directory_invalid_url() {
+    let directory = get_temp_test_directory("git_extend_invalid_url");
+    let url = format!("file://{}", directory.join("missing").to_string_lossy());
+
+    let result = get_repository_directory(
+        &url,
+        &None,
+        &directory.join("storage"),
+        &directory.join(LOCK_FILE),
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn get_repository_directory_concurrent() {
+    let directory = get_temp_test_directory("git_extend_concurrent");
+    let (repository, url) = create_repository(&directory);
+    let commit = commit_makefile(&repository, "[tasks.first]\n");
+
+    let storage_directory = directory.join("storage");
+    let lock_file = directory.join(LOCK_FILE);
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let url = url.clone();
+            let storage_directory = storage_directory.clone();
+            let lock_file = lock_file.clone();
+
+            std::thread::spawn(move || {
+                get_repository_directory(&url, &None, &storage_directory, &lock_file).unwrap()
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        let checkout = thread.join().unwrap();
+        assert!(checkout.ends_with(&commit));
+        assert_eq!(read_makefile(&checkout), "[tasks.first]\n");
+    }
+
+    // no temporary clone directories are left behind
+    let repository_directory = storage_directory.join(get_repository_name(&url));
+    let entries: Vec<_> = fs::read_dir(&repository_directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, vec![std::ffi::OsString::from(&commit)]);
+
+    assert_eq!(load_lock(&lock_file).unwrap().git.len(), 1);
+}
@@ -0,0 +1,361 @@
+//! # gnu_make
+//!
+//! Converts the rules and variables of GNU Makefiles.<br>
+//! Prerequisites which are defined as rules are converted to task dependencies, while file
+//! prerequisites are ignored (tasks are always invoked and are not based on timestamps).
+//!
+
+#[cfg(test)]
+#[path = "gnu_make_test.rs"]
+mod gnu_make_test;
+
+use super::{convert_command_prefix, create_task, get_description, Import};
+use crate::descriptor::config_cache;
+use crate::types::{EnvValue, EnvValueConditioned, EnvValueScript, TaskCondition};
+use indexmap::IndexMap;
+use regex::Regex;
+
+/// Directives which are skipped (conditionals are skipped together with their content)
+static UNSUPPORTED_DIRECTIVES: [&str; 12] = [
+    "include", "-include", "sinclude", "ifeq", "ifneq", "ifdef", "ifndef", "else", "endif",
+    "override", "vpath", "undefine",
+];
+
+#[derive(Debug, Default)]
+struct Rule {
+    description: Option<String>,
+    prerequisites: Vec<String>,
+    recipe: Vec<String>,
+    env: IndexMap<String, EnvValue>,
+}
+
+fn get_assignment_regex() -> Regex {
+    Regex::new(r"^([A-Za-z_][A-Za-z0-9_.-]*)\s*(:::=|::=|:=|\?=|\+=|!=|=)\s*(.*)$").unwrap()
+}
+
+fn is_variable_name(value: &str) -> bool {
+    !value.is_empty()
+        && value.chars().all(|value| {
+            value.is_ascii_alphanumeric() || value == '_' || value == '.' || value == '-'
+        })
+}
+
+/// Returns the content of the reference which starts at the provided text (after the
+/// opening parenthesis/brace) and its length.
+fn get_reference(text: &str, close: char) -> Option<(&str, usize)> {
+    let open = if close == ')' { '(' } else { '{' };
+    let mut depth = 0;
+
+    for (index, value) in text.char_indices() {
+        if value == open {
+            depth += 1;
+        } else if value == close {
+            if depth == 0 {
+                return Some((&text[..index], index + 1));
+            }
+            depth -= 1;
+        }
+    }
+
+    None
+}
+
+/// Converts the make variable references ($(NAME), ${NAME}, $X and automatic variables)
+/// into cargo-make ${NAME} expansions.
+fn convert_references(text: &str, target: &str, prerequisites: &[String]) -> String {
+    let mut output = String::new();
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find('$') {
+        output.push_str(&remaining[..start]);
+        let reference = &remaining[start + 1..];
+        let mut consumed = 1;
+
+        match reference.chars().next() {
+            Some('$') => output.push('$'),
+            Some('@') if !target.is_empty() => output.push_str(target),
+            Some('<') if !target.is_empty() => {
+                output.push_str(prerequisites.first().map(String::as_str).unwrap_or(""))
+            }
+            Some('^' | '+') if !target.is_empty() => output.push_str(&prerequisites.join(" ")),
+            Some(close @ ('(' | '{')) => {
+                let close = if close == '(' { ')' } else { '}' };
+
+                match get_reference(&reference[1..], close) {
+                    Some((name, length)) => {
+                        consumed = length + 1;
+
+                        match name {
+                            "MAKE" => output.push_str("make"),
+                            "CURDIR" => output.push_str("${CARGO_MAKE_WORKING_DIRECTORY}"),
+                            _ if is_variable_name(name) => {
+                                output.push_str(&format!("${{{}}}", name))
+                            }
+                            _ => {
+                                config_cache::warn(&format!(
+                                    "The make expression: $({}) is not supported and must be converted manually.",
+                                    name
+                                ));
+                                output.push('$');
+                                output.push_str(&reference[..consumed]);
+                            }
+                        }
+                    }
+                    None => {
+                        consumed = 0;
+                        output.push('$');
+                    }
+                }
+            }
+            Some(name) if name.is_ascii_alphabetic() => output.push_str(&format!("${{{}}}", name)),
+            Some(name) => {
+                if !target.is_empty() && ['?', '*', '%', '|'].contains(&name) {
+                    config_cache::warn(&format!(
+                        "Rule: {} automatic variable: ${} is not supported and must be converted manually.",
+                        target, name
+                    ));
+                }
+                consumed = 0;
+                output.push('$');
+            }
+            None => {
+                consumed = 0;
+                output.push('$');
+            }
+        }
+
+        remaining = &reference[consumed..];
+    }
+    output.push_str(remaining);
+
+    output
+}
+
+/// Joins lines which end with a backslash, unless they are recipe lines in which case
+/// they are handled by the shell.
+fn join_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    let mut continued = false;
+
+    for line in content.lines() {
+        if continued {
+            if let Some(last) = lines.last_mut() {
+                last.pop();
+                last.truncate(last.trim_end().len());
+                last.push(' ');
+                last.push_str(line.trim());
+            }
+        } else {
+            lines.push(line.to_string());
+        }
+
+        let is_recipe = lines.last().is_some_and(|last| last.starts_with('\t'));
+        continued = !is_recipe && line.ends_with('\\');
+    }
+
+    lines
+}
+
+fn convert_value(name: &str, operator: &str, value: &str, output: &mut Import) -> EnvValue {
+    let value = convert_references(value.trim(), "", &[]);
+
+    match operator {
+        "?=" => EnvValue::Conditional(EnvValueConditioned {
+            value,
+            condition: Some(TaskCondition {
+                env_not_set: Some(vec![name.to_string()]),
+                ..Default::default()
+            }),
+        }),
+        "!=" => EnvValue::Script(EnvValueScript {
+            script: vec![value],
+            multi_line: None,
+            condition: None,
+            depends_on: None,
+        }),
+        "+=" => match output.env.get(name) {
+            Some(EnvValue::Value(current_value)) => {
+                EnvValue::Value(format!("{} {}", current_value, value))
+            }
+            _ => EnvValue::Value(value),
+        },
+        _ => EnvValue::Value(value),
+    }
+}
+
+/// Parses the rule definition, returns None if the line is not a rule.
+fn parse_rule(
+    line: &str,
+    comments: &[String],
+    assignment_regex: &Regex,
+) -> Option<(Vec<String>, Rule)> {
+    let (targets, rest) = line.split_once(':')?;
+    let rest = rest.trim_start_matches(':');
+
+    let (rest, description) = match rest.split_once("##") {
+        Some((rest, description)) => (rest, get_description(&[description.to_string()])),
+        None => (rest, get_description(comments)),
+    };
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+
+    let mut rule = Rule {
+        description,
+        ..Default::default()
+    };
+
+    if let Some(captures) = assignment_regex.captures(rest.trim()) {
+        // target specific variable
+        let name = captures[1].to_string();
+        let value = convert_references(captures[3].trim(), "", &[]);
+        rule.env.insert(name, EnvValue::Value(value));
+    } else {
+        let (prerequisites, recipe) = match rest.split_once(';') {
+            Some((prerequisites, recipe)) => (prerequisites, Some(recipe.trim().to_string())),
+            None => (rest, None),
+        };
+
+        rule.prerequisites = prerequisites
+            .split_whitespace()
+            .filter(|prerequisite| *prerequisite != "|")
+            .map(str::to_string)
+            .collect();
+        rule.recipe.extend(recipe);
+    }
+
+    let targets = targets
+        .split_whitespace()
+        .filter(|target| {
+            if target.starts_with('.') {
+                // special targets such as .PHONY
+                false
+            } else if target.contains('%') || target.contains('$') {
+                config_cache::warn(&format!(
+                    "Rule: {} is not supported and must be converted manually.",
+                    target
+                ));
+                false
+            } else {
+                true
+            }
+        })
+        .map(str::to_string)
+        .collect();
+
+    Some((targets, rule))
+}
+
+fn add_rule(targets: Vec<String>, rule: Rule, rules: &mut IndexMap<String, Rule>) {
+    for target in targets {
+        let existing_rule = rules.entry(target).or_default();
+
+        // rules can be defined multiple times, the last recipe is used
+        existing_rule
+            .prerequisites
+            .extend(rule.prerequisites.iter().cloned());
+        existing_rule.env.extend(rule.env.clone());
+        if rule.description.is_some() {
+            existing_rule.description = rule.description.clone();
+        }
+        if !rule.recipe.is_empty() {
+            existing_rule.recipe = rule.recipe.clone();
+        }
+    }
+}
+
+pub(super) fn import(content: &str) -> Result<Import, String> {
+    let assignment_regex = get_assignment_regex();
+    let mut output = Import::default();
+    let mut rules: IndexMap<String, Rule> = IndexMap::new();
+    let mut current_rule: Option<(Vec<String>, Rule)> = None;
+    let mut comments: Vec<String> = vec![];
+    let mut in_define = false;
+
+    for line in join_lines(content) {
+        let trimmed = line.trim();
+
+        if in_define {
+            in_define = trimmed != "endef";
+            continue;
+        } else if let Some(recipe_line) = line.strip_prefix('\t') {
+            match current_rule {
+                Some((_, ref mut rule)) => rule.recipe.push(recipe_line.to_string()),
+                None => config_cache::warn(&format!(
+                    "Skipping recipe line outside of a rule: {}",
+                    trimmed
+                )),
+            }
+            continue;
+        } else if trimmed.is_empty() {
+            comments.clear();
+            continue;
+        } else if trimmed.starts_with('#') {
+            comments.push(trimmed.to_string());
+            continue;
+        }
+
+        if let Some((targets, rule)) = current_rule.take() {
+            add_rule(targets, rule, &mut rules);
+        }
+
+        let (keyword, rest) = trimmed
+            .split_once(char::is_whitespace)
+            .unwrap_or((trimmed, ""));
+        let rest = rest.trim();
+
+        if keyword == "define" {
+            config_cache::warn(&format!(
+                "The define directive is not supported: {}",
+                trimmed
+            ));
+            in_define = true;
+        } else if UNSUPPORTED_DIRECTIVES.contains(&keyword) {
+            config_cache::warn(&format!(
+                "The {} directive is not supported: {}",
+                keyword, trimmed
+            ));
+        } else if (keyword == "export" && !assignment_regex.is_match(rest)) || keyword == "unexport"
+        {
+            // all variables are converted to env values
+            debug!("Skipping directive: {}", trimmed);
+        } else if let Some(captures) =
+            assignment_regex.captures(if keyword == "export" { rest } else { trimmed })
+        {
+            let name = captures[1].to_string();
+            let value = convert_value(&name, &captures[2], &captures[3], &mut output);
+            output.env.insert(name, value);
+        } else {
+            match parse_rule(trimmed, &comments, &assignment_regex) {
+                Some(rule) => current_rule = Some(rule),
+                None => config_cache::warn(&format!("Unable to convert line: {}", trimmed)),
+            }
+        }
+
+        comments.clear();
+    }
+    if let Some((targets, rule)) = current_rule.take() {
+        add_rule(targets, rule, &mut rules);
+    }
+
+    for (target, rule) in &rules {
+        let prerequisites: Vec<String> = rule.prerequisites.clone();
+        let dependencies: Vec<String> = prerequisites
+            .iter()
+            .filter(|prerequisite| rules.contains_key(*prerequisite))
+            .cloned()
+            .collect();
+        let script = rule
+            .recipe
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| convert_references(&convert_command_prefix(line), target, &prerequisites))
+            .collect();
+
+        let mut task = create_task(rule.description.clone(), &dependencies, script);
+        if !rule.env.is_empty() {
+            task.env = Some(rule.env.clone());
+        }
+        output.tasks.insert(target.clone(), task);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,127 @@
+use super::*;
+use serde_json::json;
+
+fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn join_lines_continuation() {
+    let lines = join_lines("SOURCES = a.c \\\n    b.c\nbuild:\n\techo a \\\n\techo b\n");
+
+    assert_eq!(
+        lines,
+        vec![
+            "SOURCES = a.c b.c".to_string(),
+            "build:".to_string(),
+            "\techo a \\".to_string(),
+            "\techo b".to_string()
+        ]
+    );
+}
+
+#[test]
+fn convert_references_variations() {
+    let prerequisites = vec!["a.c".to_string(), "b.c".to_string()];
+
+    assert_eq!(
+        convert_references(
+            "$(CC) ${FLAGS} $X -o $@ $< $^ $$HOME $(MAKE) -C $(CURDIR)",
+            "app",
+            &prerequisites
+        ),
+        "${CC} ${FLAGS} ${X} -o app a.c a.c b.c $HOME make -C ${CARGO_MAKE_WORKING_DIRECTORY}"
+    );
+    assert_eq!(
+        convert_references("$(shell date)", "", &[]),
+        "$(shell date)"
+    );
+    assert_eq!(convert_references("cost $", "", &[]), "cost $");
+}
+
+#[test]
+fn import_variables() {
+    let output = import(
+        r#"
+CC = gcc
+FLAGS := -O2
+FLAGS += -Wall
+PREFIX ?= /usr/local
+DATE != date
+export TARGET = $(CC)
+export PREFIX
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        to_json(&output.env),
+        json!({
+            "CC": "gcc",
+            "FLAGS": "-O2 -Wall",
+            "PREFIX": { "value": "/usr/local", "condition": to_json(&TaskCondition {
+                env_not_set: Some(vec!["PREFIX".to_string()]),
+                ..Default::default()
+            }) },
+            "DATE": { "script": ["date"], "multi_line": null, "condition": null, "depends_on": null },
+            "TARGET": "${CC}"
+        })
+    );
+    assert!(output.tasks.is_empty());
+}
+
+#[test]
+fn import_rules() {
+    let output = import(
+        r#"
+.PHONY: all build test
+
+# Builds everything
+all: build test
+
+build: src/main.c ## Compiles the app
+	@echo building $@
+	-$(CC) -o $@ $<
+
+test: build ; ./test.sh
+
+test: MODE = debug
+
+%.o: %.c
+	$(CC) -c $<
+
+ifdef DEBUG
+endif
+
+define HELP
+text
+endef
+"#,
+    )
+    .unwrap();
+
+    let tasks = &output.tasks;
+    assert_eq!(
+        tasks.keys().collect::<Vec<&String>>(),
+        vec!["all", "build", "test"]
+    );
+
+    let all = to_json(&tasks["all"]);
+    assert_eq!(all["description"], json!("Builds everything"));
+    assert_eq!(all["dependencies"], json!(["build", "test"]));
+    assert!(tasks["all"].script.is_none());
+
+    let build = to_json(&tasks["build"]);
+    assert_eq!(build["description"], json!("Compiles the app"));
+    assert!(tasks["build"].dependencies.is_none());
+    assert_eq!(
+        build["script"],
+        json!(["echo building build", "${CC} -o build src/main.c || true"])
+    );
+
+    let test = to_json(&tasks["test"]);
+    assert!(tasks["test"].description.is_none());
+    assert_eq!(test["dependencies"], json!(["build"]));
+    assert_eq!(test["script"], json!(["./test.sh"]));
+    assert_eq!(test["env"], json!({ "MODE": "debug" }));
+}
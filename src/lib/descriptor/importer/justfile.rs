@@ -0,0 +1,449 @@
+//! # justfile
+//!
+//! Converts justfile recipes, aliases and variables.<br>
+//! Recipe parameters are converted to task env variables (with their default value) and
+//! {{name}} interpolations are converted to ${name} expansions.
+//!
+
+#[cfg(test)]
+#[path = "justfile_test.rs"]
+mod justfile_test;
+
+use super::{convert_command_prefix, create_task, get_description, is_name, Import};
+use crate::descriptor::config_cache;
+use crate::types::{EnvValue, EnvValueScript, TaskCondition};
+use indexmap::IndexMap;
+
+#[derive(Debug, Default)]
+struct Attributes {
+    private: bool,
+    doc: Option<String>,
+    platforms: Vec<String>,
+}
+
+/// Splits the text by the separator, ignoring separators inside quotes and parentheses.
+fn split_top_level(text: &str, is_separator: fn(char) -> bool) -> Vec<String> {
+    let mut parts = vec![];
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+
+    for value in text.chars() {
+        match quote {
+            Some(quote_char) => {
+                if value == quote_char {
+                    quote = None;
+                }
+                current.push(value);
+            }
+            None => {
+                if is_separator(value) && depth == 0 {
+                    if !current.trim().is_empty() {
+                        parts.push(current.trim().to_string());
+                    }
+                    current.clear();
+                    continue;
+                }
+
+                match value {
+                    '\'' | '"' | '`' => quote = Some(value),
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => (),
+                }
+                current.push(value);
+            }
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    parts
+}
+
+/// Returns the index of the first character outside quotes which matches the predicate.
+fn find_top_level(text: &str, predicate: impl Fn(&str) -> bool) -> Option<usize> {
+    let mut quote: Option<char> = None;
+
+    for (index, value) in text.char_indices() {
+        match quote {
+            Some(quote_char) if value == quote_char => quote = None,
+            Some(_) => (),
+            None if value == '\'' || value == '"' || value == '`' => quote = Some(value),
+            None if predicate(&text[index..]) => return Some(index),
+            None => (),
+        }
+    }
+
+    None
+}
+
+fn remove_comment(text: &str) -> &str {
+    match find_top_level(text, |rest| rest.starts_with('#')) {
+        Some(index) => text[..index].trim(),
+        None => text.trim(),
+    }
+}
+
+/// Returns the string literal value, or None if the value is an expression.
+fn parse_literal(value: &str) -> Option<String> {
+    let value = remove_comment(value);
+    let quote = value.chars().next()?;
+
+    if !['\'', '"'].contains(&quote) || value.len() < 2 || !value.ends_with(quote) {
+        return None;
+    }
+    let inner = &value[1..value.len() - 1];
+    // the closing quote must be the last character (for example 'a' + 'b' is an expression)
+    let unescaped = inner.replace("\\\\", "").replace("\\\"", "");
+    if (quote == '\'' && inner.contains(quote)) || (quote == '"' && unescaped.contains(quote)) {
+        return None;
+    }
+
+    if quote == '"' {
+        Some(
+            inner
+                .replace("\\n", "\n")
+                .replace("\\t", "\t")
+                .replace("\\\"", "\"")
+                .replace("\\\\", "\\"),
+        )
+    } else {
+        Some(inner.to_string())
+    }
+}
+
+/// Converts the {{name}} interpolations into ${name} expansions.
+fn convert_interpolations(text: &str, location: &str) -> String {
+    let mut output = String::new();
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find("{{") {
+        output.push_str(&remaining[..start]);
+        let placeholder = &remaining[start..];
+
+        if let Some(rest) = placeholder.strip_prefix("{{{{") {
+            // escaped braces
+            output.push_str("{{");
+            remaining = rest;
+            continue;
+        }
+
+        match placeholder.find("}}") {
+            Some(end) => {
+                let expression = placeholder[2..end].trim();
+
+                if is_name(expression) {
+                    output.push_str(&format!("${{{}}}", expression));
+                } else {
+                    config_cache::warn(&format!(
+                        "{}: the expression: {} is not supported and must be converted manually.",
+                        location, expression
+                    ));
+                    output.push_str(&placeholder[..end + 2]);
+                }
+
+                remaining = &placeholder[end + 2..];
+            }
+            None => {
+                output.push_str(placeholder);
+                remaining = "";
+            }
+        }
+    }
+    output.push_str(remaining);
+
+    output
+}
+
+/// Converts the variable value, only string literals, backtick commands and variable
+/// references are supported.
+fn convert_value(name: &str, value: &str) -> EnvValue {
+    let value = remove_comment(value);
+    let location = format!("Variable: {}", name);
+
+    if let Some(literal) = parse_literal(value) {
+        EnvValue::Value(convert_interpolations(&literal, &location))
+    } else if value.len() > 1
+        && value.starts_with('`')
+        && value.ends_with('`')
+        && !value[1..value.len() - 1].contains('`')
+    {
+        EnvValue::Script(EnvValueScript {
+            script: vec![convert_interpolations(
+                &value[1..value.len() - 1],
+                &location,
+            )],
+            multi_line: None,
+            condition: None,
+            depends_on: None,
+        })
+    } else if is_name(value) {
+        EnvValue::Value(format!("${{{}}}", value))
+    } else {
+        config_cache::warn(&format!(
+            "{}: the expression: {} is not supported and must be converted manually.",
+            location, value
+        ));
+        EnvValue::Value(value.to_string())
+    }
+}
+
+/// Returns the variable name and value of `name := value` lines.
+fn parse_assignment(line: &str) -> Option<(String, String)> {
+    let (name, value) = line.split_once(":=")?;
+    let name = name.trim();
+
+    if is_name(name) {
+        Some((name.to_string(), value.trim().to_string()))
+    } else {
+        None
+    }
+}
+
+fn parse_attributes(line: &str, attributes: &mut Attributes) {
+    let inner = &line[1..line.len() - 1];
+
+    for attribute in split_top_level(inner, |value| value == ',') {
+        match attribute.as_str() {
+            "private" => attributes.private = true,
+            "linux" => attributes.platforms.push("linux".to_string()),
+            "macos" => attributes.platforms.push("mac".to_string()),
+            "windows" => attributes.platforms.push("windows".to_string()),
+            "unix" => {
+                attributes.platforms.push("linux".to_string());
+                attributes.platforms.push("mac".to_string());
+            }
+            _ => match attribute
+                .strip_prefix("doc(")
+                .and_then(|doc| doc.strip_suffix(')'))
+            {
+                Some(doc) => attributes.doc = parse_literal(doc),
+                None => config_cache::warn(&format!(
+                    "Recipe attribute: {} is not supported.",
+                    attribute
+                )),
+            },
+        }
+    }
+}
+
+/// Returns the recipe body lines (without the common indentation) and the next line index.
+fn get_body<'a>(lines: &[&'a str], start: usize) -> (Vec<&'a str>, usize) {
+    let mut end = start;
+    while end < lines.len() && (lines[end].trim().is_empty() || lines[end].starts_with([' ', '\t']))
+    {
+        end += 1;
+    }
+
+    let mut body: Vec<&str> = lines[start..end].to_vec();
+    while body.last().is_some_and(|line| line.trim().is_empty()) {
+        body.pop();
+    }
+
+    let indentation = body
+        .first()
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .unwrap_or("");
+    let body = body
+        .into_iter()
+        .map(|line| line.strip_prefix(indentation).unwrap_or(line.trim_start()))
+        .collect();
+
+    (body, end)
+}
+
+fn add_parameters(name: &str, parameters: &[String], env: &mut IndexMap<String, EnvValue>) {
+    for parameter in parameters {
+        let (parameter, default_value) = match parameter.split_once('=') {
+            Some((parameter, default_value)) => (parameter, Some(default_value)),
+            None => (parameter.as_str(), None),
+        };
+        let variadic = parameter.starts_with('*');
+        let parameter = parameter.trim_start_matches(['$', '*', '+']);
+
+        match default_value {
+            Some(default_value) => {
+                env.insert(
+                    parameter.to_string(),
+                    convert_value(parameter, default_value),
+                );
+            }
+            None if variadic => {
+                env.insert(parameter.to_string(), EnvValue::Value("".to_string()));
+            }
+            None => config_cache::warn(&format!(
+                "Recipe: {} parameter: {} has no default value and must be provided as an env variable.",
+                name, parameter
+            )),
+        }
+    }
+}
+
+fn get_dependencies(name: &str, text: &str) -> Vec<String> {
+    let text = remove_comment(text);
+    let (dependencies, subsequent) = match text.split_once("&&") {
+        Some((dependencies, subsequent)) => (dependencies, subsequent.trim()),
+        None => (text, ""),
+    };
+    if !subsequent.is_empty() {
+        config_cache::warn(&format!(
+            "Recipe: {} subsequent dependencies: {} are not supported.",
+            name, subsequent
+        ));
+    }
+
+    split_top_level(dependencies, char::is_whitespace)
+        .into_iter()
+        .map(|dependency| match dependency.strip_prefix('(') {
+            Some(invocation) => {
+                config_cache::warn(&format!(
+                    "Recipe: {} dependency arguments: {} are not supported.",
+                    name, dependency
+                ));
+                invocation
+                    .trim_end_matches(')')
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or("")
+                    .to_string()
+            }
+            None => dependency,
+        })
+        .filter(|dependency| !dependency.is_empty())
+        .collect()
+}
+
+fn add_recipe(
+    header: &str,
+    body: Vec<&str>,
+    comments: &[String],
+    attributes: &Attributes,
+    output: &mut Import,
+) -> bool {
+    let header = header.trim_start_matches('@');
+    let separator = match find_top_level(header, |rest| {
+        rest.starts_with(':') && !rest.starts_with(":=")
+    }) {
+        Some(separator) => separator,
+        None => return false,
+    };
+
+    let mut signature = split_top_level(&header[..separator], char::is_whitespace);
+    if signature.is_empty() || !is_name(&signature[0]) {
+        return false;
+    }
+    let name = signature.remove(0);
+    let location = format!("Recipe: {}", name);
+
+    let script: Vec<String> = if body.first().is_some_and(|line| line.starts_with("#!")) {
+        body.iter()
+            .map(|line| convert_interpolations(line, &location))
+            .collect()
+    } else {
+        body.iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| convert_interpolations(&convert_command_prefix(line), &location))
+            .collect()
+    };
+
+    let description = attributes.doc.clone().or(get_description(comments));
+    let dependencies = get_dependencies(&name, &header[separator + 1..]);
+    let mut task = create_task(description, &dependencies, script);
+
+    if attributes.private || name.starts_with('_') {
+        task.private = Some(true);
+    }
+    if !attributes.platforms.is_empty() {
+        task.condition = Some(TaskCondition {
+            platforms: Some(attributes.platforms.clone()),
+            ..Default::default()
+        });
+    }
+
+    let mut env = IndexMap::new();
+    add_parameters(&name, &signature, &mut env);
+    if !env.is_empty() {
+        task.env = Some(env);
+    }
+
+    output.tasks.insert(name, task);
+
+    true
+}
+
+pub(super) fn import(content: &str) -> Result<Import, String> {
+    let mut output = Import::default();
+    let mut comments: Vec<String> = vec![];
+    let mut attributes = Attributes::default();
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim();
+        index += 1;
+
+        if trimmed.is_empty() {
+            comments.clear();
+            continue;
+        } else if trimmed.starts_with('#') {
+            comments.push(trimmed.to_string());
+            continue;
+        } else if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            parse_attributes(trimmed, &mut attributes);
+            continue;
+        }
+
+        let (keyword, rest) = trimmed
+            .split_once(char::is_whitespace)
+            .unwrap_or((trimmed, ""));
+        let rest = rest.trim();
+
+        match keyword {
+            "alias" => match parse_assignment(rest) {
+                Some((name, target)) => {
+                    let mut task = create_task(None, &[], vec![]);
+                    task.alias = Some(remove_comment(&target).to_string());
+                    output.tasks.insert(name, task);
+                }
+                None => config_cache::warn(&format!("Unable to convert alias: {}", trimmed)),
+            },
+            "export" if parse_assignment(rest).is_some() => {
+                if let Some((name, value)) = parse_assignment(rest) {
+                    let value = convert_value(&name, &value);
+                    output.env.insert(name, value);
+                }
+            }
+            "set" if rest.starts_with("export") => {
+                // all variables are converted to env values
+                debug!("Skipping setting: {}", trimmed);
+            }
+            "set" | "import" | "mod" | "unexport" => {
+                config_cache::warn(&format!(
+                    "The {} statement is not supported: {}",
+                    keyword, trimmed
+                ));
+            }
+            _ => {
+                if let Some((name, value)) = parse_assignment(trimmed) {
+                    let value = convert_value(&name, &value);
+                    output.env.insert(name, value);
+                } else {
+                    let (body, next_index) = get_body(&lines, index);
+                    index = next_index;
+
+                    if !add_recipe(trimmed, body, &comments, &attributes, &mut output) {
+                        config_cache::warn(&format!("Unable to convert line: {}", trimmed));
+                    }
+                }
+            }
+        }
+
+        comments.clear();
+        attributes = Attributes::default();
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,154 @@
+use super::*;
+use serde_json::json;
+
+fn import_tasks(content: &str) -> Import {
+    import(content).unwrap()
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn split_top_level_quotes_and_parentheses() {
+    assert_eq!(
+        split_top_level("build (lint \"a b\") 'c d'", char::is_whitespace),
+        vec![
+            "build".to_string(),
+            "(lint \"a b\")".to_string(),
+            "'c d'".to_string()
+        ]
+    );
+    assert_eq!(
+        split_top_level("private, doc('a, b')", |value| value == ','),
+        vec!["private".to_string(), "doc('a, b')".to_string()]
+    );
+}
+
+#[test]
+fn parse_literal_variations() {
+    assert_eq!(parse_literal("'single'"), Some("single".to_string()));
+    assert_eq!(
+        parse_literal("\"say \\\"hi\\\"\" # comment"),
+        Some("say \"hi\"".to_string())
+    );
+    assert_eq!(parse_literal("'a' + 'b'"), None);
+    assert_eq!(parse_literal("env_var('HOME')"), None);
+    assert_eq!(parse_literal("value"), None);
+}
+
+#[test]
+fn convert_interpolations_variations() {
+    assert_eq!(
+        convert_interpolations("cargo {{ mode }} --{{target}}", "test"),
+        "cargo ${mode} --${target}"
+    );
+    assert_eq!(
+        convert_interpolations("echo {{{{literal}}", "test"),
+        "echo {{literal}}"
+    );
+    assert_eq!(
+        convert_interpolations("echo {{ 'a' + name }}", "test"),
+        "echo {{ 'a' + name }}"
+    );
+}
+
+#[test]
+fn import_variables() {
+    let output = import_tasks(
+        r#"
+version := "1.0" # comment
+export TARGET := `uname -m`
+full := version
+set export
+"#,
+    );
+
+    assert_eq!(
+        to_json(&output.env),
+        json!({
+            "version": "1.0",
+            "TARGET": { "script": ["uname -m"], "multi_line": null, "condition": null, "depends_on": null },
+            "full": "${version}"
+        })
+    );
+    assert!(output.tasks.is_empty());
+}
+
+#[test]
+fn import_recipes() {
+    let output = import_tasks(
+        r#"
+alias b := build
+
+# Builds the project
+build mode="debug": fmt
+    @echo building in {{mode}}
+    -cargo build --{{mode}}
+
+[private]
+fmt:
+    cargo fmt
+
+[doc('Runs the tests')]
+[unix]
+test *args: build (lint "strict") && notify
+    cargo test {{args}}
+
+_hidden:
+
+script:
+  #!/usr/bin/env python3
+  if True:
+    print("{{mode}}")
+"#,
+    );
+
+    let tasks = &output.tasks;
+    assert_eq!(
+        tasks.keys().collect::<Vec<&String>>(),
+        vec!["b", "build", "fmt", "test", "_hidden", "script"]
+    );
+
+    assert_eq!(tasks["b"].alias, Some("build".to_string()));
+
+    let build = to_json(&tasks["build"]);
+    assert_eq!(build["description"], json!("Builds the project"));
+    assert_eq!(build["dependencies"], json!(["fmt"]));
+    assert_eq!(
+        build["script"],
+        json!(["echo building in ${mode}", "cargo build --${mode} || true"])
+    );
+    assert_eq!(build["env"], json!({ "mode": "debug" }));
+
+    assert_eq!(tasks["fmt"].private, Some(true));
+    assert!(tasks["fmt"].description.is_none());
+
+    let test = to_json(&tasks["test"]);
+    assert_eq!(test["description"], json!("Runs the tests"));
+    assert_eq!(test["dependencies"], json!(["build", "lint"]));
+    assert_eq!(test["condition"]["platforms"], json!(["linux", "mac"]));
+    assert_eq!(test["env"], json!({ "args": "" }));
+
+    assert_eq!(tasks["_hidden"].private, Some(true));
+    assert!(tasks["_hidden"].script.is_none());
+
+    assert_eq!(
+        to_json(&tasks["script"])["script"],
+        json!(["#!/usr/bin/env python3", "if True:", "  print(\"${mode}\")"])
+    );
+}
+
+#[test]
+fn import_comments_separated_by_blank_line() {
+    let output = import_tasks(
+        r#"
+# file header
+
+build:
+    cargo build
+"#,
+    );
+
+    assert!(output.tasks["build"].description.is_none());
+}
@@ -0,0 +1,181 @@
+//! # importer
+//!
+//! Converts the task definitions of other task runners (justfiles, GNU Makefiles and
+//! package.json scripts) into cargo-make tasks.<br>
+//! Only the common subset of each format is supported, any content which can't be
+//! converted is skipped with a warning.<br>
+//! Variables are converted to env values, as scripts can only reference env variables.
+//!
+
+#[cfg(test)]
+#[path = "mod_test.rs"]
+mod mod_test;
+
+mod gnu_make;
+mod justfile;
+mod npm;
+
+use crate::error::CargoMakeError;
+use crate::types::{DependencyIdentifier, EnvValue, ExternalConfig, ScriptValue, Task};
+use indexmap::IndexMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The supported import formats
+pub(crate) enum ImportFormat {
+    /// justfile recipes
+    Justfile,
+    /// GNU Makefile rules
+    GnuMake,
+    /// package.json scripts
+    Npm,
+}
+
+impl ImportFormat {
+    /// Returns the import format based on the file name, or None for cargo-make makefiles.
+    pub(crate) fn from_file(file: &str) -> Option<ImportFormat> {
+        let path = Path::new(file);
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .unwrap_or("");
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or("");
+
+        match (file_name, extension) {
+            ("justfile" | "Justfile" | ".justfile", _) | (_, "just") => {
+                Some(ImportFormat::Justfile)
+            }
+            ("GNUmakefile" | "makefile" | "Makefile", _) | (_, "mk") => Some(ImportFormat::GnuMake),
+            ("package.json", _) => Some(ImportFormat::Npm),
+            _ => None,
+        }
+    }
+
+    /// Returns the import format based on the file name and content.<br>
+    /// GNU Makefile names (such as Makefile) may also be used for cargo-make makefiles, so such
+    /// files which contain a valid TOML document are treated as cargo-make makefiles.
+    pub(crate) fn from_content(file: &str, content: &str) -> Option<ImportFormat> {
+        match ImportFormat::from_file(file) {
+            Some(ImportFormat::GnuMake) if !file.ends_with(".mk") && is_toml_makefile(content) => {
+                None
+            }
+            format => format,
+        }
+    }
+}
+
+/// Returns true if the content is a non empty TOML document.
+fn is_toml_makefile(content: &str) -> bool {
+    match toml::from_str::<toml::Table>(content) {
+        Ok(table) => !table.is_empty(),
+        Err(_) => false,
+    }
+}
+
+#[derive(Debug, Default)]
+/// Holds the converted definitions
+struct Import {
+    /// The variables (defined as env as scripts can only reference env variables)
+    env: IndexMap<String, EnvValue>,
+    /// The converted tasks
+    tasks: IndexMap<String, Task>,
+}
+
+impl Import {
+    fn into_config(self) -> ExternalConfig {
+        ExternalConfig {
+            env: if self.env.is_empty() {
+                None
+            } else {
+                Some(self.env)
+            },
+            tasks: Some(self.tasks),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns true if the value can be used as a variable or task name.
+fn is_name(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|value| value.is_ascii_alphanumeric() || value == '_' || value == '-')
+        }
+        _ => false,
+    }
+}
+
+/// Returns the text of the comment lines, joined as a single description.
+fn get_description(comments: &[String]) -> Option<String> {
+    let description = comments
+        .iter()
+        .map(|comment| comment.trim_start_matches('#').trim())
+        .filter(|comment| !comment.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    if description.is_empty() {
+        None
+    } else {
+        Some(description)
+    }
+}
+
+/// Removes the quiet (@), ignore errors (-) and always run (+) command prefixes.<br>
+/// Commands which ignore errors are converted to shell commands which never fail.
+fn convert_command_prefix(line: &str) -> String {
+    let command = line.trim_start_matches(['@', '-', '+']);
+    let prefix = &line[..line.len() - command.len()];
+
+    if prefix.contains('-') {
+        format!("{} || true", command.trim_start())
+    } else {
+        command.trim_start().to_string()
+    }
+}
+
+fn create_task(description: Option<String>, dependencies: &[String], script: Vec<String>) -> Task {
+    let mut task = Task::new();
+    task.description = description;
+
+    if !dependencies.is_empty() {
+        task.dependencies = Some(
+            dependencies
+                .iter()
+                .map(|name| DependencyIdentifier::Name(name.clone()))
+                .collect(),
+        );
+    }
+    if !script.is_empty() {
+        task.script = Some(ScriptValue::Text(script));
+    }
+
+    task
+}
+
+/// Converts the content of the provided file into a cargo-make config.
+pub(crate) fn import(content: &str, file: &str) -> Result<ExternalConfig, CargoMakeError> {
+    let output = match ImportFormat::from_content(file, content) {
+        Some(ImportFormat::Justfile) => justfile::import(content),
+        Some(ImportFormat::GnuMake) => gnu_make::import(content),
+        Some(ImportFormat::Npm) => npm::import(content),
+        None => Err("unsupported file, only justfiles, GNU Makefiles and package.json files can be imported".to_string()),
+    };
+
+    match output {
+        Ok(output) => {
+            debug!(
+                "Imported tasks: {:?} from file: {}",
+                output.tasks.keys().collect::<Vec<&String>>(),
+                file
+            );
+            Ok(output.into_config())
+        }
+        Err(error) => Err(CargoMakeError::ImportFailed(file.to_string(), error)),
+    }
+}
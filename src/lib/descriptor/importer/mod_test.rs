@@ -0,0 +1,149 @@
+use super::*;
+
+#[test]
+fn import_format_from_file() {
+    assert_eq!(
+        ImportFormat::from_file("justfile"),
+        Some(ImportFormat::Justfile)
+    );
+    assert_eq!(
+        ImportFormat::from_file("./dir/Justfile"),
+        Some(ImportFormat::Justfile)
+    );
+    assert_eq!(
+        ImportFormat::from_file(".justfile"),
+        Some(ImportFormat::Justfile)
+    );
+    assert_eq!(
+        ImportFormat::from_file("tasks.just"),
+        Some(ImportFormat::Justfile)
+    );
+    assert_eq!(
+        ImportFormat::from_file("Makefile"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_file("GNUmakefile"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_file("rules.mk"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_file("web/package.json"),
+        Some(ImportFormat::Npm)
+    );
+    assert_eq!(ImportFormat::from_file("Makefile.toml"), None);
+    assert_eq!(ImportFormat::from_file("package-lock.json"), None);
+}
+
+#[test]
+fn import_format_from_content() {
+    assert_eq!(
+        ImportFormat::from_content("Makefile", "build:\n\tcargo build\n"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_content("Makefile", "MODE = debug\n"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_content("Makefile", ""),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_content("./dir/Makefile", "[tasks.build]\ncommand = \"cargo\"\n"),
+        None
+    );
+    assert_eq!(
+        ImportFormat::from_content("GNUmakefile", "extend = \"common.toml\"\n"),
+        None
+    );
+    assert_eq!(
+        ImportFormat::from_content("rules.mk", "[tasks.build]\n"),
+        Some(ImportFormat::GnuMake)
+    );
+    assert_eq!(
+        ImportFormat::from_content("justfile", "[tasks.build]\n"),
+        Some(ImportFormat::Justfile)
+    );
+    assert_eq!(
+        ImportFormat::from_content("Makefile.toml", "build:\n"),
+        None
+    );
+}
+
+#[test]
+fn is_name_variations() {
+    assert!(is_name("build"));
+    assert!(is_name("_build-all_2"));
+    assert!(!is_name("2build"));
+    assert!(!is_name("build.o"));
+    assert!(!is_name(""));
+}
+
+#[test]
+fn get_description_variations() {
+    assert_eq!(
+        get_description(&[
+            "# Builds".to_string(),
+            "#".to_string(),
+            "## the app".to_string()
+        ]),
+        Some("Builds the app".to_string())
+    );
+    assert_eq!(get_description(&["#".to_string()]), None);
+    assert_eq!(get_description(&[]), None);
+}
+
+#[test]
+fn convert_command_prefix_variations() {
+    assert_eq!(convert_command_prefix("echo"), "echo");
+    assert_eq!(convert_command_prefix("@echo"), "echo");
+    assert_eq!(convert_command_prefix("+ echo"), "echo");
+    assert_eq!(convert_command_prefix("@-rm file"), "rm file || true");
+}
+
+#[test]
+fn create_task_empty() {
+    let task = create_task(None, &[], vec![]);
+
+    assert!(task.description.is_none());
+    assert!(task.dependencies.is_none());
+    assert!(task.script.is_none());
+}
+
+#[test]
+fn import_justfile() {
+    let config = import("VERSION := '1'\nbuild:\n    echo {{VERSION}}\n", "justfile").unwrap();
+
+    assert!(config.env.unwrap().contains_key("VERSION"));
+    let tasks = config.tasks.unwrap();
+    assert!(tasks.contains_key("build"));
+}
+
+#[test]
+fn import_empty_env() {
+    let config = import("build:\n\techo build\n", "Makefile").unwrap();
+
+    assert!(config.env.is_none());
+    assert!(config.tasks.unwrap().contains_key("build"));
+}
+
+#[test]
+fn import_unsupported_file() {
+    let output = import("", "Makefile.toml");
+
+    assert!(output.is_err());
+}
+
+#[test]
+fn import_invalid_content() {
+    let output = import("{", "package.json");
+
+    match output {
+        Err(CargoMakeError::ImportFailed(file, _)) => assert_eq!(file, "package.json"),
+        _ => panic!("invalid output"),
+    }
+}
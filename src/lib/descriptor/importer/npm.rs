@@ -0,0 +1,154 @@
+//! # npm
+//!
+//! Converts the package.json scripts.<br>
+//! Scripts are invoked via npm exec so local binaries (node_modules/.bin) are found, pre/post
+//! scripts and leading npm run invocations are converted to task dependencies and leading
+//! env assignments are converted to task env.
+//!
+
+#[cfg(test)]
+#[path = "npm_test.rs"]
+mod npm_test;
+
+use super::{create_task, Import};
+use crate::descriptor::config_cache;
+use crate::types::{EnvValue, Task};
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::Value;
+
+#[derive(Deserialize, Debug)]
+/// The package.json content used by the import (scripts are kept in their defined order)
+struct Package {
+    scripts: Option<IndexMap<String, Value>>,
+    #[serde(rename = "scripts-info")]
+    scripts_info: Option<IndexMap<String, Value>>,
+}
+
+struct ScriptParser {
+    run_regex: Regex,
+    env_regex: Regex,
+}
+
+impl ScriptParser {
+    fn new() -> ScriptParser {
+        ScriptParser {
+            run_regex: Regex::new(r"^npm\s+(?:run|run-script)\s+([^\s&|;<>]+)$").unwrap(),
+            env_regex: Regex::new(r#"^([A-Za-z_][A-Za-z0-9_]*)=("[^"]*"|'[^']*'|[^\s"']*)(\s+|$)"#)
+                .unwrap(),
+        }
+    }
+
+    /// Removes the leading env assignments from the command and returns them.
+    fn take_env(&self, command: &str) -> (String, IndexMap<String, EnvValue>) {
+        let mut env = IndexMap::new();
+        let mut command = command.strip_prefix("cross-env ").unwrap_or(command).trim();
+
+        while let Some(captures) = self.env_regex.captures(command) {
+            env.insert(
+                captures[1].to_string(),
+                EnvValue::Value(unquote(&captures[2])),
+            );
+            command = command[captures[0].len()..].trim_start();
+        }
+
+        (command.to_string(), env)
+    }
+
+    /// Returns the script task, leading npm run invocations are added to the dependencies.
+    fn create_task(&self, command: &str, dependencies: &mut Vec<String>) -> Task {
+        let mut parts = command.split("&&").map(str::trim).peekable();
+        while let Some(captures) = parts.peek().and_then(|part| self.run_regex.captures(part)) {
+            dependencies.push(captures[1].to_string());
+            parts.next();
+        }
+
+        let command = parts.collect::<Vec<&str>>().join(" && ");
+        let (command, env) = self.take_env(&command);
+        let mut task = create_task(None, &[], vec![]);
+
+        if !command.is_empty() {
+            task.command = Some("npm".to_string());
+            task.args = Some(vec!["exec".to_string(), "--call".to_string(), command]);
+        }
+        if !env.is_empty() {
+            task.env = Some(env);
+        }
+
+        task
+    }
+}
+
+/// Returns true for pre/post scripts of another script (for example pretest for test).
+fn is_hook(name: &str, scripts: &IndexMap<String, Value>) -> bool {
+    ["pre", "post"]
+        .iter()
+        .filter_map(|prefix| name.strip_prefix(prefix))
+        .any(|base| !base.is_empty() && scripts.contains_key(base))
+}
+
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if value.len() > 1 && value.starts_with(quote) && value.ends_with(quote) {
+            return value[1..value.len() - 1].to_string();
+        }
+    }
+
+    value.to_string()
+}
+
+pub(super) fn import(content: &str) -> Result<Import, String> {
+    let package: Package = serde_json::from_str(content).map_err(|error| error.to_string())?;
+    let scripts = match package.scripts {
+        Some(ref scripts) => scripts,
+        None => return Err("no scripts are defined".to_string()),
+    };
+    let descriptions = package.scripts_info.as_ref();
+    let parser = ScriptParser::new();
+
+    let mut output = Import::default();
+    for (name, value) in scripts.iter() {
+        let command = match value.as_str() {
+            Some(command) => command,
+            None => {
+                config_cache::warn(&format!("Script: {} is not a string.", name));
+                continue;
+            }
+        };
+
+        let hook = is_hook(name, scripts);
+        let pre_hook = format!("pre{}", name);
+        let post_hook = format!("post{}", name);
+        let main_task = format!("{}-main", name);
+
+        let mut dependencies = vec![];
+        if !hook && scripts.contains_key(&pre_hook) {
+            dependencies.push(pre_hook);
+        }
+        let script_task = parser.create_task(command, &mut dependencies);
+
+        let mut task =
+            if !hook && scripts.contains_key(&post_hook) && !scripts.contains_key(&main_task) {
+                // the script itself must run between its dependencies and the post script
+                let mut main = script_task;
+                main.private = Some(true);
+                output.tasks.insert(main_task.clone(), main);
+
+                dependencies.push(main_task);
+                dependencies.push(post_hook);
+                create_task(None, &dependencies, vec![])
+            } else {
+                let mut task = script_task;
+                task.dependencies = create_task(None, &dependencies, vec![]).dependencies;
+                task
+            };
+        task.description = descriptions
+            .and_then(|descriptions| descriptions.get(name))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        output.tasks.insert(name.clone(), task);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,122 @@
+use super::*;
+use serde_json::json;
+
+fn to_json<T: serde::Serialize>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
+
+#[test]
+fn is_hook_variations() {
+    let scripts: IndexMap<String, Value> = ["test", "pretest", "postinstall", "prepare"]
+        .iter()
+        .map(|name| (name.to_string(), json!("")))
+        .collect();
+
+    assert!(is_hook("pretest", &scripts));
+    assert!(!is_hook("postinstall", &scripts));
+    assert!(!is_hook("prepare", &scripts));
+    assert!(!is_hook("test", &scripts));
+}
+
+#[test]
+fn unquote_variations() {
+    assert_eq!(unquote("\"value\""), "value");
+    assert_eq!(unquote("'value'"), "value");
+    assert_eq!(unquote("value"), "value");
+    assert_eq!(unquote("\""), "\"");
+}
+
+#[test]
+fn take_env_variations() {
+    let parser = ScriptParser::new();
+
+    let (command, env) = parser.take_env("cross-env NODE_ENV=production DEBUG='a b' webpack");
+    assert_eq!(command, "webpack");
+    assert_eq!(
+        to_json(&env),
+        json!({ "NODE_ENV": "production", "DEBUG": "a b" })
+    );
+
+    let (command, env) = parser.take_env("jest --coverage");
+    assert_eq!(command, "jest --coverage");
+    assert!(env.is_empty());
+}
+
+#[test]
+fn import_scripts() {
+    let output = import(
+        r#"{
+  "name": "app",
+  "scripts": {
+    "lint": "eslint .",
+    "pretest": "npm run lint",
+    "test": "NODE_ENV=test jest",
+    "build": "npm run lint && npm run test && webpack",
+    "deploy": "./deploy.sh",
+    "postdeploy": "echo done",
+    "invalid": 1
+  },
+  "scripts-info": {
+    "build": "Builds the app"
+  }
+}"#,
+    )
+    .unwrap();
+
+    let tasks = &output.tasks;
+    assert_eq!(
+        tasks.keys().collect::<Vec<&String>>(),
+        vec![
+            "lint",
+            "pretest",
+            "test",
+            "build",
+            "deploy-main",
+            "deploy",
+            "postdeploy"
+        ]
+    );
+
+    let lint = to_json(&tasks["lint"]);
+    assert_eq!(lint["command"], json!("npm"));
+    assert_eq!(lint["args"], json!(["exec", "--call", "eslint ."]));
+
+    let pretest = to_json(&tasks["pretest"]);
+    assert_eq!(pretest["dependencies"], json!(["lint"]));
+    assert!(tasks["pretest"].command.is_none());
+
+    let test = to_json(&tasks["test"]);
+    assert_eq!(test["dependencies"], json!(["pretest"]));
+    assert_eq!(test["args"], json!(["exec", "--call", "jest"]));
+    assert_eq!(test["env"], json!({ "NODE_ENV": "test" }));
+
+    let build = to_json(&tasks["build"]);
+    assert_eq!(build["description"], json!("Builds the app"));
+    assert_eq!(build["dependencies"], json!(["lint", "test"]));
+    assert_eq!(build["args"], json!(["exec", "--call", "webpack"]));
+
+    assert_eq!(tasks["deploy-main"].private, Some(true));
+    assert_eq!(
+        to_json(&tasks["deploy-main"])["args"],
+        json!(["exec", "--call", "./deploy.sh"])
+    );
+    assert_eq!(
+        to_json(&tasks["deploy"])["dependencies"],
+        json!(["deploy-main", "postdeploy"])
+    );
+    assert!(tasks["deploy"].command.is_none());
+}
+
+#[test]
+fn import_no_scripts() {
+    let output = import(r#"{ "name": "app" }"#);
+
+    assert_eq!(output.unwrap_err(), "no scripts are defined");
+}
+
+#[test]
+fn import_invalid_json() {
+    let output = import("{");
+
+    assert!(output.is_err());
+}
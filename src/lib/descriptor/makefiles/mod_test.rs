@@ -26,6 +26,7 @@ fn create_flow_info(config: &Config) -> FlowInfo {
     FlowInfo {
         config: config.clone(),
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -38,6 +39,7 @@ fn create_flow_info(config: &Config) -> FlowInfo {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     }
 }
 
@@ -51,7 +53,9 @@ fn makefile_task_condition_test(name: &str, expect_enabled: bool, linux_only: bo
             config: task,
         };
 
-        let enabled = condition::validate_condition_for_step(&flow_info, &step).unwrap();
+        let enabled = condition::evaluate_condition_for_step(&flow_info, &step, true)
+            .unwrap()
+            .passed;
 
         let should_be_enabled = if expect_enabled {
             if ci_only {
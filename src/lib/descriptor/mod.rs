@@ -11,24 +11,47 @@
 mod mod_test;
 
 mod cargo_alias;
+mod config_cache;
 pub(crate) mod descriptor_deserializer;
-mod env;
-mod makefiles;
-
-use crate::descriptor::env::{merge_env, merge_env_files, merge_env_scripts};
+pub(crate) mod env;
+mod git_extend;
+pub(crate) mod importer;
+pub(crate) mod makefiles;
+mod templates;
+
+use crate::descriptor::descriptor_deserializer::DescriptorFormat;
+use crate::descriptor::env::{
+    create_env_origins, merge_env, merge_env_files, merge_env_origins, merge_env_scripts,
+};
+use crate::descriptor::importer::ImportFormat;
 use crate::environment;
+use crate::environment::crateinfo;
 use crate::error::CargoMakeError;
 use crate::plugin::descriptor::merge_plugins_config;
 use crate::types::{
-    Config, ConfigSection, EnvFile, EnvFileInfo, EnvValue, Extend, ExternalConfig, ModifyConfig,
-    Task,
+    Config, ConfigSection, EnvFile, EnvFileInfo, EnvOrigin, EnvValue, Extend, ExtendOptions,
+    ExternalConfig, ModifyConfig, Task,
 };
 use crate::{io, scriptengine, version};
 use fsio::path::as_path::AsPath;
 use fsio::path::from_path::FromPath;
 use indexmap::IndexMap;
+use std::cell::Cell;
 use std::path::{Path, PathBuf};
 
+/// The default makefile names, in the order they are searched for
+pub(crate) static DEFAULT_MAKEFILES: [&str; 4] = [
+    "Makefile.toml",
+    "Makefile.yaml",
+    "Makefile.yml",
+    "Makefile.json",
+];
+
+thread_local! {
+    /// True while the makefiles are loaded statically (see load_static)
+    static STATIC_LOAD: Cell<bool> = const { Cell::new(false) };
+}
+
 #[derive(Debug)]
 enum RelativeTo {
     Makefile,
@@ -150,7 +173,31 @@ fn add_file_location_info(
     external_config
 }
 
+/// Merges the env definitions.<br>
+/// When loading statically, env cycles are ignored and the extended definitions simply
+/// override the base ones.
+fn merge_descriptor_env(
+    base: &IndexMap<String, EnvValue>,
+    extended: &IndexMap<String, EnvValue>,
+) -> Result<IndexMap<String, EnvValue>, CargoMakeError> {
+    match merge_env(base, extended) {
+        Err(error) if STATIC_LOAD.with(|static_load| static_load.get()) => {
+            debug!("Ignoring env merge error: {}", error);
+
+            let mut all_env = base.clone();
+            all_env.extend(extended.clone());
+            Ok(all_env)
+        }
+        result => result,
+    }
+}
+
 fn run_load_script(external_config: &ExternalConfig) -> Result<bool, CargoMakeError> {
+    if STATIC_LOAD.with(|static_load| static_load.get()) {
+        debug!("Static load, skipping load script.");
+        return Ok(false);
+    }
+
     match external_config.config {
         Some(ref config) => {
             let load_script = config.get_load_script();
@@ -192,6 +239,7 @@ fn merge_external_configs(
     let all_env_files = merge_env_files(&mut parent_env_files, &mut extended_env_files);
 
     // merge env
+    let all_env_origins = merge_env_origins(parent_config.env_origins, config.env_origins);
     let mut parent_env = match parent_config.env {
         Some(env) => env,
         None => IndexMap::new(),
@@ -200,7 +248,13 @@ fn merge_external_configs(
         Some(env) => env,
         None => IndexMap::new(),
     };
-    let all_env = merge_env(&mut parent_env, &mut extended_env)?;
+    let all_env = merge_descriptor_env(&mut parent_env, &mut extended_env)?;
+
+    // merge vars
+    let all_vars = merge_descriptor_env(
+        &parent_config.vars.unwrap_or_default(),
+        &config.vars.unwrap_or_default(),
+    )?;
 
     // merge env scripts
     let mut parent_env_scripts = match parent_config.env_scripts {
@@ -213,6 +267,10 @@ fn merge_external_configs(
     };
     let all_env_scripts = merge_env_scripts(&mut parent_env_scripts, &mut extended_env_scripts);
 
+    // merge templates
+    let mut all_templates = parent_config.templates.unwrap_or_default();
+    all_templates.extend(config.templates.unwrap_or_default());
+
     // merge tasks
     let mut parent_tasks = match parent_config.tasks {
         Some(tasks) => tasks,
@@ -243,14 +301,168 @@ fn merge_external_configs(
         config: Some(config_section),
         env_files: Some(all_env_files),
         env: Some(all_env),
+        vars: Some(all_vars),
         env_scripts: Some(all_env_scripts),
+        templates: Some(all_templates),
         tasks: Some(all_tasks),
         plugins,
+        env_origins: Some(all_env_origins),
     };
 
     Ok(config)
 }
 
+/// Returns the name of the first default makefile found in the provided directory.<br>
+/// If none is found, the default TOML makefile name is returned.
+pub(crate) fn get_default_makefile(directory: &Path) -> String {
+    let name = DEFAULT_MAKEFILES
+        .iter()
+        .find(|name| directory.join(name).is_file())
+        .unwrap_or(&DEFAULT_MAKEFILES[0]);
+
+    name.to_string()
+}
+
+fn get_relative_to(extend_options: &ExtendOptions) -> RelativeTo {
+    let relative_to_str = extend_options
+        .relative
+        .clone()
+        .unwrap_or("makefile".to_string());
+    match relative_to_str.as_str() {
+        "git" => RelativeTo::GitRoot,
+        "crate" => RelativeTo::CrateRoot,
+        "workspace" => RelativeTo::WorkspaceRoot,
+        "makefile" => RelativeTo::Makefile,
+        _ => {
+            config_cache::warn(&format!(
+                "Unknown relative-to value: {}, defaulting to makefile",
+                &relative_to_str
+            ));
+            RelativeTo::Makefile
+        }
+    }
+}
+
+fn get_descriptor_directory(base_path: &str, relative_to: &RelativeTo) -> String {
+    match relative_to {
+        RelativeTo::Makefile => base_path.to_string(),
+        RelativeTo::GitRoot => {
+            let git_root = environment::find_git_root(&PathBuf::from(base_path));
+            debug!("git root: {:#?}", &git_root);
+            match git_root {
+                Some(git_root_dir) => git_root_dir.clone(),
+                None => base_path.to_string(),
+            }
+        }
+        RelativeTo::CrateRoot => {
+            let project_root = environment::get_project_root_for_path(&PathBuf::from(base_path));
+            debug!("project root: {:#?}", &project_root);
+            match project_root {
+                Some(crate_dir) => crate_dir.clone(),
+                None => base_path.to_string(),
+            }
+        }
+        RelativeTo::WorkspaceRoot => {
+            let base_path_buf = PathBuf::from(base_path);
+            let project_root = environment::get_project_root_for_path(&base_path_buf);
+            debug!("project root: {:#?}", &project_root);
+            match project_root {
+                Some(crate_dir) => {
+                    let crate_parent_path = PathBuf::from(&crate_dir).join("..");
+                    let workspace_root = environment::get_project_root_for_path(&crate_parent_path);
+                    debug!("workspace root: {:#?}", &workspace_root);
+                    match workspace_root {
+                        Some(workspace_dir) => workspace_dir.clone(),
+                        None => crate_dir.clone(),
+                    }
+                }
+                None => base_path.to_string(),
+            }
+        }
+    }
+}
+
+/// Returns the lock file path, located next to the makefile which declares the git extend
+/// (so all workspace members extending the workspace makefile share the same lock file).
+fn get_lock_file(parent_path: &str) -> PathBuf {
+    Path::new(parent_path).join(git_extend::LOCK_FILE)
+}
+
+/// Clones the extended git repository (if needed) and returns its checkout directory.
+fn get_git_repository_directory(
+    parent_path: &str,
+    url: &str,
+    rev: &Option<String>,
+) -> Result<PathBuf, CargoMakeError> {
+    let storage_directory = match git_extend::get_storage_directory() {
+        Some(directory) => directory,
+        None => {
+            return Err(CargoMakeError::GitExtendFailed(
+                url.to_string(),
+                "unable to find storage directory".to_string(),
+            ))
+        }
+    };
+
+    let lock_file = get_lock_file(parent_path);
+    config_cache::record_file(&lock_file);
+
+    git_extend::get_repository_directory(url, rev, &storage_directory, &lock_file)
+}
+
+/// Returns the source directory of extends pointing to a git repository or a crate
+/// dependency, or none for local extends (which are resolved via the relative option).
+fn get_extend_source_directory(
+    parent_path: &str,
+    extend_options: &ExtendOptions,
+) -> Result<Option<String>, CargoMakeError> {
+    let directory = match (&extend_options.git, &extend_options.crate_name) {
+        (Some(url), _) => get_git_repository_directory(parent_path, url, &extend_options.rev)?,
+        (None, Some(crate_name)) => {
+            // the resolved dependency version is defined by the lock file
+            let lock_file = Path::new(parent_path)
+                .ancestors()
+                .map(|directory| directory.join("Cargo.lock"))
+                .find(|lock_file| lock_file.exists())
+                .unwrap_or_else(|| Path::new(parent_path).join("Cargo.lock"));
+            config_cache::record_file(&lock_file);
+
+            crateinfo::get_dependency_directory(parent_path, crate_name)?
+        }
+        (None, None) => return Ok(None),
+    };
+
+    Ok(Some(FromPath::from_path(&directory)))
+}
+
+/// Returns the paths of all the makefiles directly extended by the provided extend
+/// definition, resolved from the parent makefile directory.
+pub(crate) fn get_extended_makefiles(parent_path: &str, extend_struct: &Extend) -> Vec<PathBuf> {
+    match extend_struct {
+        Extend::Path(base_file) => vec![Path::new(parent_path).join(base_file)],
+        Extend::Options(extend_options) => {
+            match get_extend_source_directory(parent_path, extend_options) {
+                Ok(Some(directory)) => vec![Path::new(&directory).join(&extend_options.path)],
+                Ok(None) => {
+                    let relative_to = get_relative_to(extend_options);
+                    let descriptor_dir = get_descriptor_directory(parent_path, &relative_to);
+                    vec![Path::new(&descriptor_dir).join(&extend_options.path)]
+                }
+                Err(error) => {
+                    debug!("Unable to resolve extended makefile, {}", error);
+                    vec![]
+                }
+            }
+        }
+        Extend::List(extend_list) => extend_list
+            .iter()
+            .flat_map(|extend_options| {
+                get_extended_makefiles(parent_path, &Extend::Options(extend_options.clone()))
+            })
+            .collect(),
+    }
+}
+
 fn load_descriptor_extended_makefiles(
     parent_path: &str,
     extend_struct: &Extend,
@@ -261,24 +473,43 @@ fn load_descriptor_extended_makefiles(
         }
         Extend::Options(extend_options) => {
             let force = !extend_options.optional.unwrap_or(false);
-            let relative_to_str = extend_options
-                .relative
-                .clone()
-                .unwrap_or("makefile".to_string());
-            let relative_to = match relative_to_str.as_str() {
-                "git" => RelativeTo::GitRoot,
-                "crate" => RelativeTo::CrateRoot,
-                "workspace" => RelativeTo::WorkspaceRoot,
-                "makefile" => RelativeTo::Makefile,
-                _ => {
-                    warn!(
-                        "Unknown relative-to value: {}, defaulting to makefile",
-                        &relative_to_str
-                    );
-                    RelativeTo::Makefile
+            let mut extended_config = match get_extend_source_directory(parent_path, extend_options)
+            {
+                Ok(Some(directory)) => load_external_descriptor(
+                    &directory,
+                    &extend_options.path,
+                    force,
+                    false,
+                    RelativeTo::Makefile,
+                ),
+                Ok(None) => {
+                    let relative_to = get_relative_to(extend_options);
+                    load_external_descriptor(
+                        parent_path,
+                        &extend_options.path,
+                        force,
+                        false,
+                        relative_to,
+                    )
                 }
+                Err(error) if !force => {
+                    debug!("Optional extended makefile not found, skipping. {}", error);
+
+                    Ok(ExternalConfig::new())
+                }
+                Err(error) => Err(error),
+            }?;
+
+            // the modifications apply to the extended makefile and all the makefiles it extends
+            let modify_config = ModifyConfig {
+                private: extend_options.private,
+                namespace: extend_options.namespace.clone(),
             };
-            load_external_descriptor(parent_path, &extend_options.path, force, false, relative_to)
+            if modify_config.is_modifications_defined() {
+                extended_config.apply(&modify_config);
+            }
+
+            Ok(extended_config)
         }
         Extend::List(extend_list) => {
             let mut ordered_list_config = ExternalConfig::new();
@@ -301,12 +532,15 @@ fn load_descriptor_extended_makefiles(
 
 /// Ensure the Makefile's min_version, if present, is older than cargo-make's
 /// currently running version.
-fn check_makefile_min_version(external_descriptor: &str) -> Result<(), CargoMakeError> {
-    let value: toml::Value = match toml::from_str(&external_descriptor) {
-        Ok(value) => value,
+fn check_makefile_min_version(
+    external_descriptor: &str,
+    format: DescriptorFormat,
+) -> Result<(), CargoMakeError> {
+    let value = match descriptor_deserializer::load_value(external_descriptor, format) {
+        Some(value) => value,
         // If there's an error parsing the file, let the caller function figure
         // it out
-        Err(_) => return Ok(()),
+        None => return Ok(()),
     };
 
     let min_version = value
@@ -335,43 +569,9 @@ fn load_external_descriptor(
         &file_name, &base_path, &relative_to,
     );
 
-    let descriptor_dir = match relative_to {
-        RelativeTo::Makefile => base_path.to_string(),
-        RelativeTo::GitRoot => {
-            let git_root = environment::find_git_root(&PathBuf::from(base_path));
-            debug!("git root: {:#?}", &git_root);
-            match git_root {
-                Some(git_root_dir) => git_root_dir.clone(),
-                None => base_path.to_string(),
-            }
-        }
-        RelativeTo::CrateRoot => {
-            let project_root = environment::get_project_root_for_path(&PathBuf::from(base_path));
-            debug!("project root: {:#?}", &project_root);
-            match project_root {
-                Some(crate_dir) => crate_dir.clone(),
-                None => base_path.to_string(),
-            }
-        }
-        RelativeTo::WorkspaceRoot => {
-            let base_path_buf = PathBuf::from(base_path);
-            let project_root = environment::get_project_root_for_path(&base_path_buf);
-            debug!("project root: {:#?}", &project_root);
-            match project_root {
-                Some(crate_dir) => {
-                    let crate_parent_path = PathBuf::from(&crate_dir).join("..");
-                    let workspace_root = environment::get_project_root_for_path(&crate_parent_path);
-                    debug!("workspace root: {:#?}", &workspace_root);
-                    match workspace_root {
-                        Some(workspace_dir) => workspace_dir.clone(),
-                        None => crate_dir.clone(),
-                    }
-                }
-                None => base_path.to_string(),
-            }
-        }
-    };
+    let descriptor_dir = get_descriptor_directory(base_path, &relative_to);
     let file_path = Path::new(&descriptor_dir).join(file_name);
+    config_cache::record_file(&file_path);
 
     if file_path.exists() && file_path.is_file() {
         let file_path_string: String = FromPath::from_path(&file_path);
@@ -383,15 +583,34 @@ fn load_external_descriptor(
 
         let external_descriptor = io::read_text_file(&file_path)?;
 
-        check_makefile_min_version(&external_descriptor)?;
-
-        let mut file_config =
-            descriptor_deserializer::load_external_config(&external_descriptor, &file_path_string)?;
+        let mut file_config = if ImportFormat::from_content(&file_path_string, &external_descriptor)
+            .is_some()
+        {
+            // tasks of other task runners are converted on the fly
+            importer::import(&external_descriptor, &file_path_string)?
+        } else {
+            check_makefile_min_version(
+                &external_descriptor,
+                DescriptorFormat::from_file(&file_path_string),
+            )?;
+
+            descriptor_deserializer::load_external_config(&external_descriptor, &file_path_string)?
+        };
         debug!("Loaded external config: {:#?}", &file_config);
 
         file_config = add_file_location_info(file_config, &absolute_file_path);
 
-        run_load_script(&file_config)?;
+        let layer = if set_env { "makefile" } else { "extend" };
+        file_config.env_origins = Some(create_env_origins(
+            &file_config.env.clone().unwrap_or_default(),
+            Some(&absolute_file_path),
+            layer,
+        ));
+
+        if run_load_script(&file_config)? {
+            // load scripts may modify anything so the config must be reloaded every time
+            config_cache::disable_recording();
+        }
 
         match file_config.extend {
             Some(ref extend_struct) => {
@@ -507,7 +726,7 @@ fn merge_base_config_and_external_config(
     let mut base_env = base_config.env;
 
     // merge env
-    let mut all_env = merge_env(&mut base_env, &mut external_env)?;
+    let mut all_env = merge_descriptor_env(&mut base_env, &mut external_env)?;
     all_env = match env_map {
         Some(values) => {
             let mut cli_env = IndexMap::new();
@@ -535,6 +754,10 @@ fn merge_base_config_and_external_config(
         None => all_env,
     };
 
+    // merge vars
+    let all_vars =
+        merge_descriptor_env(&base_config.vars, &external_config.vars.unwrap_or_default())?;
+
     let all_tasks = merge_tasks(&mut base_tasks, &mut external_tasks, late_merge);
 
     let mut config_section = base_config.config.clone();
@@ -546,6 +769,7 @@ fn merge_base_config_and_external_config(
         config: config_section,
         env_files,
         env: all_env,
+        vars: all_vars,
         env_scripts,
         tasks: all_tasks,
         plugins,
@@ -561,13 +785,29 @@ fn split_once(value: &str, delimiter: char) -> Option<(&str, &str)> {
     Some((part1, part2))
 }
 
+fn create_cli_env_origins(env_map: &Option<Vec<String>>) -> IndexMap<String, Vec<EnvOrigin>> {
+    let mut origins = IndexMap::new();
+
+    if let Some(env_pairs) = env_map {
+        for env_pair in env_pairs {
+            if let Some((key, value)) = env_pair.split_once('=') {
+                origins.insert(key.to_string(), EnvValue::Value(value.to_string()));
+            }
+        }
+    }
+
+    create_env_origins(&origins, None, "cli")
+}
+
 /// Loads the tasks descriptor.<br>
 /// It will first load the default descriptor which is defined in cargo-make
 /// internally and afterwards tries to find the external descriptor and load it
 /// as well.<br> If an external descriptor exists, it will be loaded and extend
 /// the default descriptor. If one of the descriptor requires a newer version of
 /// cargo-make, returns an error with the minimum version required by the
-/// descriptor.
+/// descriptor.<br>
+/// The origin (file, layer and profile) of every env definition is returned as well,
+/// ordered from the first definition to the last override.
 fn load_descriptors(
     file_name: &str,
     force: bool,
@@ -575,8 +815,9 @@ fn load_descriptors(
     stable: bool,
     experimental: bool,
     modify_core_tasks: Option<ModifyConfig>,
-) -> Result<Config, CargoMakeError> {
+) -> Result<(Config, IndexMap<String, Vec<EnvOrigin>>), CargoMakeError> {
     let default_config = load_internal_descriptors(stable, experimental, modify_core_tasks)?;
+    let internal_env_origins = create_env_origins(&default_config.env, None, "internal");
 
     let mut external_config =
         load_external_descriptor(".", file_name, force, true, RelativeTo::Makefile)?;
@@ -591,13 +832,29 @@ fn load_descriptors(
 
                         match pathbuf.to_str() {
                             Some(directory) => {
-                                let workspace_config = load_external_descriptor(
+                                let mut workspace_config = load_external_descriptor(
                                     directory,
                                     workspace_file_name_str,
                                     false,
                                     false,
                                     RelativeTo::Makefile,
                                 )?;
+                                workspace_config.env_origins =
+                                    workspace_config.env_origins.map(|env_origins| {
+                                        env_origins
+                                            .into_iter()
+                                            .map(|(key, origins)| {
+                                                let origins = origins
+                                                    .into_iter()
+                                                    .map(|origin| EnvOrigin {
+                                                        layer: "workspace".to_string(),
+                                                        ..origin
+                                                    })
+                                                    .collect();
+                                                (key, origins)
+                                            })
+                                            .collect()
+                                    });
                                 merge_external_configs(external_config, workspace_config)?
                             }
                             _ => external_config,
@@ -611,12 +868,20 @@ fn load_descriptors(
         _ => external_config,
     };
 
+    templates::instantiate(&mut external_config)?;
+
+    let mut env_origins = merge_env_origins(
+        Some(internal_env_origins),
+        external_config.env_origins.take(),
+    );
+    env_origins = merge_env_origins(Some(env_origins), Some(create_cli_env_origins(&env_map)));
+
     let config =
         merge_base_config_and_external_config(default_config, external_config, env_map, false)?;
 
     debug!("Loaded merged config: {:#?}", &config);
 
-    Ok(config)
+    Ok((config, env_origins))
 }
 
 fn load_cargo_aliases(config: &mut Config) -> Result<(), CargoMakeError> {
@@ -643,15 +908,105 @@ fn load_cargo_aliases(config: &mut Config) -> Result<(), CargoMakeError> {
 /// as well.<br> If an external descriptor exists, it will be loaded and extend
 /// the default descriptor. <br> If one of the descriptor requires a newer
 /// version of cargo-make, returns an error with the minimum version required by
-/// the descriptor.
+/// the descriptor.<br>
+/// The origin (file, layer and profile) of every env definition is returned as well,
+/// ordered from the first definition to the last override.<br>
+/// The merged config is cached between invocations as long as none of the contributing
+/// files changed (makefiles which define a load script are never cached), and the env vars
+/// set while loading are restored (and the warnings printed while loading are printed again)
+/// when the cached config is used.
 pub fn load(
     file_name: &str,
     force: bool,
     env_map: Option<Vec<String>>,
     experimental: bool,
+) -> Result<(Config, IndexMap<String, Vec<EnvOrigin>>), CargoMakeError> {
+    let cache_directory = if config_cache::is_enabled() {
+        config_cache::get_cache_directory()
+    } else {
+        None
+    };
+
+    load_with_cache(file_name, force, env_map, experimental, cache_directory)
+}
+
+/// Loads the tasks descriptor using the cache files in the provided cache directory (the cache
+/// is not used if no directory is provided).
+fn load_with_cache(
+    file_name: &str,
+    force: bool,
+    env_map: Option<Vec<String>>,
+    experimental: bool,
+    cache_directory: Option<PathBuf>,
+) -> Result<(Config, IndexMap<String, Vec<EnvOrigin>>), CargoMakeError> {
+    let cache_file = cache_directory
+        .and_then(|cache_directory| config_cache::get_cache_file(&cache_directory, file_name));
+    let cache_file = match cache_file {
+        Some(cache_file) => cache_file,
+        None => return load_config(file_name, force, env_map, experimental),
+    };
+
+    let key = config_cache::get_key(file_name, force, &env_map, experimental);
+    if let Some(entry) = config_cache::load(&cache_file, &key) {
+        environment::restore_env(&entry.env);
+        for warning in &entry.warnings {
+            warn!("{}", warning);
+        }
+
+        return Ok((entry.config, entry.env_origins));
+    }
+
+    config_cache::start_recording();
+    let mut result = None;
+    let previous_env = environment::get_modified_env(|| {
+        result = Some(load_config(file_name, force, env_map, experimental));
+    });
+    config_cache::record_env(
+        previous_env
+            .keys()
+            .map(|key| (key.to_string(), std::env::var(key).ok()))
+            .collect(),
+    );
+    let recording = config_cache::stop_recording();
+    let result = match result {
+        Some(result) => result,
+        None => panic!("config was not loaded"),
+    };
+
+    if let (Ok((config, env_origins)), Some(recording)) = (&result, recording) {
+        if recording.cacheable {
+            config_cache::store(&cache_file, &key, &recording, config, env_origins);
+        }
+    }
+
+    result
+}
+
+/// Loads the tasks descriptor statically, without invoking the makefiles load scripts and
+/// without failing on env cycles (the extended env definitions simply override the base
+/// ones).<br>
+/// Used to validate the makefiles structure, the config is never cached.
+pub(crate) fn load_static(
+    file_name: &str,
+    force: bool,
+    experimental: bool,
 ) -> Result<Config, CargoMakeError> {
+    STATIC_LOAD.with(|static_load| static_load.set(true));
+    let result = load_config(file_name, force, None, experimental);
+    STATIC_LOAD.with(|static_load| static_load.set(false));
+
+    result.map(|(config, _)| config)
+}
+
+fn load_config(
+    file_name: &str,
+    force: bool,
+    env_map: Option<Vec<String>>,
+    experimental: bool,
+) -> Result<(Config, IndexMap<String, Vec<EnvOrigin>>), CargoMakeError> {
     // load extended descriptor only
-    let mut config = load_descriptors(&file_name, force, env_map.clone(), false, false, None)?;
+    let (mut config, mut env_origins) =
+        load_descriptors(&file_name, force, env_map.clone(), false, false, None)?;
 
     // need to load core tasks as well
     if !config.config.skip_core_tasks.unwrap_or(false) {
@@ -661,7 +1016,7 @@ pub fn load(
             Some(modify_config) => {
                 if modify_config.is_modifications_defined() {
                     // reload everything with core modifications
-                    config = load_descriptors(
+                    (config, env_origins) = load_descriptors(
                         &file_name,
                         force,
                         env_map.clone(),
@@ -673,14 +1028,22 @@ pub fn load(
             }
             None => {
                 let core_config = load_internal_descriptors(true, experimental, modify_core_tasks)?;
+                env_origins = merge_env_origins(
+                    Some(create_env_origins(&core_config.env, None, "internal")),
+                    Some(env_origins),
+                );
+
                 let external_config = ExternalConfig {
                     extend: None,
                     config: Some(config.config),
                     env_files: Some(config.env_files),
                     env: Some(config.env),
+                    vars: Some(config.vars),
                     env_scripts: Some(config.env_scripts),
+                    templates: None,
                     tasks: Some(config.tasks),
                     plugins: config.plugins,
+                    env_origins: None,
                 };
 
                 config = merge_base_config_and_external_config(
@@ -696,12 +1059,19 @@ pub fn load(
     load_cargo_aliases(&mut config)?;
 
     if let Some(unstable_features) = &config.config.unstable_features {
+        let mut features_env = IndexMap::new();
         for feature in unstable_features {
             config
                 .env
                 .insert(feature.to_env_name(), EnvValue::Boolean(true));
+            features_env.insert(feature.to_env_name(), EnvValue::Boolean(true));
         }
+
+        env_origins = merge_env_origins(
+            Some(env_origins),
+            Some(create_env_origins(&features_env, None, "config")),
+        );
     }
 
-    Ok(config)
+    Ok((config, env_origins))
 }
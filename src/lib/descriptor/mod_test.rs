@@ -1,7 +1,8 @@
 use super::*;
 use crate::environment;
 use crate::environment::setup_cwd;
-use crate::types::{ExtendOptions, InstallCrate, ScriptValue};
+use crate::test::{create_test_crate, get_temp_test_directory};
+use crate::types::{ExtendOptions, InstallCrate, RunTaskInfo, ScriptValue};
 
 #[test]
 fn merge_tasks_both_empty() {
@@ -177,7 +178,7 @@ fn load_descriptors_load_workspace_makefile() {
         "CARGO_MAKE_WORKSPACE_MAKEFILE",
         "./examples/workspace/Makefile.toml",
     );
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let (config, _) = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
 
     let task = config.tasks.get("workspace-echo");
@@ -191,7 +192,7 @@ fn load_descriptors_load_workspace_makefile_no_exists() {
         "CARGO_MAKE_WORKSPACE_MAKEFILE",
         "./examples/workspace/Makefile2.toml",
     );
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let (config, _) = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
 
     let task = config.tasks.get("workspace-echo");
@@ -202,7 +203,7 @@ fn load_descriptors_load_workspace_makefile_no_exists() {
 #[ignore]
 fn load_descriptors_no_load_workspace_makefile() {
     envmnt::remove("CARGO_MAKE_WORKSPACE_MAKEFILE");
-    let config = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
+    let (config, _) = load_descriptors("./bad/bad.toml", false, None, false, false, None).unwrap();
 
     let task = config.tasks.get("workspace-echo");
     assert!(task.is_none());
@@ -214,7 +215,7 @@ fn load_env_and_plugins_extended_override() {
     let toml_file = "./src/lib/test/makefiles/env-extended.toml";
 
     envmnt::remove_all(&vec!["IF_UNDEFINED", "COMPOSITE_OF_MAPPED"]);
-    let config = load(toml_file, true, None, false).unwrap();
+    let (config, _) = load(toml_file, true, None, false).unwrap();
     environment::set_env_for_config(config.env, None, false);
 
     assert!(envmnt::is_equal("IF_UNDEFINED", "EXTENDED"));
@@ -249,7 +250,7 @@ fn load_env_override_and_plugins() {
     let toml_file = "./src/lib/test/makefiles/env.toml";
 
     envmnt::remove_all(&vec!["IF_UNDEFINED", "COMPOSITE_OF_MAPPED"]);
-    let mut config = load(toml_file, true, None, false).unwrap();
+    let (mut config, _) = load(toml_file, true, None, false).unwrap();
     let mut env = IndexMap::<String, EnvValue>::new();
     env.insert(
         "IF_UNDEFINED".to_string(),
@@ -271,7 +272,7 @@ fn load_env_override_and_plugins() {
         "VALUE: defined_in_makefile"
     ));
 
-    config = load(
+    (config, _) = load(
         toml_file,
         true,
         Some(vec!["IF_UNDEFINED=test".to_string()]),
@@ -317,7 +318,7 @@ fn load_env_override_and_plugins() {
 #[test]
 #[ignore]
 fn load_no_stable() {
-    let config = load("./examples/skip_core_tasks.toml", true, None, false).unwrap();
+    let (config, _) = load("./examples/skip_core_tasks.toml", true, None, false).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_none());
 
@@ -341,7 +342,7 @@ fn load_no_stable() {
 #[test]
 #[ignore]
 fn load_with_stable() {
-    let config = load("./examples/simple-example.toml", true, None, false).unwrap();
+    let (config, _) = load("./examples/simple-example.toml", true, None, false).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_some());
 
@@ -366,7 +367,7 @@ fn load_with_stable() {
 #[test]
 #[ignore]
 fn load_with_modify() {
-    let config = load("./examples/modify_core_tasks.toml", true, None, false).unwrap();
+    let (config, _) = load("./examples/modify_core_tasks.toml", true, None, false).unwrap();
 
     assert!(config.env.get(&"RUST_BACKTRACE".to_string()).is_some());
 
@@ -379,6 +380,92 @@ fn load_with_modify() {
     assert!(task.is_some());
 }
 
+fn get_load_env() -> Vec<Option<String>> {
+    [
+        "CARGO_MAKE_MAKEFILE_PATH",
+        "CARGO_MAKE_CORE_TASK_NAMESPACE",
+        "CARGO_MAKE_CORE_TASK_NAMESPACE_PREFIX",
+    ]
+    .iter()
+    .map(|key| std::env::var(key).ok())
+    .collect()
+}
+
+#[test]
+fn load_cached_env_restored() {
+    let directory = get_temp_test_directory("load_cached_env_restored");
+    let cache_directory = directory.join("cache");
+    let file = directory.join("Makefile.toml");
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(
+        &file,
+        "[config]\nmodify_core_tasks = { namespace = \"cached\" }\n",
+    );
+
+    let (first_config, _) =
+        load_with_cache(&file, true, None, false, Some(cache_directory.clone())).unwrap();
+    let first_env = get_load_env();
+    assert!(config_cache::get_cache_file(&cache_directory, &file)
+        .unwrap()
+        .exists());
+
+    envmnt::remove("CARGO_MAKE_MAKEFILE_PATH");
+    envmnt::remove("CARGO_MAKE_CORE_TASK_NAMESPACE");
+    envmnt::remove("CARGO_MAKE_CORE_TASK_NAMESPACE_PREFIX");
+
+    let (second_config, _) =
+        load_with_cache(&file, true, None, false, Some(cache_directory)).unwrap();
+
+    assert_eq!(get_load_env(), first_env);
+    assert_eq!(first_env[1], Some("cached".to_string()));
+    assert_eq!(
+        second_config.tasks.keys().collect::<Vec<_>>(),
+        first_config.tasks.keys().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn load_cached_warnings_stored() {
+    let directory = get_temp_test_directory("load_cached_warnings_stored");
+    let cache_directory = directory.join("cache");
+    let file = directory.join("Makefile.toml");
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(
+        &file,
+        "[config]\nskip_core_tasks = true\n\n[tasks.test]\nunknown_attribute = true\n",
+    );
+
+    load_with_cache(&file, true, None, false, Some(cache_directory.clone())).unwrap();
+
+    let cache_file = config_cache::get_cache_file(&cache_directory, &file).unwrap();
+    let key = config_cache::get_key(&file, true, &None, false);
+    let entry = config_cache::load(&cache_file, &key).unwrap();
+    assert_eq!(entry.warnings.len(), 1);
+    assert!(entry.warnings[0].contains("tasks.test.unknown_attribute"));
+
+    let (config, _) = load_with_cache(&file, true, None, false, Some(cache_directory)).unwrap();
+    assert!(config.tasks.contains_key("test"));
+}
+
+#[test]
+fn load_static_env_cycle() {
+    let directory = get_temp_test_directory("load_static_env_cycle");
+    let file = directory.join("Makefile.toml");
+    let file = file.to_str().unwrap().to_string();
+    io::write_text_file(
+        &file,
+        "[config]\nskip_core_tasks = true\n\n[env]\nA = \"${B}\"\nB = \"${A}\"\n\n[tasks.cycle]\n",
+    );
+
+    assert!(load(&file, true, None, false).is_err());
+
+    let config = load_static(&file, true, false).unwrap();
+
+    assert!(config.tasks.contains_key("cycle"));
+    assert!(config.env.contains_key("A"));
+    assert!(config.env.contains_key("B"));
+}
+
 #[test]
 #[should_panic]
 fn load_not_found() {
@@ -704,6 +791,52 @@ fn load_external_descriptor_broken_makefile_panic() {
     .unwrap();
 }
 
+#[test]
+fn load_external_descriptor_imported_file() {
+    let directory = get_temp_test_directory("load_external_descriptor_imported_file");
+    io::write_text_file(
+        &directory.join("justfile").to_string_lossy(),
+        "mode := 'release'\n\nbuild:\n    cargo build --{{mode}}\n",
+    );
+
+    let config = load_external_descriptor(
+        &directory.to_string_lossy(),
+        "justfile",
+        true,
+        false,
+        RelativeTo::Makefile,
+    )
+    .unwrap();
+
+    assert!(config.env.unwrap().contains_key("mode"));
+    let tasks = config.tasks.unwrap();
+    assert!(tasks.get("build").unwrap().script.is_some());
+}
+
+#[test]
+fn load_external_descriptor_toml_named_makefile() {
+    let directory = get_temp_test_directory("load_external_descriptor_toml_named_makefile");
+    io::write_text_file(
+        &directory.join("Makefile").to_string_lossy(),
+        "[tasks.build]\ncommand = \"cargo\"\nargs = [\"build\"]\n",
+    );
+
+    let config = load_external_descriptor(
+        &directory.to_string_lossy(),
+        "Makefile",
+        true,
+        false,
+        RelativeTo::Makefile,
+    )
+    .unwrap();
+
+    let tasks = config.tasks.unwrap();
+    assert_eq!(
+        tasks.get("build").unwrap().command,
+        Some("cargo".to_string())
+    );
+}
+
 #[test]
 fn run_load_script_no_config_section() {
     let external_config = ExternalConfig::new();
@@ -809,6 +942,11 @@ fn load_descriptor_extended_makefiles_options_exists() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: None,
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -817,6 +955,75 @@ fn load_descriptor_extended_makefiles_options_exists() {
     assert!(tasks.contains_key("test1"));
 }
 
+#[test]
+fn load_descriptor_extended_makefiles_options_namespace() {
+    let directory = get_temp_test_directory("load_descriptor_extended_makefiles_namespace");
+    io::write_text_file(
+        &directory.join("shared.toml").to_string_lossy(),
+        r#"
+extend = "base.toml"
+
+[config]
+end_task = "cleanup"
+
+[tasks.build]
+dependencies = ["format"]
+
+[tasks.cleanup]
+"#,
+    );
+    io::write_text_file(
+        &directory.join("base.toml").to_string_lossy(),
+        "[tasks.format]\n",
+    );
+
+    let descriptor = load_descriptor_extended_makefiles(
+        &directory.to_string_lossy(),
+        &Extend::List(vec![
+            ExtendOptions {
+                path: "shared.toml".to_string(),
+                optional: None,
+                relative: None,
+                git: None,
+                rev: None,
+                crate_name: None,
+                namespace: Some("shared".to_string()),
+                private: Some(true),
+            },
+            ExtendOptions {
+                path: "base.toml".to_string(),
+                optional: None,
+                relative: None,
+                git: None,
+                rev: None,
+                crate_name: None,
+                namespace: None,
+                private: None,
+            },
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        descriptor.config.unwrap().end_task,
+        Some("shared::cleanup".to_string())
+    );
+    let tasks = descriptor.tasks.unwrap();
+    assert_eq!(
+        tasks.keys().collect::<Vec<&String>>(),
+        vec![
+            "shared::format",
+            "shared::build",
+            "shared::cleanup",
+            "format"
+        ]
+    );
+    let task = tasks.get("shared::build").unwrap();
+    assert_eq!(task.dependencies, Some(vec!["shared::format".into()]));
+    assert!(task.private.unwrap());
+    assert!(tasks.get("format").unwrap().private.is_none());
+}
+
 #[test]
 #[ignore]
 #[should_panic]
@@ -828,6 +1035,11 @@ fn load_descriptor_extended_makefiles_options_not_exists() {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: None,
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -843,6 +1055,11 @@ fn load_descriptor_extended_makefiles_options_exists_optional() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(true),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -861,6 +1078,11 @@ fn load_descriptor_extended_makefiles_options_exists_not_optional() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -880,6 +1102,11 @@ fn load_descriptor_extended_makefiles_options_not_exists_optional() {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(true),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -899,6 +1126,11 @@ fn load_descriptor_extended_makefiles_options_not_exists_not_optional() {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         }),
     )
     .unwrap();
@@ -913,11 +1145,21 @@ fn load_descriptor_extended_makefiles_list_exists() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/test2.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -937,11 +1179,21 @@ fn load_descriptor_extended_makefiles_list_not_exists() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
     ];
     load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -956,11 +1208,21 @@ fn load_descriptor_extended_makefiles_list_exists_optional() {
             path: "src/lib/test/makefiles/test1.toml".to_string(),
             optional: Some(false),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
         ExtendOptions {
             path: "src/lib/test/makefiles/bad.toml".to_string(),
             optional: Some(true),
             relative: None,
+            git: None,
+            rev: None,
+            crate_name: None,
+            namespace: None,
+            private: None,
         },
     ];
     let descriptor = load_descriptor_extended_makefiles(&parent_path, &Extend::List(list)).unwrap();
@@ -973,7 +1235,7 @@ fn load_descriptor_extended_makefiles_list_exists_optional() {
 #[test]
 fn check_makefile_min_version_empty() {
     let toml_string = "";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -981,7 +1243,7 @@ fn check_makefile_min_version_empty() {
 #[test]
 fn check_makefile_min_version_invalid_format() {
     let toml_string = "123";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -989,7 +1251,7 @@ fn check_makefile_min_version_invalid_format() {
 #[test]
 fn check_makefile_min_version_no_config() {
     let toml_string = "test = true";
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -1000,7 +1262,7 @@ fn check_makefile_min_version_no_min_version() {
     [config]
     test = true
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -1011,7 +1273,7 @@ fn check_makefile_min_version_smaller_min_version() {
     [config]
     min_version = "0.0.1"
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -1022,7 +1284,7 @@ fn check_makefile_min_version_bigger_min_version() {
     [config]
     min_version = "999.999.999"
     "#;
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_err());
     assert_eq!(
@@ -1040,7 +1302,7 @@ fn check_makefile_min_version_same_min_version() {
     buffer.push_str(env!("CARGO_PKG_VERSION"));
     buffer.push_str("\"");
     toml_string = buffer.as_str();
-    let result = check_makefile_min_version(toml_string);
+    let result = check_makefile_min_version(toml_string, DescriptorFormat::Toml);
 
     assert!(result.is_ok());
 }
@@ -1067,3 +1329,228 @@ fn load_cargo_aliases_found() {
 
     assert_eq!(count, config.tasks.len());
 }
+
+#[test]
+fn load_env_origins_extended_and_cli() {
+    let toml_file = "./src/lib/test/makefiles/env-extended.toml";
+
+    let (config, env_origins) = load(
+        toml_file,
+        true,
+        Some(vec!["IF_UNDEFINED=CLI".to_string()]),
+        false,
+    )
+    .unwrap();
+
+    match config.env.get("IF_UNDEFINED").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "CLI"),
+        _ => panic!("invalid env value type"),
+    };
+
+    let origins = env_origins.get("IF_UNDEFINED").unwrap();
+    assert_eq!(origins.len(), 3);
+    assert_eq!(origins[0].layer, "extend");
+    assert!(origins[0].file.clone().unwrap().ends_with("env.toml"));
+    assert!(!origins[0]
+        .file
+        .clone()
+        .unwrap()
+        .ends_with("env-extended.toml"));
+    assert_eq!(origins[1].layer, "makefile");
+    assert!(origins[1]
+        .file
+        .clone()
+        .unwrap()
+        .ends_with("env-extended.toml"));
+    match origins[1].value {
+        EnvValue::Value(ref value) => assert_eq!(value, "EXTENDED"),
+        _ => panic!("invalid env value type"),
+    };
+    assert_eq!(origins[2].layer, "cli");
+    assert!(origins[2].file.is_none());
+    match origins[2].value {
+        EnvValue::Value(ref value) => assert_eq!(value, "CLI"),
+        _ => panic!("invalid env value type"),
+    };
+
+    let origins = env_origins.get("RUST_BACKTRACE");
+    assert!(origins.is_some());
+    assert_eq!(origins.unwrap()[0].layer, "internal");
+}
+
+#[test]
+fn load_vars_extended() {
+    let toml_file = "./src/lib/test/makefiles/vars-extended.toml";
+
+    let (config, _) = load(toml_file, true, None, false).unwrap();
+
+    match config.vars.get("BASE_VAR").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "base"),
+        _ => panic!("invalid var value type"),
+    };
+    match config.vars.get("OVERRIDDEN_VAR").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "extended"),
+        _ => panic!("invalid var value type"),
+    };
+    assert!(config.vars.contains_key("COMPOSITE_VAR"));
+    assert!(!config.env.contains_key("BASE_VAR"));
+
+    let task_vars = config.tasks.get("vars").unwrap().vars.clone().unwrap();
+    match task_vars.get("TASK_VAR").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "task"),
+        _ => panic!("invalid var value type"),
+    };
+}
+
+#[test]
+fn load_mixed_formats_extended() {
+    let yaml_file = "./src/lib/test/makefiles/formats.yaml";
+
+    let (config, _) = load(yaml_file, true, None, false).unwrap();
+
+    for (key, expected) in [
+        ("YAML_ENV", "yaml"),
+        ("JSON_ENV", "json"),
+        ("TOML_ENV", "toml"),
+        ("OVERRIDDEN_ENV", "yaml"),
+    ] {
+        match config.env.get(key).unwrap() {
+            EnvValue::Value(value) => assert_eq!(value, expected),
+            _ => panic!("invalid env value type"),
+        };
+    }
+
+    assert!(config.tasks.contains_key("yaml-task"));
+    assert!(config.tasks.contains_key("json-task"));
+    assert!(config.tasks.contains_key("toml-task"));
+
+    let task = config.tasks.get("yaml-task").unwrap();
+    assert_eq!(task.command.clone().unwrap(), "echo");
+    assert_eq!(task.dependencies.clone().unwrap().len(), 2);
+
+    match config.tasks.get("routing").unwrap().run_task {
+        Some(RunTaskInfo::Routing(ref routing_info)) => {
+            assert_eq!(routing_info.len(), 2);
+            let platforms = routing_info[0]
+                .condition
+                .clone()
+                .unwrap()
+                .platforms
+                .unwrap();
+            assert_eq!(platforms, vec!["linux".to_string(), "mac".to_string()]);
+        }
+        _ => panic!("invalid run task type"),
+    };
+}
+
+#[test]
+fn check_makefile_min_version_yaml_and_json() {
+    let result =
+        check_makefile_min_version("config:\n  min_version: 999.0.0\n", DescriptorFormat::Yaml);
+    assert!(result.is_err());
+
+    let result = check_makefile_min_version(
+        r#"{ "config": { "min_version": "999.0.0" } }"#,
+        DescriptorFormat::Json,
+    );
+    assert!(result.is_err());
+
+    let result = check_makefile_min_version(
+        r#"{ "config": { "min_version": "0.0.1" } }"#,
+        DescriptorFormat::Json,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn get_default_makefile_search_order() {
+    let directory = crate::test::get_temp_test_directory("descriptor_get_default_makefile");
+
+    assert_eq!(get_default_makefile(&directory), "Makefile.toml");
+
+    fsio::file::write_text_file(&directory.join("Makefile.json"), "{}").unwrap();
+    assert_eq!(get_default_makefile(&directory), "Makefile.json");
+
+    fsio::file::write_text_file(&directory.join("Makefile.yml"), "").unwrap();
+    assert_eq!(get_default_makefile(&directory), "Makefile.yml");
+
+    fsio::file::write_text_file(&directory.join("Makefile.yaml"), "").unwrap();
+    assert_eq!(get_default_makefile(&directory), "Makefile.yaml");
+
+    fsio::file::write_text_file(&directory.join("Makefile.toml"), "").unwrap();
+    assert_eq!(get_default_makefile(&directory), "Makefile.toml");
+}
+
+#[test]
+fn load_descriptor_extended_makefiles_crate_dependency() {
+    let directory = get_temp_test_directory("descriptor_extended_makefiles_crate_dependency");
+    let tasks_directory = directory.join("tasks");
+    create_test_crate(&tasks_directory, "extend-tasks", "");
+    fsio::file::write_text_file(
+        &tasks_directory.join("ci/Makefile.toml"),
+        "[tasks.shared]\nscript = \"echo shared\"\n",
+    )
+    .unwrap();
+    create_test_crate(
+        &directory,
+        "extend-host",
+        "extend-tasks = { path = \"tasks\" }\n",
+    );
+
+    let descriptor = load_descriptor_extended_makefiles(
+        directory.to_str().unwrap(),
+        &Extend::Options(ExtendOptions {
+            path: "ci/Makefile.toml".to_string(),
+            optional: None,
+            relative: None,
+            git: None,
+            rev: None,
+            crate_name: Some("extend-tasks".to_string()),
+            namespace: None,
+            private: None,
+        }),
+    )
+    .unwrap();
+
+    let tasks = descriptor.tasks.unwrap();
+    assert!(tasks.contains_key("shared"));
+
+    let descriptor = load_descriptor_extended_makefiles(
+        directory.to_str().unwrap(),
+        &Extend::Options(ExtendOptions {
+            path: "ci/Makefile.toml".to_string(),
+            optional: Some(true),
+            relative: None,
+            git: None,
+            rev: None,
+            crate_name: Some("missing-crate".to_string()),
+            namespace: None,
+            private: None,
+        }),
+    )
+    .unwrap();
+
+    assert!(descriptor.tasks.is_none());
+}
+
+#[test]
+fn load_templates_extended() {
+    let (config, _) = load("./src/lib/test/makefiles/templates.toml", true, None, false).unwrap();
+
+    for (name, member) in [("lint-api", "api"), ("lint-web", "web")] {
+        let task = config.tasks.get(name).unwrap();
+        assert!(task.template.is_none());
+        assert_eq!(task.command.clone().unwrap(), "cargo");
+        assert_eq!(
+            task.args.clone().unwrap(),
+            vec!["clippy".to_string(), "-p".to_string(), member.to_string()]
+        );
+    }
+}
+
+#[test]
+fn get_lock_file_next_to_declaring_makefile() {
+    let lock_file = get_lock_file("./examples");
+
+    assert_eq!(lock_file, Path::new("./examples").join("Makefile.lock"));
+}
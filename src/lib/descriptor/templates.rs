@@ -0,0 +1,183 @@
+//! # templates
+//!
+//! Instantiates tasks from the makefile task templates.<br>
+//! Template parameters are defined as {{name}} placeholders which are substituted with
+//! the task with values, after which the task itself is merged on top of the template.
+//!
+
+#[cfg(test)]
+#[path = "templates_test.rs"]
+mod templates_test;
+
+use crate::error::CargoMakeError;
+use crate::types::{EnvValue, ExternalConfig, Task};
+use indexmap::IndexMap;
+use serde_yaml_ng::Value;
+
+fn is_parameter_name(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|value| value.is_ascii_alphanumeric() || value == '_' || value == '-')
+        }
+        _ => false,
+    }
+}
+
+/// Replaces all {{name}} placeholders with the parameter values.<br>
+/// Returns the name of the first placeholder without a value in case of an error.
+fn substitute(value: &str, parameters: &IndexMap<String, String>) -> Result<String, String> {
+    let mut output = String::new();
+    let mut remaining = value;
+
+    while let Some(mut start) = remaining.find("{{") {
+        // the placeholder starts at the last brace (for example ${{{name}}})
+        while remaining[start + 2..].starts_with('{') {
+            start += 1;
+        }
+
+        let (prefix, placeholder) = remaining.split_at(start);
+        output.push_str(prefix);
+
+        match placeholder.find("}}") {
+            Some(end) => {
+                let name = placeholder[2..end].trim();
+
+                if is_parameter_name(name) {
+                    match parameters.get(name) {
+                        Some(parameter_value) => output.push_str(parameter_value),
+                        None => return Err(name.to_string()),
+                    }
+                } else {
+                    // not a template placeholder (for example {{.Names}})
+                    output.push_str(&placeholder[..end + 2]);
+                }
+
+                remaining = &placeholder[end + 2..];
+            }
+            None => {
+                output.push_str(placeholder);
+                remaining = "";
+            }
+        }
+    }
+    output.push_str(remaining);
+
+    Ok(output)
+}
+
+/// Substitutes all string values and keys, the yaml value is used (and not json) as it
+/// preserves the keys order which is significant for env blocks.
+fn substitute_value(
+    value: &mut Value,
+    parameters: &IndexMap<String, String>,
+) -> Result<(), String> {
+    match value {
+        Value::String(string_value) => {
+            *string_value = substitute(string_value, parameters)?;
+        }
+        Value::Sequence(sequence) => {
+            for item in sequence.iter_mut() {
+                substitute_value(item, parameters)?;
+            }
+        }
+        Value::Mapping(mapping) => {
+            let entries = std::mem::take(mapping);
+
+            for (mut key, mut item) in entries {
+                substitute_value(&mut key, parameters)?;
+                substitute_value(&mut item, parameters)?;
+                mapping.insert(key, item);
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+fn merge_values(
+    template_values: Option<IndexMap<String, EnvValue>>,
+    task_values: &Option<IndexMap<String, EnvValue>>,
+) -> Option<IndexMap<String, EnvValue>> {
+    match (template_values, task_values) {
+        (Some(mut values), Some(task_values)) => {
+            values.extend(task_values.clone());
+            Some(values)
+        }
+        (values, None) => values,
+        (None, task_values) => task_values.clone(),
+    }
+}
+
+fn instantiate_task(
+    name: &str,
+    task: &Task,
+    templates: &IndexMap<String, Task>,
+) -> Result<Task, CargoMakeError> {
+    let template_name = task.template.clone().unwrap_or_default();
+    let create_error = |message: String| CargoMakeError::TemplateFailed(name.to_string(), message);
+
+    let template = match templates.get(&template_name) {
+        Some(template) => template,
+        None => {
+            return Err(create_error(format!(
+                "template: {} is not defined",
+                &template_name
+            )))
+        }
+    };
+    if template.template.is_some() {
+        return Err(create_error(format!(
+            "template: {} cannot be based on another template",
+            &template_name
+        )));
+    }
+
+    let parameters = task.with.clone().unwrap_or_default();
+    let mut value = match serde_yaml_ng::to_value(template) {
+        Ok(value) => value,
+        Err(error) => return Err(create_error(error.to_string())),
+    };
+    if let Err(parameter) = substitute_value(&mut value, &parameters) {
+        return Err(create_error(format!(
+            "template: {} parameter: {} is not provided",
+            &template_name, parameter
+        )));
+    }
+
+    let mut instance: Task = match serde_yaml_ng::from_value(value) {
+        Ok(instance) => instance,
+        Err(error) => return Err(create_error(error.to_string())),
+    };
+    let template_env = instance.env.take();
+    let template_vars = instance.vars.take();
+    instance.extend(task);
+    // env and vars are merged (and not replaced) so tasks can add their own values
+    instance.env = merge_values(template_env, &task.env);
+    instance.vars = merge_values(template_vars, &task.vars);
+    instance.template = None;
+    instance.with = None;
+
+    Ok(instance)
+}
+
+/// Replaces all tasks which are based on a template with the template instance.
+pub(crate) fn instantiate(external_config: &mut ExternalConfig) -> Result<(), CargoMakeError> {
+    let templates = external_config.templates.take().unwrap_or_default();
+
+    if let Some(ref mut tasks) = external_config.tasks {
+        for (name, task) in tasks.iter_mut() {
+            if task.template.is_some() {
+                debug!(
+                    "Instantiating task: {} from template: {:?}",
+                    name, &task.template
+                );
+                *task = instantiate_task(name, task, &templates)?;
+            }
+        }
+    }
+
+    Ok(())
+}
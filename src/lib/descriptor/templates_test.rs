@@ -0,0 +1,175 @@
+use super::*;
+use crate::descriptor::descriptor_deserializer;
+use crate::types::EnvValue;
+
+fn load(content: &str) -> ExternalConfig {
+    descriptor_deserializer::load_external_config(content, "Makefile.toml").unwrap()
+}
+
+fn create_parameters(values: &[(&str, &str)]) -> IndexMap<String, String> {
+    values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn is_parameter_name_variations() {
+    assert!(is_parameter_name("member"));
+    assert!(is_parameter_name("_member"));
+    assert!(is_parameter_name("member-name_2"));
+    assert!(!is_parameter_name(""));
+    assert!(!is_parameter_name("2member"));
+    assert!(!is_parameter_name(".Names"));
+    assert!(!is_parameter_name("member name"));
+}
+
+#[test]
+fn substitute_parameters() {
+    let parameters = create_parameters(&[("member", "api"), ("mode", "release")]);
+
+    assert_eq!(
+        substitute("clippy -p {{member}} --{{ mode }}", &parameters).unwrap(),
+        "clippy -p api --release"
+    );
+    assert_eq!(
+        substitute("no placeholders", &parameters).unwrap(),
+        "no placeholders"
+    );
+    assert_eq!(
+        substitute("docker ps --format '{{.Names}}' {{", &parameters).unwrap(),
+        "docker ps --format '{{.Names}}' {{"
+    );
+    assert_eq!(
+        substitute("{{member}}-{{missing}}", &parameters),
+        Err("missing".to_string())
+    );
+}
+
+#[test]
+fn instantiate_tasks() {
+    let mut external_config = load(
+        r#"
+[templates.clippy-member]
+description = "Lint the {{member}} member"
+command = "cargo"
+args = ["clippy", "-p", "{{member}}"]
+env = { "{{member}}_LINT" = "true", LEVEL = "${{{member}}_LINT}" }
+
+[tasks.lint-api]
+template = "clippy-member"
+with = { member = "api" }
+
+[tasks.lint-web]
+template = "clippy-member"
+with = { member = "web" }
+args = ["clippy", "-p", "web", "--all-targets"]
+env = { WEB = "true" }
+
+[tasks.other]
+command = "echo"
+"#,
+    );
+
+    instantiate(&mut external_config).unwrap();
+
+    assert!(external_config.templates.is_none());
+    let tasks = external_config.tasks.unwrap();
+
+    let lint_api = tasks.get("lint-api").unwrap();
+    assert!(lint_api.template.is_none());
+    assert!(lint_api.with.is_none());
+    assert_eq!(lint_api.command.clone().unwrap(), "cargo");
+    assert_eq!(lint_api.description.clone().unwrap(), "Lint the api member");
+    assert_eq!(
+        lint_api.args.clone().unwrap(),
+        vec!["clippy".to_string(), "-p".to_string(), "api".to_string()]
+    );
+    let env_keys: Vec<&String> = lint_api.env.as_ref().unwrap().keys().collect();
+    assert_eq!(env_keys, vec!["api_LINT", "LEVEL"]);
+    match lint_api.env.as_ref().unwrap().get("LEVEL").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "${api_LINT}"),
+        _ => panic!("invalid env value type"),
+    }
+
+    let lint_web = tasks.get("lint-web").unwrap();
+    assert_eq!(
+        lint_web.args.clone().unwrap(),
+        vec![
+            "clippy".to_string(),
+            "-p".to_string(),
+            "web".to_string(),
+            "--all-targets".to_string()
+        ]
+    );
+
+    let env_keys: Vec<&String> = lint_web.env.as_ref().unwrap().keys().collect();
+    assert_eq!(env_keys, vec!["web_LINT", "LEVEL", "WEB"]);
+
+    let other = tasks.get("other").unwrap();
+    assert_eq!(other.command.clone().unwrap(), "echo");
+}
+
+#[test]
+fn instantiate_template_not_defined() {
+    let mut external_config = load(
+        r#"
+[tasks.lint-api]
+template = "missing"
+"#,
+    );
+
+    let result = instantiate(&mut external_config);
+
+    match result {
+        Err(CargoMakeError::TemplateFailed(name, message)) => {
+            assert_eq!(name, "lint-api");
+            assert_eq!(message, "template: missing is not defined");
+        }
+        _ => panic!("missing template should fail"),
+    }
+}
+
+#[test]
+fn instantiate_parameter_not_provided() {
+    let mut external_config = load(
+        r#"
+[templates.clippy-member]
+args = ["clippy", "-p", "{{member}}"]
+
+[tasks.lint-api]
+template = "clippy-member"
+with = { package = "api" }
+"#,
+    );
+
+    let result = instantiate(&mut external_config);
+
+    match result {
+        Err(CargoMakeError::TemplateFailed(_, message)) => assert_eq!(
+            message,
+            "template: clippy-member parameter: member is not provided"
+        ),
+        _ => panic!("missing parameter should fail"),
+    }
+}
+
+#[test]
+fn instantiate_nested_template() {
+    let mut external_config = load(
+        r#"
+[templates.base]
+command = "cargo"
+
+[templates.nested]
+template = "base"
+
+[tasks.lint-api]
+template = "nested"
+"#,
+    );
+
+    let result = instantiate(&mut external_config);
+
+    assert!(result.is_err());
+}
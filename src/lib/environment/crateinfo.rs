@@ -8,9 +8,9 @@
 mod crateinfo_test;
 
 use crate::error::CargoMakeError;
-use crate::types::{CrateDependency, CrateInfo, PackageInfo, Workspace};
+use crate::types::{CrateDependency, CrateInfo, CrateTarget, PackageInfo, Workspace};
 use cargo_metadata::camino::Utf8PathBuf;
-use cargo_metadata::{Metadata, MetadataCommand};
+use cargo_metadata::{Metadata, MetadataCommand, PackageId};
 use glob::glob;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -332,6 +332,32 @@ fn convert_metadata_to_crate_info(metadata: &Metadata) -> CrateInfo {
         package_info.repository = root_package.repository.clone();
 
         crate_info.package = Some(package_info);
+
+        crate_info.features = Some(root_package.features.keys().cloned().collect());
+        crate_info.dependency_names = Some(
+            root_package
+                .dependencies
+                .iter()
+                .map(|dependency| dependency.name.clone())
+                .unique()
+                .collect(),
+        );
+        crate_info.targets = Some(
+            root_package
+                .targets
+                .iter()
+                .map(|target| CrateTarget {
+                    name: target.name.clone(),
+                    kind: target.kind.iter().map(|kind| kind.to_string()).collect(),
+                })
+                .collect(),
+        );
+
+        let workspace_member = match root_package.manifest_path.parent() {
+            Some(package_directory) => package_directory != metadata.workspace_root,
+            None => false,
+        };
+        crate_info.workspace_member = Some(workspace_member);
     }
 
     crate_info
@@ -394,6 +420,59 @@ pub(crate) fn crate_target_dirs(home: Option<PathBuf>) -> CrateTargetDirs {
     CrateTargetDirs { host, custom }
 }
 
+/// Returns the source directory of the named crate dependency (path, git or registry).<br>
+/// Direct dependencies of the workspace members are preferred in case the dependency
+/// tree contains multiple versions of the crate.
+pub(crate) fn get_dependency_directory(
+    directory: &str,
+    name: &str,
+) -> Result<PathBuf, CargoMakeError> {
+    debug!(
+        "Getting cargo metadata for crate: {} in: {}",
+        name, directory
+    );
+
+    let metadata = match MetadataCommand::new().current_dir(directory).exec() {
+        Ok(metadata) => metadata,
+        Err(error) => {
+            return Err(CargoMakeError::NotFound(format!(
+                "Unable to find crate: {} dependency, {}",
+                name, error
+            )));
+        }
+    };
+
+    let direct_dependencies: Vec<&PackageId> = match metadata.resolve {
+        Some(ref resolve) => resolve
+            .nodes
+            .iter()
+            .filter(|node| metadata.workspace_members.contains(&node.id))
+            .flat_map(|node| node.deps.iter().map(|dependency| &dependency.pkg))
+            .collect(),
+        None => vec![],
+    };
+
+    let mut packages = metadata
+        .packages
+        .iter()
+        .filter(|package| package.name == name);
+    let package = match packages
+        .clone()
+        .find(|package| direct_dependencies.contains(&&package.id))
+    {
+        Some(package) => Some(package),
+        None => packages.next(),
+    };
+
+    match package.and_then(|package| package.manifest_path.parent()) {
+        Some(package_directory) => Ok(package_directory.as_std_path().to_path_buf()),
+        None => Err(CargoMakeError::NotFound(format!(
+            "Crate: {} is not a dependency of: {}",
+            name, directory
+        ))),
+    }
+}
+
 pub(crate) fn search_workspace_root() -> Option<String> {
     if envmnt::is("CARGO_MAKE_WORKSPACE_EMULATION") {
         search_workspace_root_for_emulation()
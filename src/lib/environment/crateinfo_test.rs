@@ -1,5 +1,5 @@
 use super::*;
-use crate::test::is_min_rust_version;
+use crate::test::{create_test_crate, get_temp_test_directory, is_min_rust_version};
 use crate::types::CrateDependencyInfo;
 use cargo_metadata::camino::Utf8Path;
 
@@ -12,6 +12,17 @@ fn crate_info_load() {
 
     let package = crate_info.package.unwrap();
     assert_eq!(package.name.unwrap(), "cargo-make");
+
+    let features = crate_info.features.unwrap();
+    assert!(features.contains(&"tls-rustls".to_string()));
+    let dependency_names = crate_info.dependency_names.unwrap();
+    assert!(dependency_names.contains(&"envmnt".to_string()));
+    assert!(dependency_names.contains(&"expect-test".to_string()));
+    let targets = crate_info.targets.unwrap();
+    assert!(targets
+        .iter()
+        .any(|target| target.name == "makers" && target.kind.contains(&"bin".to_string())));
+    assert!(!crate_info.workspace_member.unwrap());
 }
 
 #[test]
@@ -798,6 +809,7 @@ fn load_from_inherit_from_workspace_toml() {
         assert_eq!(package_info.license.unwrap(), "test license");
         assert_eq!(package_info.homepage.unwrap(), "https://testpage.com");
         assert_eq!(package_info.repository.unwrap(), "https://repotest.com");
+        assert!(crate_info.workspace_member.unwrap());
     }
 }
 
@@ -833,3 +845,25 @@ fn dedup_members_with_duplicates() {
         ]
     );
 }
+
+#[test]
+fn get_dependency_directory_path_dependency() {
+    let directory = get_temp_test_directory("crateinfo_get_dependency_directory");
+    let tasks_directory = directory.join("tasks");
+    create_test_crate(&tasks_directory, "extend-tasks", "");
+    create_test_crate(
+        &directory,
+        "extend-host",
+        "extend-tasks = { path = \"tasks\" }\n",
+    );
+
+    let dependency_directory =
+        get_dependency_directory(directory.to_str().unwrap(), "extend-tasks").unwrap();
+    assert_eq!(
+        dependency_directory.canonicalize().unwrap(),
+        tasks_directory.canonicalize().unwrap()
+    );
+
+    let result = get_dependency_directory(directory.to_str().unwrap(), "missing-crate");
+    assert!(result.is_err());
+}
@@ -4,6 +4,8 @@
 //!
 
 pub(crate) mod crateinfo;
+pub(crate) mod sources;
+mod structured_env;
 
 #[cfg(test)]
 #[path = "mod_test.rs"]
@@ -13,29 +15,46 @@ use crate::command;
 use crate::condition;
 use crate::error::CargoMakeError;
 use crate::io;
+use crate::process_env;
 use crate::profile;
 use crate::scriptengine;
+use crate::secrets;
 use crate::time_summary;
 use crate::types::{
     CliArgs, Config, CrateInfo, EnvFile, EnvInfo, EnvValue, EnvValueConditioned, EnvValueDecode,
-    EnvValuePathGlob, EnvValueScript, PackageInfo, ScriptValue, Step, Task, Workspace,
+    EnvValueFromFile, EnvValueFromScript, EnvValuePathGlob, EnvValueScript, EnvValueSecret,
+    PackageInfo, ScriptValue, Step, Task, Workspace,
 };
 use ci_info::types::CiInfo;
 use envmnt::{ExpandOptions, ExpansionType};
 use fsio::path::from_path::FromPath;
 use git_info::types::GitInfo;
 use indexmap::IndexMap;
+use once_cell::sync::Lazy;
 use rust_info::types::{RustChannel, RustInfo};
+use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::SystemTime;
 
+pub(crate) static MAKEFILE_ENV_SOURCE: &str = "makefile env";
+static ENV_SCRIPT_SOURCE: &str = "env script";
+static VARS: Lazy<Mutex<IndexMap<String, String>>> = Lazy::new(|| Mutex::new(IndexMap::new()));
+
 fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
-    match command::run_script_get_output(&env_value.script, None, &vec![], true, Some(false)) {
+    let multi_line = env_value.multi_line.unwrap_or(false);
+
+    evaluate_env_script(key, &env_value.script, multi_line, false)
+}
+
+fn evaluate_env_script(key: &str, script: &Vec<String>, multi_line: bool, secret: bool) -> String {
+    match command::run_script_get_output(script, None, &vec![], true, Some(false)) {
         Ok(output) => {
             let exit_code = output.0;
             let stdout = output.1;
             let stderr = output.2;
+            let printable_stdout = if secret { "***" } else { &stdout };
 
             if exit_code != 0 {
                 error!(
@@ -45,16 +64,11 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
                         "Stdout:\n{}\n",
                         "Stderr:\n{}\n"
                     ),
-                    key, exit_code, env_value.script, &stdout, &stderr
+                    key, exit_code, script, printable_stdout, &stderr
                 );
             }
 
-            debug!("Env script stdout:\n{}", &stdout);
-
-            let multi_line = match env_value.multi_line {
-                Some(bool_value) => bool_value,
-                None => false,
-            };
+            debug!("Env script stdout:\n{}", printable_stdout);
 
             if multi_line {
                 stdout.to_string()
@@ -77,12 +91,68 @@ fn evaluate_env_value(key: &str, env_value: &EnvValueScript) -> String {
     }
 }
 
+/// Returns the makefile variable value (task variables take precedence over the makefile
+/// variables)
+pub(crate) fn get_var(key: &str) -> Option<String> {
+    match process_env::get_var(key) {
+        Some(value) => Some(value),
+        None => VARS
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+            .get(key)
+            .cloned(),
+    }
+}
+
+/// Returns the makefile variable value or the env value if no such variable is defined
+pub(crate) fn get_var_or_env(key: &str) -> Option<String> {
+    match get_var(key) {
+        Some(value) => Some(value),
+        None => env::var(key).ok(),
+    }
+}
+
+/// Replaces all ${name} and ${name:default} references to makefile variables with their values,
+/// references to undefined variables are left for the env expansion.
+fn expand_vars(value: &str) -> String {
+    let mut output = String::new();
+    let mut remaining = value;
+
+    while let Some(start) = remaining.find("${") {
+        let reference = &remaining[start + 2..];
+
+        let expanded = reference.find('}').and_then(|end| {
+            let name = match reference[..end].split_once(':') {
+                Some((name, _)) => name,
+                None => &reference[..end],
+            };
+
+            get_var(name).map(|var_value| (var_value, end))
+        });
+
+        match expanded {
+            Some((var_value, end)) => {
+                output.push_str(&remaining[..start]);
+                output.push_str(&var_value);
+                remaining = &reference[end + 1..];
+            }
+            None => {
+                output.push_str(&remaining[..start + 2]);
+                remaining = reference;
+            }
+        }
+    }
+    output.push_str(remaining);
+
+    output
+}
+
 pub(crate) fn expand_value(value: &str) -> String {
     let mut options = ExpandOptions::new();
     options.expansion_type = Some(ExpansionType::UnixBracketsWithDefaults);
     options.default_to_empty = false;
 
-    envmnt::expand(&value, Some(options))
+    envmnt::expand(&expand_vars(value), Some(options))
 }
 
 fn evaluate_and_set_env(key: &str, value: &str) {
@@ -121,6 +191,111 @@ fn set_env_for_script(key: &str, env_value: &EnvValueScript) {
     }
 }
 
+fn set_env_for_secret(key: &str, secret_info: &EnvValueSecret) {
+    let value = match secret_info.script {
+        Some(ref script) => evaluate_env_script(key, script, false, secret_info.secret),
+        None => match secret_info.file {
+            Some(ref file) => {
+                let file_path = expand_value(file);
+
+                match io::read_text_file(&PathBuf::from(&file_path)) {
+                    Ok(text) => text.trim_end_matches(&['\r', '\n']).to_string(),
+                    Err(error) => {
+                        error!(
+                            "Unable to read secret file: {} for env: {}, error: {}",
+                            &file_path, key, error
+                        );
+                        "".to_string()
+                    }
+                }
+            }
+            None => match secret_info.value {
+                Some(ref value) => expand_value(value),
+                None => "".to_string(),
+            },
+        },
+    };
+
+    envmnt::set(key, &value);
+    if secret_info.secret {
+        secrets::add(key);
+    }
+
+    debug!("Setting Env: {} Value: {}", key, &value);
+}
+
+fn get_env_file_path(file: &str) -> PathBuf {
+    let expanded_file_name = expand_value(file);
+
+    if expanded_file_name.starts_with(".") {
+        Path::new(&envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", ".")).join(expanded_file_name)
+    } else {
+        Path::new(&expanded_file_name).to_path_buf()
+    }
+}
+
+fn set_env_for_map(source: &str, result: Result<IndexMap<String, String>, CargoMakeError>) {
+    match result {
+        Ok(env_map) => {
+            for (key, value) in &env_map {
+                debug!("Setting Env: {} Value: {}", key, value);
+                envmnt::set(key, value);
+            }
+        }
+        Err(error) => error!("Unable to load env from: {}, error: {}", source, error),
+    }
+}
+
+fn set_env_for_from_file(from_file_info: &EnvValueFromFile) {
+    let valid = match from_file_info.condition {
+        Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
+        None => true,
+    };
+
+    if valid {
+        let file_path = get_env_file_path(&from_file_info.from_file);
+        let source = file_path.to_string_lossy().to_string();
+
+        let format = match from_file_info.format {
+            Some(ref value) => value.to_lowercase(),
+            None => structured_env::get_format_from_file_name(&source),
+        };
+
+        let result = io::read_text_file(&file_path).and_then(|text| {
+            let document = structured_env::parse(&source, &text, &format)?;
+            structured_env::flatten(
+                &source,
+                &document,
+                &from_file_info.path,
+                &from_file_info.prefix,
+            )
+        });
+
+        set_env_for_map(&source, result);
+    }
+}
+
+fn set_env_for_from_script(key: &str, from_script_info: &EnvValueFromScript) {
+    let valid = match from_script_info.condition {
+        Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
+        None => true,
+    };
+
+    if valid {
+        let output = evaluate_env_script(key, &from_script_info.from_script, true, false);
+
+        let result = structured_env::parse_script_output(
+            key,
+            &output,
+            &from_script_info.format,
+            &from_script_info.path,
+            &from_script_info.prefix,
+        );
+
+        set_env_for_map(key, result);
+    }
+}
+
 fn set_env_for_decode_info(key: &str, decode_info: &EnvValueDecode) {
     let valid = match decode_info.condition {
         Some(ref condition) => condition::validate_conditions_without_context(condition.clone()),
@@ -191,6 +366,38 @@ pub(crate) fn set_env(env: IndexMap<String, EnvValue>) {
     set_env_for_config(env, None, true)
 }
 
+/// Invokes the action and returns the previous values (None if it was not defined) of
+/// all the env variables it modified, so they can be restored later on.
+pub(crate) fn get_modified_env<F: FnOnce()>(action: F) -> IndexMap<String, Option<String>> {
+    let before: HashMap<String, String> = env::vars().collect();
+    action();
+    let after: HashMap<String, String> = env::vars().collect();
+
+    let mut modified = IndexMap::new();
+    for (key, value) in &after {
+        if before.get(key) != Some(value) {
+            modified.insert(key.to_string(), before.get(key).cloned());
+        }
+    }
+    for (key, value) in &before {
+        if !after.contains_key(key) {
+            modified.insert(key.to_string(), Some(value.to_string()));
+        }
+    }
+
+    modified
+}
+
+/// Restores the env variables to their previous values (undefined values are removed)
+pub(crate) fn restore_env(previous_env: &IndexMap<String, Option<String>>) {
+    for (key, value) in previous_env {
+        match value {
+            Some(value) => envmnt::set(key, value),
+            None => envmnt::remove(key),
+        };
+    }
+}
+
 fn unset_env(key: &str) {
     envmnt::remove(key);
 }
@@ -212,6 +419,11 @@ pub(crate) fn set_env_for_config(
             EnvValue::Number(value) => evaluate_and_set_env(&key, &value.to_string()),
             EnvValue::List(ref value) => set_env_for_list(&key, value),
             EnvValue::Script(ref script_info) => set_env_for_script(&key, script_info),
+            EnvValue::Secret(ref secret_info) => set_env_for_secret(key, secret_info),
+            EnvValue::FromFile(ref from_file_info) => set_env_for_from_file(from_file_info),
+            EnvValue::FromScript(ref from_script_info) => {
+                set_env_for_from_script(key, from_script_info)
+            }
             EnvValue::Decode(ref decode_info) => set_env_for_decode_info(&key, decode_info),
             EnvValue::Conditional(ref conditioned_value) => {
                 set_env_for_conditional_value(&key, conditioned_value)
@@ -249,6 +461,64 @@ pub(crate) fn set_env_for_config(
     }
 }
 
+/// Evaluates the variables one by one (same as env values) without leaving them in the process
+/// env and passes the evaluated values (None if unset) to the store function.
+fn evaluate_vars<F: Fn(IndexMap<String, Option<String>>)>(
+    vars: IndexMap<String, EnvValue>,
+    additional_profiles: Option<&Vec<String>>,
+    store: F,
+) {
+    for (key, var_value) in vars {
+        if let EnvValue::Unset(ref value) = var_value {
+            if value.unset {
+                let mut unset_vars = IndexMap::new();
+                unset_vars.insert(key, None);
+                store(unset_vars);
+            }
+
+            continue;
+        }
+
+        let mut var = IndexMap::new();
+        var.insert(key, var_value);
+
+        let (evaluated_vars, _) = process_env::evaluate(false, || {
+            let previous_env =
+                get_modified_env(|| set_env_for_config(var, additional_profiles, true));
+
+            previous_env
+                .keys()
+                .map(|key| (key.to_string(), env::var(key).ok()))
+                .collect()
+        });
+
+        debug!("Setting Vars: {:#?}", &evaluated_vars);
+        store(evaluated_vars);
+    }
+}
+
+fn set_vars_for_config(
+    vars: IndexMap<String, EnvValue>,
+    additional_profiles: Option<&Vec<String>>,
+) {
+    evaluate_vars(vars, additional_profiles, |evaluated_vars| {
+        let mut all_vars = VARS.lock().unwrap_or_else(|error| error.into_inner());
+
+        for (key, value) in evaluated_vars {
+            match value {
+                Some(value) => all_vars.insert(key, value),
+                None => all_vars.shift_remove(&key),
+            };
+        }
+    });
+}
+
+/// Evaluates the task variables which are visible only while the task is running and are never
+/// exported to the process env
+pub(crate) fn set_task_vars(vars: IndexMap<String, EnvValue>) {
+    evaluate_vars(vars, None, process_env::set_vars);
+}
+
 pub(crate) fn set_env_files(env_files: Vec<EnvFile>) {
     set_env_files_for_config(env_files, None);
 }
@@ -337,11 +607,17 @@ fn initialize_env(config: &Config, cli_args: &Vec<String>) -> Result<(), CargoMa
         None => None,
     };
 
+    set_vars_for_config(config.vars.clone(), additional_profiles);
+
     set_env_files_for_config(config.env_files.clone(), additional_profiles);
 
-    set_env_for_config(config.env.clone(), additional_profiles, true);
+    sources::track(MAKEFILE_ENV_SOURCE, || {
+        set_env_for_config(config.env.clone(), additional_profiles, true)
+    });
 
-    set_env_scripts(config.env_scripts.clone(), cli_args)
+    sources::track(ENV_SCRIPT_SOURCE, || {
+        set_env_scripts(config.env_scripts.clone(), cli_args)
+    })
 }
 
 fn setup_env_for_duckscript() {
@@ -758,7 +1034,12 @@ fn load_env_file_with_base_directory(
                         }
                     };
 
-                    match envmnt::evaluate_and_load_file(file_path_str, evaluate_env_var) {
+                    let source = format!("env file: {}", file_path_str);
+                    let result = sources::track(&source, || {
+                        envmnt::evaluate_and_load_file(file_path_str, evaluate_env_var)
+                    });
+
+                    match result {
                         Err(error) => {
                             error!(
                                 "Unable to load env file: {} Error: {:#?}",
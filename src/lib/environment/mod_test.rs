@@ -1,6 +1,9 @@
 use super::*;
 
-use crate::types::{ConfigSection, EnvFileInfo, EnvValueUnset, TaskCondition};
+use crate::types::{
+    ConfigSection, EnvFileInfo, EnvValueFromFile, EnvValueFromScript, EnvValueSecret,
+    EnvValueUnset, TaskCondition,
+};
 use std::collections::HashMap;
 use std::env;
 use std::{thread, time};
@@ -330,6 +333,10 @@ fn set_env_script_with_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let mut env = IndexMap::new();
@@ -451,6 +458,10 @@ fn set_env_for_decode_info_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let decode_info = EnvValueDecode {
@@ -492,6 +503,10 @@ fn set_env_for_decode_info_condition_false() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let decode_info = EnvValueDecode {
@@ -547,6 +562,10 @@ fn set_env_for_conditional_value_condition_true() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let info = EnvValueConditioned {
@@ -585,6 +604,10 @@ fn set_env_for_conditional_value_condition_false() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     };
 
     let info = EnvValueConditioned {
@@ -706,6 +729,193 @@ fn set_env_for_config_unset() {
     assert!(!envmnt::exists("set_env_for_config_unset"));
 }
 
+#[test]
+fn set_env_for_config_secret_value() {
+    envmnt::set("SET_ENV_FOR_CONFIG_SECRET_VALUE_SOURCE", "secret-source");
+
+    let secret = EnvValueSecret {
+        secret: true,
+        value: Some("${SET_ENV_FOR_CONFIG_SECRET_VALUE_SOURCE}-value".to_string()),
+        file: None,
+        script: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "SET_ENV_FOR_CONFIG_SECRET_VALUE".to_string(),
+        EnvValue::Secret(secret),
+    );
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_SECRET_VALUE"),
+        "secret-source-value"
+    );
+    assert!(secrets::get_keys().contains(&"SET_ENV_FOR_CONFIG_SECRET_VALUE".to_string()));
+}
+
+#[test]
+fn set_env_for_config_secret_file() {
+    let file = "./target/_temp/set_env_for_config_secret_file.txt";
+    fsio::file::write_text_file(file, "secret-from-file\n").unwrap();
+
+    let secret = EnvValueSecret {
+        secret: true,
+        value: None,
+        file: Some(file.to_string()),
+        script: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "SET_ENV_FOR_CONFIG_SECRET_FILE".to_string(),
+        EnvValue::Secret(secret),
+    );
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_SECRET_FILE"),
+        "secret-from-file"
+    );
+    assert!(secrets::get_keys().contains(&"SET_ENV_FOR_CONFIG_SECRET_FILE".to_string()));
+}
+
+#[test]
+fn set_env_for_config_secret_script() {
+    let secret = EnvValueSecret {
+        secret: true,
+        value: None,
+        file: None,
+        script: Some(vec!["echo secret-from-script".to_string()]),
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "SET_ENV_FOR_CONFIG_SECRET_SCRIPT".to_string(),
+        EnvValue::Secret(secret),
+    );
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_SECRET_SCRIPT"),
+        "secret-from-script"
+    );
+    assert!(secrets::get_keys().contains(&"SET_ENV_FOR_CONFIG_SECRET_SCRIPT".to_string()));
+}
+
+#[test]
+fn set_env_for_config_secret_disabled() {
+    let secret = EnvValueSecret {
+        secret: false,
+        value: Some("not-secret".to_string()),
+        file: None,
+        script: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert(
+        "SET_ENV_FOR_CONFIG_SECRET_DISABLED".to_string(),
+        EnvValue::Secret(secret),
+    );
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_SECRET_DISABLED"),
+        "not-secret"
+    );
+    assert!(!secrets::get_keys().contains(&"SET_ENV_FOR_CONFIG_SECRET_DISABLED".to_string()));
+}
+
+#[test]
+fn set_env_for_config_from_json_file() {
+    let file = "./target/_temp/set_env_for_config_from_json_file.json";
+    fsio::file::write_text_file(
+        file,
+        r#"{ "services": { "api": { "host": "localhost", "port": 8080 } } }"#,
+    )
+    .unwrap();
+
+    let from_file = EnvValueFromFile {
+        from_file: file.to_string(),
+        format: None,
+        path: Some("services.api".to_string()),
+        prefix: Some("SET_ENV_FOR_CONFIG_FROM_JSON_FILE_".to_string()),
+        condition: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert("from_json".to_string(), EnvValue::FromFile(from_file));
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_FROM_JSON_FILE_HOST"),
+        "localhost"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_FROM_JSON_FILE_PORT"),
+        "8080"
+    );
+    assert!(!envmnt::exists("from_json"));
+}
+
+#[test]
+fn set_env_for_config_from_toml_file() {
+    let file = "./target/_temp/set_env_for_config_from_toml_file.conf";
+    fsio::file::write_text_file(file, "[set_env_for_config_from_toml_file]\nkey = \"value\"")
+        .unwrap();
+
+    let from_file = EnvValueFromFile {
+        from_file: file.to_string(),
+        format: Some("toml".to_string()),
+        path: None,
+        prefix: None,
+        condition: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert("from_toml".to_string(), EnvValue::FromFile(from_file));
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_FROM_TOML_FILE_KEY"),
+        "value"
+    );
+}
+
+#[test]
+fn set_env_for_config_from_script() {
+    let from_script = EnvValueFromScript {
+        from_script: vec![
+            "echo SET_ENV_FOR_CONFIG_FROM_SCRIPT_1=first".to_string(),
+            "echo SET_ENV_FOR_CONFIG_FROM_SCRIPT_2=second".to_string(),
+        ],
+        format: None,
+        path: None,
+        prefix: None,
+        condition: None,
+    };
+
+    let mut env = IndexMap::new();
+    env.insert("from_script".to_string(), EnvValue::FromScript(from_script));
+
+    set_env_for_config(env, None, true);
+
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_FROM_SCRIPT_1"),
+        "first"
+    );
+    assert_eq!(
+        envmnt::get_or_panic("SET_ENV_FOR_CONFIG_FROM_SCRIPT_2"),
+        "second"
+    );
+}
+
 #[test]
 #[ignore]
 fn set_env_for_config_conditional() {
@@ -732,6 +942,10 @@ fn set_env_for_config_conditional() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
     };
 
@@ -1034,6 +1248,7 @@ fn initialize_env_all() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     initialize_env(&config, &vec![]).unwrap();
@@ -1069,6 +1284,7 @@ fn setup_env_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1100,6 +1316,7 @@ fn setup_env_skip_git() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1121,6 +1338,7 @@ fn setup_env_skip_rust() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1142,6 +1360,7 @@ fn setup_env_skip_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let env_info = setup_env(&cli_args, &config, "setup_env_empty1", None, &mut vec![]).unwrap();
@@ -1191,6 +1410,7 @@ fn setup_env_cli_arguments() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     envmnt::set("CARGO_MAKE_TASK_ARGS", "EMPTY");
@@ -1213,6 +1433,7 @@ fn setup_env_values() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.env.insert(
         "MY_ENV_KEY".to_string(),
@@ -1244,6 +1465,7 @@ fn setup_env_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.env.insert(
         "MY_ENV_SCRIPT_KEY".to_string(),
@@ -1933,6 +2155,7 @@ fn setup_env_for_project_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let crate_info = crateinfo::load().unwrap();
@@ -1962,6 +2185,7 @@ fn setup_env_for_project_workspace_with_main_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1986,6 +2210,7 @@ fn setup_env_for_project_workspace_no_main_crate() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     envmnt::remove("CARGO_MAKE_PROJECT_NAME");
@@ -1999,3 +2224,154 @@ fn setup_env_for_project_workspace_no_main_crate() {
     assert!(envmnt::is_equal("CARGO_MAKE_PROJECT_NAME", "workspace1"));
     assert!(!envmnt::exists("CARGO_MAKE_PROJECT_VERSION"));
 }
+
+#[test]
+fn get_modified_env_and_restore() {
+    envmnt::set("GET_MODIFIED_ENV_CHANGED", "before");
+    envmnt::set("GET_MODIFIED_ENV_REMOVED", "before");
+    envmnt::set("GET_MODIFIED_ENV_SAME", "before");
+    envmnt::remove("GET_MODIFIED_ENV_ADDED");
+
+    let previous_env = get_modified_env(|| {
+        envmnt::set("GET_MODIFIED_ENV_CHANGED", "after");
+        envmnt::remove("GET_MODIFIED_ENV_REMOVED");
+        envmnt::set("GET_MODIFIED_ENV_SAME", "before");
+        envmnt::set("GET_MODIFIED_ENV_ADDED", "after");
+    });
+
+    assert_eq!(
+        previous_env.get("GET_MODIFIED_ENV_CHANGED").unwrap(),
+        &Some("before".to_string())
+    );
+    assert_eq!(
+        previous_env.get("GET_MODIFIED_ENV_REMOVED").unwrap(),
+        &Some("before".to_string())
+    );
+    assert_eq!(previous_env.get("GET_MODIFIED_ENV_ADDED").unwrap(), &None);
+    assert!(!previous_env.contains_key("GET_MODIFIED_ENV_SAME"));
+
+    restore_env(&previous_env);
+
+    assert!(envmnt::is_equal("GET_MODIFIED_ENV_CHANGED", "before"));
+    assert!(envmnt::is_equal("GET_MODIFIED_ENV_REMOVED", "before"));
+    assert!(!envmnt::exists("GET_MODIFIED_ENV_ADDED"));
+
+    envmnt::remove_all(&vec![
+        "GET_MODIFIED_ENV_CHANGED",
+        "GET_MODIFIED_ENV_REMOVED",
+        "GET_MODIFIED_ENV_SAME",
+    ]);
+}
+
+#[test]
+fn expand_value_with_vars() {
+    envmnt::set("EXPAND_VALUE_VARS_ENV", "env");
+
+    let mut process_env = process_env::ProcessEnv::default();
+    process_env
+        .vars
+        .insert("EXPAND_VALUE_VARS_VAR".to_string(), "var".to_string());
+
+    let output = process_env::run_with(process_env, || {
+        expand_value(
+            "${EXPAND_VALUE_VARS_VAR} ${EXPAND_VALUE_VARS_VAR:default} ${EXPAND_VALUE_VARS_ENV} ${EXPAND_VALUE_VARS_UNDEFINED:default}",
+        )
+    });
+
+    assert_eq!(output, "var var env default");
+}
+
+#[test]
+fn expand_vars_undefined_and_unterminated() {
+    let output = expand_vars("${EXPAND_VARS_UNDEFINED} ${EXPAND_VARS_UNTERMINATED");
+
+    assert_eq!(
+        output,
+        "${EXPAND_VARS_UNDEFINED} ${EXPAND_VARS_UNTERMINATED"
+    );
+}
+
+#[test]
+fn set_vars_for_config_not_exported() {
+    envmnt::remove("SET_VARS_FOR_CONFIG_VAR");
+    envmnt::remove("SET_VARS_FOR_CONFIG_COMPOSITE");
+    envmnt::remove("SET_VARS_FOR_CONFIG_UNSET");
+
+    let mut vars = IndexMap::new();
+    vars.insert(
+        "SET_VARS_FOR_CONFIG_VAR".to_string(),
+        EnvValue::Value("value".to_string()),
+    );
+    vars.insert(
+        "SET_VARS_FOR_CONFIG_COMPOSITE".to_string(),
+        EnvValue::Value("${SET_VARS_FOR_CONFIG_VAR}-composite".to_string()),
+    );
+    vars.insert(
+        "SET_VARS_FOR_CONFIG_UNSET".to_string(),
+        EnvValue::Value("value".to_string()),
+    );
+    set_vars_for_config(vars, None);
+
+    assert_eq!(get_var("SET_VARS_FOR_CONFIG_VAR").unwrap(), "value");
+    assert_eq!(
+        get_var("SET_VARS_FOR_CONFIG_COMPOSITE").unwrap(),
+        "value-composite"
+    );
+    assert!(!envmnt::exists("SET_VARS_FOR_CONFIG_VAR"));
+    assert!(!envmnt::exists("SET_VARS_FOR_CONFIG_COMPOSITE"));
+
+    let mut vars = IndexMap::new();
+    vars.insert(
+        "SET_VARS_FOR_CONFIG_UNSET".to_string(),
+        EnvValue::Unset(EnvValueUnset { unset: true }),
+    );
+    set_vars_for_config(vars, None);
+
+    assert!(get_var("SET_VARS_FOR_CONFIG_UNSET").is_none());
+}
+
+#[test]
+fn set_task_vars_scoped() {
+    envmnt::remove("SET_TASK_VARS_VAR");
+
+    let mut vars = IndexMap::new();
+    vars.insert(
+        "SET_TASK_VARS_VAR".to_string(),
+        EnvValue::Conditional(EnvValueConditioned {
+            value: "value".to_string(),
+            condition: None,
+        }),
+    );
+
+    let (output, process_env) = process_env::evaluate(false, || {
+        set_task_vars(vars);
+        expand_value("${SET_TASK_VARS_VAR}")
+    });
+
+    assert_eq!(output, "value");
+    assert_eq!(process_env.vars.get("SET_TASK_VARS_VAR").unwrap(), "value");
+    assert!(!process_env.env.contains_key("SET_TASK_VARS_VAR"));
+    assert!(get_var("SET_TASK_VARS_VAR").is_none());
+    assert!(!envmnt::exists("SET_TASK_VARS_VAR"));
+}
+
+#[test]
+fn get_var_or_env_precedence() {
+    envmnt::set("GET_VAR_OR_ENV_BOTH", "env");
+    envmnt::set("GET_VAR_OR_ENV_ENV", "env");
+
+    let mut process_env = process_env::ProcessEnv::default();
+    process_env
+        .vars
+        .insert("GET_VAR_OR_ENV_BOTH".to_string(), "var".to_string());
+
+    let (both, env_only) = process_env::run_with(process_env, || {
+        (
+            get_var_or_env("GET_VAR_OR_ENV_BOTH"),
+            get_var_or_env("GET_VAR_OR_ENV_ENV"),
+        )
+    });
+
+    assert_eq!(both.unwrap(), "var");
+    assert_eq!(env_only.unwrap(), "env");
+}
@@ -0,0 +1,100 @@
+//! # sources
+//!
+//! Optionally tracks which source (env file, makefile env, env script, task env...) set each env variable.
+//!
+
+#[cfg(test)]
+#[path = "sources_test.rs"]
+mod sources_test;
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::env;
+use std::sync::Mutex;
+
+/// Source name for env variables which were already defined before cargo-make started
+pub(crate) static PROCESS_ENV_SOURCE: &str = "process env";
+/// Source name for env variables set internally by cargo-make
+pub(crate) static CARGO_MAKE_SOURCE: &str = "cargo-make";
+
+struct SourcesTracker {
+    initial_env: HashMap<String, String>,
+    sources: HashMap<String, String>,
+}
+
+static TRACKER: Mutex<Option<SourcesTracker>> = Mutex::new(None);
+static TRACK_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static IN_TRACK: Cell<bool> = const { Cell::new(false) };
+}
+
+fn get_env_snapshot() -> HashMap<String, String> {
+    env::vars().collect()
+}
+
+/// Starts tracking env sources (the current env is considered as the process env)
+pub(crate) fn enable() {
+    if let Ok(mut tracker) = TRACKER.lock() {
+        if tracker.is_none() {
+            *tracker = Some(SourcesTracker {
+                initial_env: get_env_snapshot(),
+                sources: HashMap::new(),
+            });
+        }
+    }
+}
+
+fn is_enabled() -> bool {
+    match TRACKER.lock() {
+        Ok(tracker) => tracker.is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Invokes the action and if tracking is enabled, marks all modified env variables
+/// as set by the provided source.
+/// Nested calls are attributed to the outer source.
+pub(crate) fn track<T, F: FnOnce() -> T>(source: &str, action: F) -> T {
+    if !is_enabled() || IN_TRACK.with(|in_track| in_track.get()) {
+        return action();
+    }
+
+    let _guard = TRACK_LOCK.lock();
+    IN_TRACK.with(|in_track| in_track.set(true));
+
+    let before = get_env_snapshot();
+    let output = action();
+    let after = get_env_snapshot();
+
+    IN_TRACK.with(|in_track| in_track.set(false));
+
+    if let Ok(mut tracker) = TRACKER.lock() {
+        if let Some(ref mut tracker) = *tracker {
+            for (key, value) in &after {
+                if before.get(key) != Some(value) {
+                    tracker.sources.insert(key.to_string(), source.to_string());
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns the name of the source which set the env variable
+pub(crate) fn get(key: &str, value: &str) -> String {
+    if let Ok(tracker) = TRACKER.lock() {
+        if let Some(ref tracker) = *tracker {
+            if let Some(source) = tracker.sources.get(key) {
+                return source.to_string();
+            }
+
+            if tracker.initial_env.get(key).map(|initial| initial.as_str()) == Some(value) {
+                return PROCESS_ENV_SOURCE.to_string();
+            }
+        }
+    }
+
+    CARGO_MAKE_SOURCE.to_string()
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn track_and_get() {
+    enable();
+
+    track("test source", || {
+        envmnt::set("SOURCES_TEST_TRACK_AND_GET_NEW", "new");
+    });
+
+    assert_eq!(get("SOURCES_TEST_TRACK_AND_GET_NEW", "new"), "test source");
+}
+
+#[test]
+fn get_process_env_value() {
+    enable();
+
+    let initial_entry = {
+        let tracker = TRACKER.lock().unwrap();
+        let tracker = tracker.as_ref().unwrap();
+
+        tracker
+            .initial_env
+            .iter()
+            .find(|(key, _)| !tracker.sources.contains_key(*key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+    };
+
+    if let Some((key, value)) = initial_entry {
+        assert_eq!(get(&key, &value), PROCESS_ENV_SOURCE);
+    }
+}
+
+#[test]
+fn get_untracked_value() {
+    enable();
+
+    envmnt::set("SOURCES_TEST_GET_UNTRACKED_VALUE", "value");
+
+    assert_eq!(
+        get("SOURCES_TEST_GET_UNTRACKED_VALUE", "value"),
+        CARGO_MAKE_SOURCE
+    );
+}
+
+#[test]
+fn track_nested() {
+    enable();
+
+    track("outer source", || {
+        track("inner source", || {
+            envmnt::set("SOURCES_TEST_TRACK_NESTED", "value");
+        });
+    });
+
+    assert_eq!(get("SOURCES_TEST_TRACK_NESTED", "value"), "outer source");
+}
+
+#[test]
+fn track_returns_action_output() {
+    let output = track("test source", || 5);
+
+    assert_eq!(output, 5);
+}
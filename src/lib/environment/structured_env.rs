@@ -0,0 +1,211 @@
+//! # structured_env
+//!
+//! Flattens structured documents (JSON/TOML/YAML files or script outputs) into env variables.
+//!
+
+#[cfg(test)]
+#[path = "structured_env_test.rs"]
+mod structured_env_test;
+
+use crate::error::CargoMakeError;
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::path::Path;
+
+/// Returns the structured format name based on the file extension
+pub(crate) fn get_format_from_file_name(file: &str) -> String {
+    match Path::new(file).extension() {
+        Some(extension) => extension.to_string_lossy().to_lowercase(),
+        None => "".to_string(),
+    }
+}
+
+/// Parses the structured text based on the provided format (json, toml, yaml)
+pub(crate) fn parse(source: &str, text: &str, format: &str) -> Result<Value, CargoMakeError> {
+    match format {
+        "json" => serde_json::from_str(text).map_err(|error| {
+            CargoMakeError::ParseFileFailed(source.to_string(), error.to_string())
+        }),
+        "toml" => toml::from_str(text).map_err(|error| {
+            CargoMakeError::ParseFileFailed(source.to_string(), error.to_string())
+        }),
+        "yaml" | "yml" => serde_yaml_ng::from_str(text).map_err(|error| {
+            CargoMakeError::ParseFileFailed(source.to_string(), error.to_string())
+        }),
+        _ => Err(CargoMakeError::ParseFileFailed(
+            source.to_string(),
+            format!("Unsupported format: {}", format),
+        )),
+    }
+}
+
+fn get_section<'a>(
+    source: &str,
+    document: &'a Value,
+    path: &Option<String>,
+) -> Result<&'a Value, CargoMakeError> {
+    let mut section = document;
+
+    if let Some(path_value) = path {
+        for key in path_value.split('.').filter(|key| !key.is_empty()) {
+            let next_section = match section {
+                Value::Object(map) => map.get(key),
+                Value::Array(list) => key.parse::<usize>().ok().and_then(|index| list.get(index)),
+                _ => None,
+            };
+
+            section = match next_section {
+                Some(value) => value,
+                None => {
+                    return Err(CargoMakeError::NotFound(format!(
+                        "Path: {} not found in: {}",
+                        path_value, source
+                    )))
+                }
+            };
+        }
+    }
+
+    Ok(section)
+}
+
+fn to_env_name(key: &str) -> String {
+    key.chars()
+        .map(|character| {
+            if character.is_ascii_alphanumeric() {
+                character.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn to_env_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.to_string()),
+        Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+fn flatten_value(name: &str, value: &Value, output: &mut IndexMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_name = format!("{}_{}", name, to_env_name(key));
+                flatten_value(&child_name, child, output);
+            }
+        }
+        Value::Array(list) => {
+            if list
+                .iter()
+                .all(|item| !item.is_object() && !item.is_array())
+            {
+                // scalar lists are joined with ';' same as env list values
+                let values: Vec<String> = list.iter().filter_map(to_env_value).collect();
+                output.insert(name.to_string(), values.join(";"));
+            } else {
+                for (index, child) in list.iter().enumerate() {
+                    let child_name = format!("{}_{}", name, index);
+                    flatten_value(&child_name, child, output);
+                }
+            }
+        }
+        _ => {
+            if let Some(env_value) = to_env_value(value) {
+                output.insert(name.to_string(), env_value);
+            }
+        }
+    }
+}
+
+/// Flattens the document section found in the given path into env variables.<br>
+/// Nested keys are joined with '_' and converted to upper case.
+pub(crate) fn flatten(
+    source: &str,
+    document: &Value,
+    path: &Option<String>,
+    prefix: &Option<String>,
+) -> Result<IndexMap<String, String>, CargoMakeError> {
+    let section = get_section(source, document, path)?;
+    let prefix_value = prefix.clone().unwrap_or_default();
+
+    let mut output = IndexMap::new();
+    match section {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let name = format!("{}{}", &prefix_value, to_env_name(key));
+                flatten_value(&name, child, &mut output);
+            }
+
+            Ok(output)
+        }
+        _ => Err(CargoMakeError::ParseFileFailed(
+            source.to_string(),
+            "Only tables can be loaded as env variables".to_string(),
+        )),
+    }
+}
+
+/// Parses KEY=VALUE lines (empty lines and comments are ignored)
+pub(crate) fn parse_dotenv(text: &str, prefix: &Option<String>) -> IndexMap<String, String> {
+    let prefix_value = prefix.clone().unwrap_or_default();
+
+    let mut output = IndexMap::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim();
+            let value = if value.len() >= 2
+                && ((value.starts_with('"') && value.ends_with('"'))
+                    || (value.starts_with('\'') && value.ends_with('\'')))
+            {
+                &value[1..value.len() - 1]
+            } else {
+                value
+            };
+
+            output.insert(
+                format!("{}{}", &prefix_value, key.trim()),
+                value.to_string(),
+            );
+        }
+    }
+
+    output
+}
+
+/// Parses the script output (dotenv, json, toml or yaml) into env variables.<br>
+/// If no format is provided, json is assumed if the output is a JSON object.
+pub(crate) fn parse_script_output(
+    source: &str,
+    output: &str,
+    format: &Option<String>,
+    path: &Option<String>,
+    prefix: &Option<String>,
+) -> Result<IndexMap<String, String>, CargoMakeError> {
+    let format_value = match format {
+        Some(value) => value.to_lowercase(),
+        None => {
+            if output.trim_start().starts_with('{') {
+                "json".to_string()
+            } else {
+                "dotenv".to_string()
+            }
+        }
+    };
+
+    if format_value == "dotenv" {
+        Ok(parse_dotenv(output, prefix))
+    } else {
+        let document = parse(source, output, &format_value)?;
+        flatten(source, &document, path, prefix)
+    }
+}
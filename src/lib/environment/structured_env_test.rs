@@ -0,0 +1,173 @@
+use super::*;
+
+#[test]
+fn get_format_from_file_name_json() {
+    let format = get_format_from_file_name("./config/deploy.JSON");
+
+    assert_eq!(format, "json");
+}
+
+#[test]
+fn get_format_from_file_name_no_extension() {
+    let format = get_format_from_file_name("./config/deploy");
+
+    assert_eq!(format, "");
+}
+
+#[test]
+fn parse_json() {
+    let document = parse("test", r#"{ "a": { "b": 1 } }"#, "json").unwrap();
+
+    assert_eq!(document["a"]["b"], 1);
+}
+
+#[test]
+fn parse_toml() {
+    let document = parse("test", "[a]\nb = \"value\"", "toml").unwrap();
+
+    assert_eq!(document["a"]["b"], "value");
+}
+
+#[test]
+fn parse_invalid_json() {
+    let result = parse("test", "{ invalid", "json");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_yaml() {
+    let document = parse("test", "a:\n  b: value\n  c: [1, 2]", "yaml").unwrap();
+
+    assert_eq!(document["a"]["b"], "value");
+    assert_eq!(document["a"]["c"][1], 2);
+
+    let document = parse("test", "a: 1", "yml").unwrap();
+    assert_eq!(document["a"], 1);
+}
+
+#[test]
+fn parse_invalid_yaml() {
+    let result = parse("test", "a: [1", "yaml");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_unsupported_format() {
+    let result = parse("test", "a = 1", "ini");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn flatten_nested_values() {
+    let document: Value = serde_json::from_str(
+        r#"{
+        "services": {
+            "api": {
+                "host": "localhost",
+                "port": 8080,
+                "enabled": true,
+                "tags": ["a", "b"],
+                "db-config": { "name": "main" },
+                "empty": null
+            }
+        }
+    }"#,
+    )
+    .unwrap();
+
+    let env = flatten(
+        "test",
+        &document,
+        &Some("services.api".to_string()),
+        &Some("API_".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(env.len(), 5);
+    assert_eq!(env.get("API_HOST").unwrap(), "localhost");
+    assert_eq!(env.get("API_PORT").unwrap(), "8080");
+    assert_eq!(env.get("API_ENABLED").unwrap(), "true");
+    assert_eq!(env.get("API_TAGS").unwrap(), "a;b");
+    assert_eq!(env.get("API_DB_CONFIG_NAME").unwrap(), "main");
+}
+
+#[test]
+fn flatten_list_of_tables() {
+    let document: Value =
+        serde_json::from_str(r#"{ "servers": [{ "name": "first" }, { "name": "second" }] }"#)
+            .unwrap();
+
+    let env = flatten("test", &document, &None, &None).unwrap();
+
+    assert_eq!(env.get("SERVERS_0_NAME").unwrap(), "first");
+    assert_eq!(env.get("SERVERS_1_NAME").unwrap(), "second");
+}
+
+#[test]
+fn flatten_path_not_found() {
+    let document: Value = serde_json::from_str(r#"{ "a": { "b": 1 } }"#).unwrap();
+
+    let result = flatten("test", &document, &Some("a.c".to_string()), &None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn flatten_path_not_table() {
+    let document: Value = serde_json::from_str(r#"{ "a": { "b": 1 } }"#).unwrap();
+
+    let result = flatten("test", &document, &Some("a.b".to_string()), &None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn parse_dotenv_valid() {
+    let env = parse_dotenv(
+        "# comment\n\nKEY1=value1\nexport KEY2 = \"value 2\"\nKEY3='value=3'\ninvalid line\n",
+        &Some("PRE_".to_string()),
+    );
+
+    assert_eq!(env.len(), 3);
+    assert_eq!(env.get("PRE_KEY1").unwrap(), "value1");
+    assert_eq!(env.get("PRE_KEY2").unwrap(), "value 2");
+    assert_eq!(env.get("PRE_KEY3").unwrap(), "value=3");
+}
+
+#[test]
+fn parse_script_output_detect_json() {
+    let env = parse_script_output(
+        "test",
+        r#"{ "key": "value" }"#,
+        &None,
+        &None,
+        &Some("PRE_".to_string()),
+    )
+    .unwrap();
+
+    assert_eq!(env.get("PRE_KEY").unwrap(), "value");
+}
+
+#[test]
+fn parse_script_output_detect_dotenv() {
+    let env = parse_script_output("test", "KEY=value\n", &None, &None, &None).unwrap();
+
+    assert_eq!(env.get("KEY").unwrap(), "value");
+}
+
+#[test]
+fn parse_script_output_explicit_dotenv() {
+    let env = parse_script_output(
+        "test",
+        "KEY={value}\n",
+        &Some("dotenv".to_string()),
+        &None,
+        &None,
+    )
+    .unwrap();
+
+    assert_eq!(env.get("KEY").unwrap(), "{value}");
+}
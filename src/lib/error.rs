@@ -46,6 +46,21 @@ pub enum CargoMakeError {
     #[strum(to_string = "Task {0:#?} is {1}")]
     TaskIs(String, &'static str) = 110,
 
+    #[strum(to_string = "Lint failed with {0} finding(s).")]
+    LintFailed(usize) = 111,
+
+    #[strum(to_string = "Makefile: {0} is not formatted.")]
+    NotFormatted(String) = 112,
+
+    #[strum(to_string = "Unable to extend makefile from git repository: {0}, {1}")]
+    GitExtendFailed(String, String) = 113,
+
+    #[strum(to_string = "Unable to instantiate task: {0} from template, {1}")]
+    TemplateFailed(String, String) = 114,
+
+    #[strum(to_string = "Unable to import tasks from file: {0}, {1}")]
+    ImportFailed(String, String) = 115,
+
     #[strum(to_string = "{0}")]
     NotFound(String) = 404,
 
@@ -7,6 +7,7 @@
 #[path = "execution_plan_test.rs"]
 mod execution_plan_test;
 
+use crate::descriptor;
 use crate::environment;
 use crate::error::CargoMakeError;
 use crate::logger;
@@ -195,7 +196,9 @@ fn filter_workspace_members(members: &Vec<String>) -> Vec<String> {
     filtered_members
 }
 
-fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
+/// Creates the task which invokes the provided tasks in all the workspace members (a single
+/// invocation per member, so shared dependencies are invoked once per member).
+fn create_workspace_task(crate_info: &CrateInfo, tasks: &[&str]) -> Task {
     let set_workspace_emulation = crate_info.workspace.is_none()
         && envmnt::is("CARGO_MAKE_WORKSPACE_EMULATION")
         && !envmnt::exists("CARGO_MAKE_WORKSPACE_EMULATION_ROOT_DIRECTORY");
@@ -249,8 +252,17 @@ fn create_workspace_task(crate_info: &CrateInfo, task: &str) -> Task {
             make_line.push_str(&member_name);
             make_line.push_str(" --profile ");
             make_line.push_str(&profile_name);
-            make_line.push_str(" -- ");
-            make_line.push_str(&task);
+            match tasks {
+                [task] => {
+                    make_line.push_str(" -- ");
+                    make_line.push_str(task);
+                }
+                _ => {
+                    make_line.push_str(" --tasks=");
+                    make_line.push_str(&tasks.join(","));
+                    make_line.push_str(" --");
+                }
+            };
 
             if let Some(args) = envmnt::get_list("CARGO_MAKE_TASK_ARGS") {
                 for arg in args {
@@ -358,7 +370,10 @@ fn create_for_step(
             let working_directory = get_parent_directory(&path_obj);
             (working_directory, filename)
         } else {
-            (Some(path.to_string()), Some("Makefile.toml".to_string()))
+            (
+                Some(path.to_string()),
+                Some(descriptor::get_default_makefile(path_obj)),
+            )
         };
 
         let mut proxy_task = create_proxy_task(&task.name, true, false, makefile, None);
@@ -450,6 +465,7 @@ pub(crate) struct ExecutionPlanBuilder<'a> {
     pub sub_flow: bool,
     pub skip_tasks_pattern: Option<&'a Regex>,
     pub skip_init_end_tasks: bool,
+    pub additional_tasks: &'a [String],
 }
 
 impl<'a> ExecutionPlanBuilder<'a> {
@@ -463,6 +479,7 @@ impl<'a> ExecutionPlanBuilder<'a> {
             sub_flow: false,
             skip_tasks_pattern: None,
             skip_init_end_tasks: false,
+            additional_tasks: &[],
         }
     }
 
@@ -476,6 +493,7 @@ impl<'a> ExecutionPlanBuilder<'a> {
             sub_flow,
             skip_tasks_pattern,
             skip_init_end_tasks,
+            additional_tasks,
         } = *self;
         let mut task_names = HashSet::new();
         let mut steps = Vec::new();
@@ -502,22 +520,26 @@ impl<'a> ExecutionPlanBuilder<'a> {
             };
         }
 
-        let skip = match skip_tasks_pattern {
-            Some(pattern) => pattern.is_match(task),
-            None => false,
-        };
-
-        if !skip {
-            let workspace_flow =
-                is_workspace_flow(&config, &task, disable_workspace, &crate_info, sub_flow);
-
-            if workspace_flow {
-                let workspace_task = create_workspace_task(crate_info, task);
+        // shared dependencies of multiple tasks are only added once
+        let tasks = std::iter::once(task).chain(additional_tasks.iter().map(String::as_str));
+        let mut workspace_tasks = vec![];
+        let mut workspace_step_index = 0;
+        for task in tasks {
+            let skip = match skip_tasks_pattern {
+                Some(pattern) => pattern.is_match(task),
+                None => false,
+            };
 
-                steps.push(Step {
-                    name: "workspace".to_string(),
-                    config: workspace_task,
-                });
+            if skip {
+                debug!("Skipping task: {} due to skip pattern.", &task);
+            } else if is_workspace_flow(&config, &task, disable_workspace, &crate_info, sub_flow) {
+                // all workspace tasks are invoked in a single members invocation
+                if workspace_tasks.is_empty() {
+                    workspace_step_index = steps.len();
+                }
+                workspace_tasks.push(task);
+            } else if task_names.contains(task) {
+                debug!("Task: {} already added to the execution plan.", &task);
             } else {
                 create_for_step(
                     &config,
@@ -529,8 +551,18 @@ impl<'a> ExecutionPlanBuilder<'a> {
                     skip_tasks_pattern,
                 )?;
             }
-        } else {
-            debug!("Skipping task: {} due to skip pattern.", &task);
+        }
+
+        if !workspace_tasks.is_empty() {
+            let workspace_task = create_workspace_task(crate_info, &workspace_tasks);
+
+            steps.insert(
+                workspace_step_index,
+                Step {
+                    name: "workspace".to_string(),
+                    config: workspace_task,
+                },
+            );
         }
 
         if !skip_init_end_tasks {
@@ -13,6 +13,7 @@ fn get_actual_task_name_not_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let name = get_actual_task_name(&config, "test");
@@ -35,6 +36,7 @@ fn get_actual_task_name_no_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("test".to_string(), Task::new());
@@ -53,6 +55,7 @@ fn get_actual_task_name_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -76,6 +79,7 @@ fn get_actual_task_name_alias_self_referential() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -95,6 +99,7 @@ fn get_actual_task_name_alias_circular() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task_a = Task::new();
@@ -118,6 +123,7 @@ fn get_actual_task_name_platform_alias() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -174,7 +180,7 @@ fn create_workspace_task_no_members() {
         package: None,
     });
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     assert!(task.script.is_some());
     let script = match task.script.unwrap() {
@@ -203,7 +209,7 @@ fn create_workspace_task_with_members() {
 
     envmnt::remove("CARGO_MAKE_USE_WORKSPACE_PROFILE");
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     let mut expected_script = r#"workspace_directory = pwd
 cd ./member1
@@ -250,7 +256,7 @@ fn create_workspace_task_with_members_no_workspace_profile() {
 
     envmnt::set_bool("CARGO_MAKE_USE_WORKSPACE_PROFILE", false);
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     let mut expected_script = r#"workspace_directory = pwd
 cd ./member1
@@ -299,7 +305,7 @@ fn create_workspace_task_with_members_and_arguments() {
         &vec!["arg1".to_string(), "arg2".to_string()],
     );
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     envmnt::remove("CARGO_MAKE_TASK_ARGS");
 
@@ -358,7 +364,7 @@ fn create_workspace_task_with_included_members() {
 
     profile::set(&profile::default_profile());
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
 
@@ -413,7 +419,7 @@ fn create_workspace_task_with_included_and_skipped_members() {
 
     profile::set(&profile::default_profile());
 
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
 
     envmnt::remove("CARGO_MAKE_WORKSPACE_INCLUDE_MEMBERS");
     envmnt::remove("CARGO_MAKE_WORKSPACE_SKIP_MEMBERS");
@@ -449,7 +455,7 @@ fn create_workspace_task_extend_workspace_makefile() {
     });
 
     envmnt::set("CARGO_MAKE_EXTEND_WORKSPACE_MAKEFILE", "true");
-    let task = create_workspace_task(&crate_info, "some_task");
+    let task = create_workspace_task(&crate_info, &["some_task"]);
     envmnt::set("CARGO_MAKE_EXTEND_WORKSPACE_MAKEFILE", "false");
 
     assert!(task.script.is_some());
@@ -486,6 +492,7 @@ fn is_workspace_flow_true_default() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -517,6 +524,7 @@ fn is_workspace_flow_false_in_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -548,6 +556,7 @@ fn is_workspace_flow_true_in_config() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -577,6 +586,7 @@ fn is_workspace_flow_true_in_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -605,6 +615,7 @@ fn is_workspace_flow_default_false_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -634,6 +645,7 @@ fn is_workspace_flow_true_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -663,6 +675,7 @@ fn is_workspace_flow_false_in_task_and_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -689,6 +702,7 @@ fn is_workspace_flow_task_not_defined() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let workspace_flow = is_workspace_flow(&config, "notfound", false, &crate_info, false);
@@ -710,6 +724,7 @@ fn is_workspace_flow_no_workspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -739,6 +754,7 @@ fn is_workspace_flow_disabled_via_cli() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -768,6 +784,7 @@ fn is_workspace_flow_disabled_via_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("test".to_string(), task);
 
@@ -788,6 +805,7 @@ fn create_single() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -821,6 +839,7 @@ fn create_single_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -855,6 +874,7 @@ fn create_single_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -882,6 +902,7 @@ fn create_single_allow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -916,6 +937,7 @@ fn create_with_dependencies() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -956,6 +978,7 @@ fn create_with_foreign_dependencies_directory() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1004,6 +1027,7 @@ fn create_with_foreign_dependencies_filename() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1052,6 +1076,7 @@ fn create_with_foreign_dependencies_file_and_directory() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1100,6 +1125,7 @@ fn create_with_dependencies_sub_flow() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1139,6 +1165,7 @@ fn create_disabled_task_with_dependencies() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1178,6 +1205,7 @@ fn create_with_dependencies_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1215,6 +1243,7 @@ fn create_platform_disabled() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -1246,6 +1275,7 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        vars: None,
     });
     task.windows = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1275,6 +1305,7 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        vars: None,
     });
     task.mac = Some(PlatformOverrideTask {
         clear: Some(true),
@@ -1304,6 +1335,7 @@ fn create_platform_disabled() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        vars: None,
     });
 
     config.tasks.insert("test".to_string(), task);
@@ -1329,6 +1361,7 @@ fn create_with_dependencies_and_skip_filter() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1371,6 +1404,7 @@ fn create_workspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let task = Task::new();
@@ -1401,6 +1435,7 @@ fn create_noworkspace() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let task = Task::new();
@@ -1425,7 +1460,7 @@ fn create_noworkspace() {
 #[test]
 #[ignore]
 fn create_task_extends_empty_env_bug_verification() {
-    let config = descriptor::load(
+    let (config, _) = descriptor::load(
         "./src/lib/test/makefiles/task_extend.toml",
         true,
         None,
@@ -1548,6 +1583,7 @@ fn get_normalized_task_multi_extend() {
         run_task: None,
         dependencies: None,
         toolchain: None,
+        vars: None,
     };
 
     let mut task2 = Task::new();
@@ -1569,6 +1605,7 @@ fn get_normalized_task_multi_extend() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("1".to_string(), task1);
     config.tasks.insert("2".to_string(), task2);
@@ -1599,6 +1636,7 @@ fn get_normalized_task_simple() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.tasks.insert("1".to_string(), task1);
 
@@ -1622,6 +1660,7 @@ fn respect_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     config.tasks.insert("init".to_string(), Task::new());
@@ -1641,3 +1680,148 @@ fn respect_skip_init_end_tasks() {
     assert_eq!(execution_plan.steps.len(), 1);
     assert_eq!(execution_plan.steps[0].name, "test");
 }
+
+fn create_multiple_tasks_config() -> Config {
+    let mut config_section = ConfigSection::new();
+    config_section.init_task = Some("init".to_string());
+    config_section.end_task = Some("end".to_string());
+    let mut config = Config {
+        config: config_section,
+        env_files: vec![],
+        env: IndexMap::new(),
+        env_scripts: vec![],
+        tasks: IndexMap::new(),
+        plugins: None,
+        vars: IndexMap::new(),
+    };
+
+    config.tasks.insert("init".to_string(), Task::new());
+    config.tasks.insert("end".to_string(), Task::new());
+    config.tasks.insert("shared".to_string(), Task::new());
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["shared".into()]);
+    config.tasks.insert("build".to_string(), task);
+
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["shared".into(), "build".into()]);
+    config.tasks.insert("test".to_string(), task);
+
+    config
+}
+
+#[test]
+fn create_multiple_tasks() {
+    let config = create_multiple_tasks_config();
+    let additional_tasks = vec!["test".to_string()];
+
+    let execution_plan = ExecutionPlanBuilder {
+        additional_tasks: &additional_tasks,
+        ..ExecutionPlanBuilder::new(&config, "build")
+    }
+    .build()
+    .unwrap();
+    let names: Vec<&str> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["init", "shared", "build", "test", "end"]);
+}
+
+#[test]
+fn create_multiple_tasks_already_in_plan() {
+    let config = create_multiple_tasks_config();
+    let additional_tasks = vec!["build".to_string(), "shared".to_string()];
+
+    let execution_plan = ExecutionPlanBuilder {
+        additional_tasks: &additional_tasks,
+        ..ExecutionPlanBuilder::new(&config, "test")
+    }
+    .build()
+    .unwrap();
+    let names: Vec<&str> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["init", "shared", "build", "test", "end"]);
+}
+
+#[test]
+fn create_multiple_tasks_skip_init_end_tasks() {
+    let config = create_multiple_tasks_config();
+    let additional_tasks = vec!["shared".to_string(), "test".to_string()];
+
+    let execution_plan = ExecutionPlanBuilder {
+        additional_tasks: &additional_tasks,
+        skip_init_end_tasks: true,
+        ..ExecutionPlanBuilder::new(&config, "build")
+    }
+    .build()
+    .unwrap();
+    let names: Vec<&str> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["shared", "build", "test"]);
+}
+
+#[test]
+fn create_workspace_task_multiple_tasks() {
+    let mut crate_info = CrateInfo::new();
+    crate_info.workspace = Some(Workspace {
+        members: Some(vec!["member1".to_string()]),
+        exclude: None,
+        dependencies: None,
+        package: None,
+    });
+
+    let task = create_workspace_task(&crate_info, &["build", "test"]);
+
+    let script = match task.script.unwrap() {
+        ScriptValue::Text(value) => value,
+        _ => panic!("Invalid script value type."),
+    };
+    assert_eq!(script.len(), 4);
+    assert!(script[2].contains(" --tasks=build,test --"));
+    assert!(!script[2].contains(" -- build"));
+}
+
+#[test]
+fn create_multiple_tasks_workspace() {
+    let mut config = create_multiple_tasks_config();
+    let mut task = Task::new();
+    task.workspace = Some(false);
+    config.tasks.insert("local".to_string(), task);
+    let additional_tasks = vec!["local".to_string(), "test".to_string()];
+
+    let mut crate_info = CrateInfo::new();
+    crate_info.workspace = Some(Workspace {
+        members: Some(vec!["member1".to_string()]),
+        exclude: None,
+        dependencies: None,
+        package: None,
+    });
+
+    let execution_plan = ExecutionPlanBuilder {
+        additional_tasks: &additional_tasks,
+        crate_info: Some(&crate_info),
+        ..ExecutionPlanBuilder::new(&config, "build")
+    }
+    .build()
+    .unwrap();
+    let names: Vec<&str> = execution_plan
+        .steps
+        .iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["init", "workspace", "local", "end"]);
+
+    let script = match execution_plan.steps[1].config.script {
+        Some(ScriptValue::Text(ref value)) => value.join("\n"),
+        _ => panic!("Invalid script value type."),
+    };
+    assert!(script.contains(" --tasks=build,test --"));
+}
@@ -10,6 +10,7 @@ mod cargo_plugin_installer_test;
 use crate::command;
 use crate::error::CargoMakeError;
 use crate::installer::crate_version_check;
+use crate::process_env;
 use crate::toolchain::wrap_command;
 use crate::types::ToolchainSpecifier;
 use std::process::Command;
@@ -76,6 +77,7 @@ fn is_crate_installed(
         }
         None => Command::new("cargo"),
     };
+    process_env::apply_to_command(&mut command_struct);
 
     let result = command_struct.arg("--list").output();
 
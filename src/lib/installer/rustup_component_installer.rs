@@ -8,6 +8,7 @@
 mod rustup_component_installer_test;
 
 use crate::command;
+use crate::process_env;
 use crate::toolchain::{get_channel, wrap_command};
 use crate::types::{InstallRustupComponentInfo, ToolchainSpecifier};
 use std::process::Command;
@@ -27,6 +28,7 @@ pub(crate) fn is_installed(
         }
         None => Command::new(binary),
     };
+    process_env::apply_to_command(&mut command_struct);
 
     debug!(
         "Validating installation using command: {} args: {:#?}",
@@ -60,6 +62,7 @@ pub(crate) fn invoke_rustup_install(
     info: &InstallRustupComponentInfo,
 ) -> bool {
     let mut command_spec = Command::new("rustup");
+    process_env::apply_to_command(&mut command_spec);
     command_spec.arg("component");
     command_spec.arg("add");
 
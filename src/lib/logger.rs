@@ -8,6 +8,7 @@
 mod logger_test;
 
 use crate::recursion_level;
+use crate::secrets;
 use crate::types::FlowInfo;
 use colored::{ColoredString, Colorize};
 use log::{Level, LevelFilter};
@@ -153,10 +154,11 @@ pub(crate) fn init(options: &LoggerOptions) {
             }
 
             let record_level_fmt = get_formatted_log_level(&record_level, color);
+            let masked_message = secrets::mask(&message.to_string());
 
             out.finish(format_args!(
                 "[{}]{} {} - {}",
-                &name_fmt, &recursion_level_log, &record_level_fmt, &message
+                &name_fmt, &recursion_level_log, &record_level_fmt, &masked_message
             ));
 
             if record_level == Level::Error {
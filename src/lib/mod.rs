@@ -69,11 +69,13 @@ mod io;
 mod legacy;
 pub mod logger;
 mod plugin;
+mod process_env;
 mod profile;
 mod proxy_task;
 mod recursion_level;
 pub mod runner;
 mod scriptengine;
+mod secrets;
 mod storage;
 mod time_summary;
 mod toolchain;
@@ -60,6 +60,7 @@ fn get_plugin_no_plugins_config() {
             env_scripts: vec![],
             tasks: IndexMap::new(),
             plugins: None,
+            vars: IndexMap::new(),
         },
         "test",
     );
@@ -80,6 +81,7 @@ fn get_plugin_not_found() {
                 aliases: None,
                 plugins: IndexMap::new(),
             }),
+            vars: IndexMap::new(),
         },
         "test",
     );
@@ -108,6 +110,7 @@ fn get_plugin_found() {
                 aliases: None,
                 plugins,
             }),
+            vars: IndexMap::new(),
         },
         "test",
     );
@@ -141,6 +144,7 @@ fn get_plugin_found_with_alias() {
                 aliases: Some(aliases),
                 plugins,
             }),
+            vars: IndexMap::new(),
         },
         "test",
     );
@@ -454,6 +458,8 @@ fn run_task_invoked_valid() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("extended".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -473,6 +479,10 @@ fn run_task_invoked_valid() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: None,
@@ -501,6 +511,8 @@ fn run_task_invoked_valid() {
         linux: None,
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let mut flow_info = create_empty_flow_info();
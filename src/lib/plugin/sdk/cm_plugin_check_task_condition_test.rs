@@ -51,6 +51,10 @@ fn run_valid() {
         files_exist: None,
         files_not_exist: None,
         files_modified: None,
+        crate_has_feature: None,
+        crate_has_dependency: None,
+        crate_has_target: None,
+        crate_is_workspace_member: None,
     });
 
     let mut flow_info = create_empty_flow_info();
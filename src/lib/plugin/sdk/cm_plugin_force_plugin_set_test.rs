@@ -63,6 +63,7 @@ set_env FORCE_PLUGIN_SET_AND_CLEAR_FLOW_TEST_SET_4 1
     let flow_info = FlowInfo {
         config,
         task: "force_flow".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -75,6 +76,7 @@ set_env FORCE_PLUGIN_SET_AND_CLEAR_FLOW_TEST_SET_4 1
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     assert!(!envmnt::exists("FORCE_PLUGIN_SET_AND_CLEAR_FLOW_TEST_SET"));
@@ -23,8 +23,9 @@ fn run_valid() {
 
                 cm_plugin_run_custom_task ${task_json}
 
+                # task env is restored once the task is done
                 value = get_env cm_plugin_run_custom_task_test_valid_env
-                assert_eq ${value} 1
+                assert_eq ${value} ""
                 value = get_env cm_plugin_run_custom_task_test_valid_script
                 assert_eq ${value} yes
 
@@ -75,10 +76,7 @@ fn run_valid() {
     );
 
     assert!(done);
-    assert!(envmnt::is_equal(
-        "cm_plugin_run_custom_task_test_valid_env",
-        "1"
-    ));
+    assert!(!envmnt::exists("cm_plugin_run_custom_task_test_valid_env"));
     assert!(envmnt::is_equal(
         "cm_plugin_run_custom_task_test_valid_script",
         "yes"
@@ -0,0 +1,229 @@
+//! # process_env
+//!
+//! Holds the env and working directory of the task running in the current thread.<br>
+//! The task env is evaluated while holding a global lock and captured as env overrides, instead
+//! of being left in the shared process env, so tasks running in parallel do not override each
+//! other values.<br>
+//! Child processes (commands and scripts) get the full task env and working directory explicitly
+//! and are spawned without holding the lock, while duckscript gets the task env in its context.
+//!
+
+#[cfg(test)]
+#[path = "process_env_test.rs"]
+mod process_env_test;
+
+use crate::environment;
+use indexmap::IndexMap;
+use std::cell::{Cell, RefCell};
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
+use std::thread;
+use std::thread::JoinHandle;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// The env and working directory of a task
+pub(crate) struct ProcessEnv {
+    /// The env overrides on top of the process env (None to remove the env variable)
+    pub(crate) env: IndexMap<String, Option<String>>,
+    /// The working directory
+    pub(crate) cwd: Option<PathBuf>,
+    /// The task makefile variables (never passed to child processes)
+    pub(crate) vars: IndexMap<String, String>,
+}
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static CURRENT: RefCell<Option<ProcessEnv>> = const { RefCell::new(None) };
+    static LOCKED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Invokes the action while holding the global env lock (nested calls in the same thread
+/// reuse the already held lock).
+fn with_lock<T, F: FnOnce() -> T>(action: F) -> T {
+    if LOCKED.with(|locked| locked.get()) {
+        return action();
+    }
+
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+    LOCKED.with(|locked| locked.set(true));
+    let output = action();
+    LOCKED.with(|locked| locked.set(false));
+
+    output
+}
+
+/// Returns the env of the task running in the current thread
+pub(crate) fn get() -> Option<ProcessEnv> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Invokes the action with the given process env set for the current thread
+pub(crate) fn run_with<T, F: FnOnce() -> T>(process_env: ProcessEnv, action: F) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(process_env)));
+    let output = action();
+    CURRENT.with(|current| current.replace(previous));
+
+    output
+}
+
+/// Returns the task makefile variable value of the task running in the current thread
+pub(crate) fn get_var(key: &str) -> Option<String> {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .and_then(|process_env| process_env.vars.get(key).cloned())
+    })
+}
+
+/// Sets the task makefile variables (None to remove the variable) of the task running in the
+/// current thread
+pub(crate) fn set_vars(vars: IndexMap<String, Option<String>>) {
+    CURRENT.with(|current| {
+        let mut current = current.borrow_mut();
+        let process_env = current.get_or_insert_with(ProcessEnv::default);
+
+        for (key, value) in vars {
+            match value {
+                Some(value) => process_env.vars.insert(key, value),
+                None => process_env.vars.shift_remove(&key),
+            };
+        }
+    });
+}
+
+/// Spawns a new thread which inherits the env of the task running in the current thread
+pub(crate) fn spawn<T, F>(action: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let process_env = get();
+
+    thread::spawn(move || match process_env {
+        Some(process_env) => run_with(process_env, action),
+        None => action(),
+    })
+}
+
+/// Applies the current thread env overrides to the process env and invokes the action
+/// while holding the global env lock.<br>
+/// Returns the action output and the process env which contains the current thread env
+/// overrides and all env variables modified by the action.<br>
+/// Once done, the current thread env overrides are removed from the process env and unless
+/// keep is true, the env modified by the action is restored as well.<br>
+/// Task makefile variables set by the action are returned as part of the process env and are
+/// not kept for the current thread.
+pub(crate) fn evaluate<T, F: FnOnce() -> T>(keep: bool, action: F) -> (T, ProcessEnv) {
+    with_lock(|| {
+        let current = get();
+        let mut process_env = current.clone().unwrap_or_default();
+
+        let overrides_previous_env =
+            environment::get_modified_env(|| environment::restore_env(&process_env.env));
+
+        let mut output = None;
+        let action_previous_env = environment::get_modified_env(|| {
+            output = Some(action());
+        });
+
+        let action_process_env = CURRENT.with(|current_env| current_env.replace(current));
+        if let Some(action_process_env) = action_process_env {
+            process_env.vars = action_process_env.vars;
+        }
+
+        for key in action_previous_env.keys() {
+            process_env.env.insert(key.to_string(), env::var(key).ok());
+        }
+        if process_env.cwd.is_none() {
+            process_env.cwd = env::current_dir().ok();
+        }
+
+        if keep {
+            let mut overrides_previous_env = overrides_previous_env;
+            overrides_previous_env.retain(|key, _| !action_previous_env.contains_key(key));
+            environment::restore_env(&overrides_previous_env);
+        } else {
+            environment::restore_env(&action_previous_env);
+            environment::restore_env(&overrides_previous_env);
+        }
+
+        match output {
+            Some(output) => (output, process_env),
+            None => panic!("env evaluation action was not invoked"),
+        }
+    })
+}
+
+/// Applies the current thread env overrides to the process env and invokes the action while
+/// holding the global env lock.<br>
+/// Used for single in process env operations (such as the duckscript env commands) which read
+/// or modify the process env directly, it must never wrap a whole script or a child process
+/// run as it blocks all other tasks from evaluating their env.<br>
+/// Env variables modified by the action itself are kept once done, both in the process env and
+/// in the current thread env overrides.
+pub(crate) fn run_in_process<T, F: FnOnce() -> T>(action: F) -> T {
+    let process_env = match get() {
+        Some(process_env) => process_env,
+        None => return with_lock(action),
+    };
+
+    with_lock(|| {
+        let previous_env =
+            environment::get_modified_env(|| environment::restore_env(&process_env.env));
+
+        let mut output = None;
+        let action_previous_env = environment::get_modified_env(|| {
+            output = Some(action());
+        });
+
+        let mut unmodified_env = previous_env;
+        unmodified_env.retain(|key, _| !action_previous_env.contains_key(key));
+        environment::restore_env(&unmodified_env);
+
+        CURRENT.with(|current| {
+            if let Some(ref mut current) = *current.borrow_mut() {
+                for key in action_previous_env.keys() {
+                    current.env.insert(key.to_string(), env::var(key).ok());
+                }
+            }
+        });
+
+        match output {
+            Some(output) => output,
+            None => panic!("in process action was not invoked"),
+        }
+    })
+}
+
+/// Returns the full env of the task running in the current thread, which is the process env
+/// (read while holding the global env lock) with the current thread env overrides applied
+pub(crate) fn get_child_env() -> IndexMap<String, String> {
+    let mut child_env: IndexMap<String, String> = with_lock(|| env::vars().collect());
+
+    if let Some(process_env) = get() {
+        for (key, value) in process_env.env {
+            match value {
+                Some(value) => child_env.insert(key, value),
+                None => child_env.shift_remove(&key),
+            };
+        }
+    }
+
+    child_env
+}
+
+/// Sets the full env and working directory of the task running in the current thread to the
+/// command, so it does not inherit env variables which are temporarily set while the env of
+/// another task is evaluated.<br>
+/// The command can be spawned without holding the global env lock.
+pub(crate) fn apply_to_command(command: &mut Command) {
+    command.env_clear().envs(get_child_env());
+
+    if let Some(cwd) = get().and_then(|process_env| process_env.cwd) {
+        command.current_dir(cwd);
+    }
+}
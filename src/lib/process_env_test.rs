@@ -0,0 +1,237 @@
+use super::*;
+
+#[test]
+fn get_none() {
+    let output = get();
+
+    assert!(output.is_none());
+}
+
+#[test]
+fn run_with_sets_and_restores() {
+    let mut process_env = ProcessEnv::default();
+    process_env.env.insert(
+        "PROCESS_ENV_TEST_RUN_WITH".to_string(),
+        Some("1".to_string()),
+    );
+
+    let output = run_with(process_env.clone(), || get());
+
+    assert_eq!(output, Some(process_env));
+    assert!(get().is_none());
+}
+
+#[test]
+fn evaluate_restore() {
+    envmnt::remove("PROCESS_ENV_TEST_EVALUATE_RESTORE");
+
+    let (output, process_env) = evaluate(false, || {
+        envmnt::set("PROCESS_ENV_TEST_EVALUATE_RESTORE", "1");
+        true
+    });
+
+    assert!(output);
+    assert!(!envmnt::exists("PROCESS_ENV_TEST_EVALUATE_RESTORE"));
+    assert_eq!(
+        process_env.env.get("PROCESS_ENV_TEST_EVALUATE_RESTORE"),
+        Some(&Some("1".to_string()))
+    );
+    assert!(process_env.cwd.is_some());
+}
+
+#[test]
+fn evaluate_keep() {
+    envmnt::remove("PROCESS_ENV_TEST_EVALUATE_KEEP");
+
+    let (_, process_env) = evaluate(true, || {
+        envmnt::set("PROCESS_ENV_TEST_EVALUATE_KEEP", "1");
+    });
+
+    assert_eq!(envmnt::get_or_panic("PROCESS_ENV_TEST_EVALUATE_KEEP"), "1");
+    assert_eq!(
+        process_env.env.get("PROCESS_ENV_TEST_EVALUATE_KEEP"),
+        Some(&Some("1".to_string()))
+    );
+
+    envmnt::remove("PROCESS_ENV_TEST_EVALUATE_KEEP");
+}
+
+#[test]
+fn evaluate_with_overrides() {
+    envmnt::remove("PROCESS_ENV_TEST_OVERRIDES_PARENT");
+    envmnt::remove("PROCESS_ENV_TEST_OVERRIDES_CHILD");
+
+    let mut parent = ProcessEnv::default();
+    parent.env.insert(
+        "PROCESS_ENV_TEST_OVERRIDES_PARENT".to_string(),
+        Some("parent".to_string()),
+    );
+
+    let (parent_value, process_env) = run_with(parent, || {
+        evaluate(false, || {
+            envmnt::set("PROCESS_ENV_TEST_OVERRIDES_CHILD", "child");
+            envmnt::get_or("PROCESS_ENV_TEST_OVERRIDES_PARENT", "")
+        })
+    });
+
+    assert_eq!(parent_value, "parent");
+    assert!(!envmnt::exists("PROCESS_ENV_TEST_OVERRIDES_PARENT"));
+    assert!(!envmnt::exists("PROCESS_ENV_TEST_OVERRIDES_CHILD"));
+    assert_eq!(
+        process_env.env.get("PROCESS_ENV_TEST_OVERRIDES_PARENT"),
+        Some(&Some("parent".to_string()))
+    );
+    assert_eq!(
+        process_env.env.get("PROCESS_ENV_TEST_OVERRIDES_CHILD"),
+        Some(&Some("child".to_string()))
+    );
+}
+
+#[test]
+fn run_in_process_overrides_applied() {
+    envmnt::remove("PROCESS_ENV_TEST_IN_PROCESS");
+    envmnt::remove("PROCESS_ENV_TEST_IN_PROCESS_SET");
+
+    let mut process_env = ProcessEnv::default();
+    process_env.env.insert(
+        "PROCESS_ENV_TEST_IN_PROCESS".to_string(),
+        Some("1".to_string()),
+    );
+
+    let output = run_with(process_env, || {
+        run_in_process(|| {
+            envmnt::set("PROCESS_ENV_TEST_IN_PROCESS_SET", "1");
+            envmnt::get_or("PROCESS_ENV_TEST_IN_PROCESS", "")
+        })
+    });
+
+    assert_eq!(output, "1");
+    assert!(!envmnt::exists("PROCESS_ENV_TEST_IN_PROCESS"));
+    assert_eq!(envmnt::get_or_panic("PROCESS_ENV_TEST_IN_PROCESS_SET"), "1");
+
+    envmnt::remove("PROCESS_ENV_TEST_IN_PROCESS_SET");
+}
+
+#[test]
+fn spawn_inherits_env() {
+    let mut process_env = ProcessEnv::default();
+    process_env
+        .env
+        .insert("PROCESS_ENV_TEST_SPAWN".to_string(), Some("1".to_string()));
+
+    let output = run_with(process_env.clone(), || spawn(get).join().unwrap());
+
+    assert_eq!(output, Some(process_env));
+}
+
+#[test]
+fn apply_to_command_env_and_cwd() {
+    let mut process_env = ProcessEnv::default();
+    process_env.env.insert(
+        "PROCESS_ENV_TEST_COMMAND".to_string(),
+        Some("1".to_string()),
+    );
+    process_env
+        .env
+        .insert("PROCESS_ENV_TEST_COMMAND_REMOVED".to_string(), None);
+    process_env.cwd = Some(PathBuf::from("./src"));
+
+    let mut command = Command::new("echo");
+    run_with(process_env, || apply_to_command(&mut command));
+
+    let envs: Vec<_> = command.get_envs().collect();
+    assert!(envs.contains(&(
+        std::ffi::OsStr::new("PROCESS_ENV_TEST_COMMAND"),
+        Some(std::ffi::OsStr::new("1"))
+    )));
+    assert!(!envs
+        .iter()
+        .any(|(key, _)| *key == std::ffi::OsStr::new("PROCESS_ENV_TEST_COMMAND_REMOVED")));
+    assert!(envs
+        .iter()
+        .any(|(key, _)| *key == std::ffi::OsStr::new("PATH")));
+    assert_eq!(
+        command.get_current_dir(),
+        Some(PathBuf::from("./src").as_path())
+    );
+}
+
+#[test]
+fn apply_to_command_no_env() {
+    let mut command = Command::new("echo");
+    apply_to_command(&mut command);
+
+    assert_eq!(command.get_envs().count(), env::vars().count());
+    assert!(command.get_current_dir().is_none());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn command_output_with_env() {
+    let mut process_env = ProcessEnv::default();
+    process_env.env.insert(
+        "PROCESS_ENV_TEST_COMMAND_OUTPUT".to_string(),
+        Some("test".to_string()),
+    );
+
+    let mut command = Command::new("sh");
+    command.args(["-c", "echo $PROCESS_ENV_TEST_COMMAND_OUTPUT"]);
+    run_with(process_env, || apply_to_command(&mut command));
+
+    let output = command.output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "test");
+}
+
+#[test]
+fn run_in_process_modified_env_kept_in_overrides() {
+    envmnt::remove("PROCESS_ENV_TEST_IN_PROCESS_KEPT");
+
+    let mut process_env = ProcessEnv::default();
+    process_env.env.insert(
+        "PROCESS_ENV_TEST_IN_PROCESS_KEPT".to_string(),
+        Some("1".to_string()),
+    );
+
+    let output = run_with(process_env, || {
+        run_in_process(|| envmnt::set("PROCESS_ENV_TEST_IN_PROCESS_KEPT", "2"));
+
+        get_child_env()
+            .get("PROCESS_ENV_TEST_IN_PROCESS_KEPT")
+            .cloned()
+    });
+
+    assert_eq!(output, Some("2".to_string()));
+    assert_eq!(
+        envmnt::get_or_panic("PROCESS_ENV_TEST_IN_PROCESS_KEPT"),
+        "2"
+    );
+
+    envmnt::remove("PROCESS_ENV_TEST_IN_PROCESS_KEPT");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn command_output_with_removed_env() {
+    envmnt::set("PROCESS_ENV_TEST_COMMAND_OUTPUT_REMOVED", "1");
+
+    let mut process_env = ProcessEnv::default();
+    process_env
+        .env
+        .insert("PROCESS_ENV_TEST_COMMAND_OUTPUT_REMOVED".to_string(), None);
+
+    let mut command = Command::new("sh");
+    command.args([
+        "-c",
+        "echo ${PROCESS_ENV_TEST_COMMAND_OUTPUT_REMOVED:-removed}",
+    ]);
+    run_with(process_env, || apply_to_command(&mut command));
+
+    envmnt::remove("PROCESS_ENV_TEST_COMMAND_OUTPUT_REMOVED");
+
+    let output = command.output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "removed");
+}
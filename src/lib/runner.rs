@@ -14,7 +14,6 @@ mod runner_test;
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::thread;
 use std::time::SystemTime;
 
 use indexmap::IndexMap;
@@ -29,63 +28,89 @@ use crate::functions;
 use crate::installer;
 use crate::logger;
 use crate::plugin::runner::run_task as run_task_plugin;
+use crate::process_env;
 use crate::profile;
 use crate::proxy_task::create_proxy_task;
 use crate::scriptengine;
 use crate::time_summary;
 use crate::types::{
-    CliArgs, Config, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo, FlowState,
-    MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step, Task,
-    TaskWatchOptions,
+    CliArgs, ConditionVerdict, Config, DeprecationInfo, EnvInfo, EnvValue, ExecutionPlan, FlowInfo,
+    FlowState, MaybeArray, RunTaskInfo, RunTaskName, RunTaskOptions, RunTaskRoutingInfo, Step,
+    Task, TaskWatchOptions,
 };
 
 fn do_in_task_working_directory<F>(step: &Step, mut action: F) -> Result<(), CargoMakeError>
 where
     F: FnMut() -> Result<bool, CargoMakeError>,
 {
-    let revert_directory = match step.config.cwd {
-        Some(ref cwd) => {
-            let expanded_cwd = environment::expand_value(cwd);
+    let expanded_cwd = match step.config.cwd {
+        Some(ref cwd) => environment::expand_value(cwd),
+        None => "".to_string(),
+    };
 
-            if expanded_cwd.len() > 0 {
-                let directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
+    if expanded_cwd.len() > 0 {
+        let directory = envmnt::get_or("CARGO_MAKE_WORKING_DIRECTORY", "");
 
-                environment::setup_cwd(Some(&expanded_cwd));
+        // the task working directory is passed explicitly to the child processes
+        let (cwd, mut cwd_process_env) = process_env::evaluate(true, || {
+            environment::setup_cwd(Some(&expanded_cwd));
+            std::env::current_dir().ok()
+        });
+        cwd_process_env.cwd = cwd;
 
-                directory
-            } else {
-                "".to_string()
-            }
-        }
-        None => "".to_string(),
-    };
+        let output = process_env::run_with(cwd_process_env, &mut action);
 
-    action()?;
+        // revert to original cwd
+        process_env::evaluate(true, || environment::setup_cwd(Some(&directory)));
+
+        output?;
+    } else {
+        action()?;
+    }
 
-    // revert to original cwd
-    match step.config.cwd {
-        Some(_) => {
-            environment::setup_cwd(Some(&revert_directory));
-        }
-        _ => (),
-    };
     Ok(())
 }
 
-pub(crate) fn validate_condition(
+/// Evaluates the step condition in the task working directory.<br>
+/// The condition script is only invoked if evaluate_script is true.
+pub(crate) fn evaluate_condition(
     flow_info: &FlowInfo,
     step: &Step,
-) -> Result<bool, CargoMakeError> {
-    let mut valid = true;
+    evaluate_script: bool,
+) -> Result<ConditionVerdict, CargoMakeError> {
+    let mut verdict = ConditionVerdict {
+        passed: true,
+        reasons: vec![],
+        script_skipped: false,
+    };
 
     let do_validate = || -> Result<bool, CargoMakeError> {
-        valid = condition::validate_condition_for_step(&flow_info, &step)?;
-        Ok(valid)
+        verdict = condition::evaluate_condition_for_step(&flow_info, &step, evaluate_script)?;
+        Ok(verdict.passed)
     };
 
     do_in_task_working_directory(&step, do_validate)?;
 
-    Ok(valid)
+    Ok(verdict)
+}
+
+pub(crate) fn validate_condition(
+    flow_info: &FlowInfo,
+    step: &Step,
+) -> Result<bool, CargoMakeError> {
+    let verdict = evaluate_condition(flow_info, step, true)?;
+
+    Ok(verdict.passed)
+}
+
+fn print_condition_verdict(verdict: &ConditionVerdict) {
+    if verdict.reasons.is_empty() {
+        info!("  - no conditions defined");
+    } else {
+        for reason in &verdict.reasons {
+            info!("  - {}", reason);
+        }
+    }
 }
 
 pub(crate) fn get_sub_task_info_for_routing_info(
@@ -122,14 +147,25 @@ pub(crate) fn get_sub_task_info_for_routing_info(
     Ok((task_name, fork, parallel, cleanup_task))
 }
 
+/// Returns the task and arguments used to invoke the flow tasks from a proxy task.<br>
+/// Multiple tasks are passed via the --tasks flag.
+fn get_proxy_task_and_arguments(flow_info: &FlowInfo) -> (String, Option<Vec<String>>) {
+    if flow_info.additional_tasks.is_empty() {
+        (flow_info.task.clone(), flow_info.cli_arguments.clone())
+    } else {
+        let mut tasks = vec![flow_info.task.clone()];
+        tasks.extend(flow_info.additional_tasks.iter().cloned());
+
+        let mut arguments = vec!["--".to_string()];
+        arguments.extend(flow_info.cli_arguments.clone().unwrap_or_default());
+
+        (format!("--tasks={}", tasks.join(",")), Some(arguments))
+    }
+}
+
 fn create_fork_step(flow_info: &FlowInfo) -> Step {
-    let fork_task = create_proxy_task(
-        &flow_info.task,
-        true,
-        true,
-        None,
-        flow_info.cli_arguments.clone(),
-    );
+    let (task, arguments) = get_proxy_task_and_arguments(flow_info);
+    let fork_task = create_proxy_task(&task, true, true, None, arguments);
 
     Step {
         name: "cargo_make_run_fork".to_string(),
@@ -225,6 +261,7 @@ fn run_sub_task_and_report(
                   -> Result<(), CargoMakeError> {
                 let mut sub_flow_info = flow_info.clone();
                 sub_flow_info.task = name;
+                sub_flow_info.additional_tasks = vec![];
 
                 if fork {
                     run_forked_task(&sub_flow_info, flow_state, cleanup_task)
@@ -238,7 +275,7 @@ fn run_sub_task_and_report(
                 // we do not support merging changes back to parent
                 let cloned_flow_state = flow_state.borrow().clone();
                 let cloned_cleanup_task = cleanup_task.clone();
-                threads.push(thread::spawn(move || -> Result<(), CargoMakeError> {
+                threads.push(process_env::spawn(move || -> Result<(), CargoMakeError> {
                     task_run_fn(
                         &run_flow_info,
                         Rc::new(RefCell::new(cloned_flow_state)),
@@ -337,6 +374,71 @@ pub(crate) fn run_task(
     run_task_with_options(flow_info, flow_state, step, &options)
 }
 
+fn set_current_task_env(step: &Step) {
+    match step.config.env {
+        Some(ref env) => environment::set_current_task_meta_info_env(env.clone()),
+        None => (),
+    };
+    envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+}
+
+fn run_task_actions(
+    flow_info: &FlowInfo,
+    flow_state: Rc<RefCell<FlowState>>,
+    step: &Step,
+    updated_step: &Step,
+    start_time: SystemTime,
+) -> Result<(), CargoMakeError> {
+    let watch = should_watch(&step.config);
+
+    if watch {
+        watch_task(
+            &flow_info,
+            flow_state,
+            &step.name,
+            step.config.watch.clone(),
+        )?;
+    } else {
+        do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
+            installer::install(&updated_step.config, flow_info, flow_state.clone())?;
+            Ok(true)
+        })?;
+
+        match step.config.run_task {
+            Some(ref sub_task) => {
+                time_summary::add(
+                    &mut flow_state.borrow_mut().time_summary,
+                    &step.name,
+                    start_time,
+                );
+
+                run_sub_task(&flow_info, flow_state, sub_task)?;
+            }
+            None => {
+                do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
+                    // run script
+                    let script_runner_done =
+                        scriptengine::invoke(&updated_step.config, flow_info, flow_state.clone())?;
+
+                    // run command
+                    if !script_runner_done {
+                        command::run(&updated_step)?;
+                    };
+                    Ok(true)
+                })?;
+
+                time_summary::add(
+                    &mut flow_state.borrow_mut().time_summary,
+                    &step.name,
+                    start_time,
+                );
+            }
+        };
+    }
+
+    Ok(())
+}
+
 pub(crate) fn run_task_with_options(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
@@ -356,22 +458,31 @@ pub(crate) fn run_task_with_options(
     }
 
     if step.config.is_actionable() {
-        match step.config.env {
-            Some(ref env) => environment::set_current_task_meta_info_env(env.clone()),
-            None => (),
-        };
-        envmnt::set("CARGO_MAKE_CURRENT_TASK_NAME", &step.name);
+        let (verdict, _) = process_env::evaluate(false, || {
+            set_current_task_env(step);
 
-        if validate_condition(
-            &flow_info,
-            &environment::expand_condition_script_runner_arguments(&step),
-        )? {
-            if logger::should_reduce_output(&flow_info) && step.config.script.is_none() {
+            evaluate_condition(
+                &flow_info,
+                &environment::expand_condition_script_runner_arguments(&step),
+                true,
+            )
+        });
+        let verdict = verdict?;
+
+        if verdict.passed {
+            if logger::should_reduce_output(&flow_info)
+                && step.config.script.is_none()
+                && !flow_info.why
+            {
                 debug!("Running Task: {}", &step.name);
             } else {
                 info!("Running Task: {}", &step.name);
             }
 
+            if flow_info.why {
+                print_condition_verdict(&verdict);
+            }
+
             if !step.config.is_valid() {
                 error!(
                     "Invalid task: {}, contains multiple actions.\n{:#?}",
@@ -401,71 +512,35 @@ pub(crate) fn run_task_with_options(
             //get profile
             let profile_name = profile::get();
 
-            match step.config.env_files {
-                Some(ref env_files) => environment::set_env_files(env_files.clone()),
-                None => (),
-            };
-            match step.config.env {
-                Some(ref env) => environment::set_env(env.clone()),
-                None => (),
-            };
-
-            //make sure profile env is not overwritten
-            profile::set(&profile_name);
-
-            // modify step using env and functions
-            let mut updated_step = functions::run(&step)?;
-            updated_step = environment::expand_env(&updated_step);
+            // task env is scoped to the task unless explicitly exported to the next tasks
+            let (updated_step, task_process_env) =
+                process_env::evaluate(step.config.should_export_env(), || {
+                    set_current_task_env(step);
 
-            let watch = should_watch(&step.config);
-
-            if watch {
-                watch_task(
-                    &flow_info,
-                    flow_state,
-                    &step.name,
-                    step.config.watch.clone(),
-                )?;
-            } else {
-                do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
-                    installer::install(&updated_step.config, flow_info, flow_state.clone())?;
-                    Ok(true)
-                })?;
-
-                match step.config.run_task {
-                    Some(ref sub_task) => {
-                        time_summary::add(
-                            &mut flow_state.borrow_mut().time_summary,
-                            &step.name,
-                            start_time,
-                        );
-
-                        run_sub_task(&flow_info, flow_state, sub_task)?;
+                    if let Some(ref vars) = step.config.vars {
+                        environment::set_task_vars(vars.clone());
                     }
-                    None => {
-                        do_in_task_working_directory(&step, || -> Result<bool, CargoMakeError> {
-                            // run script
-                            let script_runner_done = scriptengine::invoke(
-                                &updated_step.config,
-                                flow_info,
-                                flow_state.clone(),
-                            )?;
-
-                            // run command
-                            if !script_runner_done {
-                                command::run(&updated_step)?;
-                            };
-                            Ok(true)
-                        })?;
-
-                        time_summary::add(
-                            &mut flow_state.borrow_mut().time_summary,
-                            &step.name,
-                            start_time,
-                        );
-                    }
-                };
-            }
+                    match step.config.env_files {
+                        Some(ref env_files) => environment::set_env_files(env_files.clone()),
+                        None => (),
+                    };
+                    match step.config.env {
+                        Some(ref env) => environment::set_env(env.clone()),
+                        None => (),
+                    };
+
+                    //make sure profile env is not overwritten
+                    profile::set(&profile_name);
+
+                    // modify step using env and functions
+                    functions::run(&step).map(|updated_step| environment::expand_env(&updated_step))
+                });
+            let updated_step = updated_step?;
+
+            // the task env is passed explicitly to the child processes
+            process_env::run_with(task_process_env, || {
+                run_task_actions(flow_info, flow_state, step, &updated_step, start_time)
+            })?;
         } else {
             let fail_message = match step.config.condition {
                 Some(ref condition) => match condition.fail_message {
@@ -475,11 +550,18 @@ pub(crate) fn run_task_with_options(
                 None => "".to_string(),
             };
 
-            if logger::should_reduce_output(&flow_info) && !step.config.is_actionable() {
+            if logger::should_reduce_output(&flow_info)
+                && !step.config.is_actionable()
+                && !flow_info.why
+            {
                 debug!("Skipping Task: {} {}", &step.name, &fail_message);
             } else {
                 info!("Skipping Task: {} {}", &step.name, &fail_message);
             }
+
+            if flow_info.why {
+                print_condition_verdict(&verdict);
+            }
         }
     } else {
         debug!("Ignoring Empty Task: {}", &step.name);
@@ -607,6 +689,7 @@ pub(crate) fn run_flow(
         sub_flow,
         skip_tasks_pattern: flow_info.skip_tasks_pattern.as_ref(),
         skip_init_end_tasks: flow_info.skip_init_end_tasks,
+        additional_tasks: &flow_info.additional_tasks,
         ..ExecutionPlanBuilder::new(&flow_info.config, &flow_info.task)
     }
     .build()?;
@@ -621,12 +704,13 @@ fn run_protected_flow(
     flow_info: &FlowInfo,
     flow_state: Rc<RefCell<FlowState>>,
 ) -> Result<(), CargoMakeError> {
+    let (task, arguments) = get_proxy_task_and_arguments(flow_info);
     let proxy_task = create_proxy_task(
-        &flow_info.task,
+        &task,
         flow_info.allow_private,
         flow_info.skip_init_end_tasks,
         None,
-        flow_info.cli_arguments.clone(),
+        arguments,
     );
 
     let exit_code = command::run_command(&proxy_task.command.unwrap(), &proxy_task.args, false)?;
@@ -637,6 +721,7 @@ fn run_protected_flow(
                 let mut error_flow_info = flow_info.clone();
                 error_flow_info.disable_on_error = true;
                 error_flow_info.task = on_error_task.clone();
+                error_flow_info.additional_tasks = vec![];
 
                 run_flow(&error_flow_info, flow_state, false)?;
             }
@@ -648,21 +733,13 @@ fn run_protected_flow(
     Ok(())
 }
 
-/// Runs the requested tasks.<br>
-/// The flow is as follows:
-///
-/// * Create an execution plan based on the requested task and its dependencies
-/// * Run all tasks defined in the execution plan
-pub fn run(
+/// Creates the flow info based on the loaded config and the cli arguments.
+pub(crate) fn create_flow_info(
     config: Config,
     task: &str,
     env_info: EnvInfo,
     cli_args: &CliArgs,
-    start_time: SystemTime,
-    time_summary_vec: Vec<(String, u128)>,
-) -> Result<(), CargoMakeError> {
-    time_summary::init(&config, &cli_args);
-
+) -> FlowInfo {
     let skip_tasks_pattern = match cli_args.skip_tasks_pattern {
         Some(ref pattern) => match Regex::new(pattern) {
             Ok(reg) => Some(reg),
@@ -674,9 +751,10 @@ pub fn run(
         None => None,
     };
 
-    let flow_info = FlowInfo {
+    FlowInfo {
         config,
         task: task.to_string(),
+        additional_tasks: cli_args.additional_tasks.clone(),
         env_info,
         disable_workspace: cli_args.disable_workspace,
         disable_on_error: cli_args.disable_on_error,
@@ -684,7 +762,26 @@ pub fn run(
         skip_init_end_tasks: cli_args.skip_init_end_tasks,
         skip_tasks_pattern,
         cli_arguments: cli_args.arguments.clone(),
-    };
+        why: cli_args.why,
+    }
+}
+
+/// Runs the requested tasks.<br>
+/// The flow is as follows:
+///
+/// * Create an execution plan based on the requested task and its dependencies
+/// * Run all tasks defined in the execution plan
+pub fn run(
+    config: Config,
+    task: &str,
+    env_info: EnvInfo,
+    cli_args: &CliArgs,
+    start_time: SystemTime,
+    time_summary_vec: Vec<(String, u128)>,
+) -> Result<(), CargoMakeError> {
+    time_summary::init(&config, &cli_args);
+
+    let flow_info = create_flow_info(config, task, env_info, cli_args);
     let mut flow_state = FlowState::new();
     flow_state.time_summary = time_summary_vec;
 
@@ -24,6 +24,7 @@ fn run_flow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -34,6 +35,7 @@ fn run_flow_private() {
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -46,6 +48,7 @@ fn run_flow_private() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
@@ -61,6 +64,7 @@ fn run_flow_private_skipped() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -71,6 +75,7 @@ fn run_flow_private_skipped() {
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -83,6 +88,7 @@ fn run_flow_private_skipped() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: Some(Regex::new("test").unwrap()),
         cli_arguments: None,
+        why: false,
     };
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
@@ -98,6 +104,7 @@ fn run_flow_private_sub_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -108,6 +115,7 @@ fn run_flow_private_sub_task() {
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -120,6 +128,7 @@ fn run_flow_private_sub_task() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), true).unwrap();
@@ -135,6 +144,7 @@ fn run_flow_allow_private() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
 
     let mut task = Task::new();
@@ -145,6 +155,7 @@ fn run_flow_allow_private() {
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -157,6 +168,7 @@ fn run_flow_allow_private() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
@@ -173,10 +185,12 @@ fn run_task_bad_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -189,6 +203,7 @@ fn run_task_bad_script() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -213,10 +228,12 @@ fn run_task_script_with_args_error() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -229,6 +246,7 @@ fn run_task_script_with_args_error() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: Some(vec!["1".to_string()]),
+        why: false,
     };
 
     let mut task = Task::new();
@@ -252,10 +270,12 @@ fn run_task_script_with_args_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -268,6 +288,7 @@ fn run_task_script_with_args_valid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: Some(vec!["0".to_string()]),
+        why: false,
     };
 
     let mut task = Task::new();
@@ -290,10 +311,12 @@ fn run_task_command() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -306,6 +329,7 @@ fn run_task_command() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -352,10 +376,12 @@ fn run_task_bad_command_valid_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -368,6 +394,7 @@ fn run_task_bad_command_valid_script() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -391,10 +418,12 @@ fn run_task_no_command_valid_script() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -407,6 +436,7 @@ fn run_task_no_command_valid_script() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -436,10 +466,12 @@ fn run_task_bad_run_task_valid_command() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -452,6 +484,7 @@ fn run_task_bad_run_task_valid_command() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -482,10 +515,12 @@ fn run_task_valid_run_task() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -498,6 +533,7 @@ fn run_task_valid_run_task() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -521,10 +557,12 @@ fn run_task_invalid_task() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -537,6 +575,7 @@ fn run_task_invalid_task() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -560,10 +599,12 @@ fn run_task_set_env_file() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -576,6 +617,7 @@ fn run_task_set_env_file() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let env_data = envmnt::parse_file("./src/lib/test/test_files/env.env").unwrap();
@@ -590,6 +632,7 @@ fn run_task_set_env_file() {
     task.env_files = Some(vec![EnvFile::Path(
         "./src/lib/test/test_files/env.env".to_string(),
     )]);
+    task.export_env = Some(true);
 
     let step = Step {
         name: "test".to_string(),
@@ -615,10 +658,12 @@ fn run_task_set_env() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -631,6 +676,7 @@ fn run_task_set_env() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut env = IndexMap::new();
@@ -642,6 +688,7 @@ fn run_task_set_env() {
     let mut task = Task::new();
     task.script = Some(ScriptValue::Text(vec!["exit 0".to_string()]));
     task.env = Some(env);
+    task.export_env = Some(true);
 
     let step = Step {
         name: "test".to_string(),
@@ -666,10 +713,12 @@ fn run_task_cwd_no_such_dir() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -682,6 +731,7 @@ fn run_task_cwd_no_such_dir() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -705,10 +755,12 @@ fn run_task_cwd_dir_exists() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -721,6 +773,7 @@ fn run_task_cwd_dir_exists() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -744,10 +797,12 @@ fn run_task_cwd_env_expansion() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -760,6 +815,7 @@ fn run_task_cwd_env_expansion() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -783,10 +839,12 @@ fn run_task_deprecated_message() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -799,6 +857,7 @@ fn run_task_deprecated_message() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -845,10 +904,12 @@ fn run_task_deprecated_flag() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -861,6 +922,7 @@ fn run_task_deprecated_flag() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let mut task = Task::new();
@@ -908,6 +970,7 @@ fn run_task_failed_condition_script_doesnt_change_env() {
     let flow_info = FlowInfo {
         config: Config::default(),
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -920,6 +983,7 @@ fn run_task_failed_condition_script_doesnt_change_env() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let step = Step {
@@ -1444,10 +1508,12 @@ fn run_sub_task_and_report_for_name() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1460,6 +1526,7 @@ fn run_sub_task_and_report_for_name() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Name("test".to_string());
@@ -1491,10 +1558,12 @@ fn run_sub_task_and_report_for_name_not_found() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1507,6 +1576,7 @@ fn run_sub_task_and_report_for_name_not_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Name("test2".to_string());
@@ -1535,10 +1605,12 @@ fn run_sub_task_and_report_for_details_single() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1551,6 +1623,7 @@ fn run_sub_task_and_report_for_details_single() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Details(RunTaskDetails {
@@ -1587,10 +1660,12 @@ fn run_sub_task_and_report_for_details_multiple() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1603,6 +1678,7 @@ fn run_sub_task_and_report_for_details_multiple() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Details(RunTaskDetails {
@@ -1638,10 +1714,12 @@ fn run_sub_task_and_report_routing_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1654,6 +1732,7 @@ fn run_sub_task_and_report_routing_empty() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Routing(vec![]);
@@ -1684,10 +1763,12 @@ fn run_sub_task_and_report_routing_no_condition() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1700,6 +1781,7 @@ fn run_sub_task_and_report_routing_no_condition() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Routing(vec![RunTaskRoutingInfo {
@@ -1738,10 +1820,12 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1754,6 +1838,7 @@ fn run_sub_task_and_report_routing_condition_not_met() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Routing(vec![RunTaskRoutingInfo {
@@ -1779,6 +1864,10 @@ fn run_sub_task_and_report_routing_condition_not_met() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: None,
         condition_script_runner_args: None,
@@ -1811,10 +1900,12 @@ fn run_sub_task_and_report_routing_not_found() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1827,6 +1918,7 @@ fn run_sub_task_and_report_routing_not_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Routing(vec![RunTaskRoutingInfo {
@@ -1864,10 +1956,12 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1880,6 +1974,7 @@ fn run_sub_task_and_report_with_cleanup_task_but_no_fork() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let sub_task = RunTaskInfo::Details(RunTaskDetails {
@@ -1906,10 +2001,12 @@ fn get_sub_task_info_for_routing_info_empty() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1922,6 +2019,7 @@ fn get_sub_task_info_for_routing_info_empty() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) =
@@ -1942,10 +2040,12 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -1958,6 +2058,7 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -1985,6 +2086,10 @@ fn get_sub_task_info_for_routing_info_condition_not_met() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2007,10 +2112,12 @@ fn get_sub_task_info_for_routing_info_condition_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2023,6 +2130,7 @@ fn get_sub_task_info_for_routing_info_condition_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2050,6 +2158,10 @@ fn get_sub_task_info_for_routing_info_condition_found() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2072,10 +2184,12 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2088,6 +2202,7 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2115,6 +2230,10 @@ fn get_sub_task_info_for_routing_info_condition_found_multiple_tasks() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2137,10 +2256,12 @@ fn get_sub_task_info_for_routing_info_script_not_met() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2153,6 +2274,7 @@ fn get_sub_task_info_for_routing_info_script_not_met() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2184,10 +2306,12 @@ fn get_sub_task_info_for_routing_info_script_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2200,6 +2324,7 @@ fn get_sub_task_info_for_routing_info_script_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2231,10 +2356,12 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2247,6 +2374,7 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2275,6 +2403,10 @@ fn get_sub_task_info_for_routing_info_multiple_found() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    crate_has_feature: None,
+                    crate_has_dependency: None,
+                    crate_has_target: None,
+                    crate_is_workspace_member: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2307,10 +2439,12 @@ fn get_sub_task_info_for_routing_info_default() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2323,6 +2457,7 @@ fn get_sub_task_info_for_routing_info_default() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2351,6 +2486,10 @@ fn get_sub_task_info_for_routing_info_default() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    crate_has_feature: None,
+                    crate_has_dependency: None,
+                    crate_has_target: None,
+                    crate_is_workspace_member: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2392,10 +2531,12 @@ fn get_sub_task_info_for_routing_info_multiple() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2408,6 +2549,7 @@ fn get_sub_task_info_for_routing_info_multiple() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2436,6 +2578,10 @@ fn get_sub_task_info_for_routing_info_multiple() {
                     files_exist: None,
                     files_not_exist: None,
                     files_modified: None,
+                    crate_has_feature: None,
+                    crate_has_dependency: None,
+                    crate_has_target: None,
+                    crate_is_workspace_member: None,
                 }),
                 condition_script: None,
                 condition_script_runner_args: None,
@@ -2486,10 +2632,12 @@ fn get_sub_task_info_for_routing_info_fork_false() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2502,6 +2650,7 @@ fn get_sub_task_info_for_routing_info_fork_false() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2529,6 +2678,10 @@ fn get_sub_task_info_for_routing_info_fork_false() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2551,10 +2704,12 @@ fn get_sub_task_info_for_routing_info_fork_true() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2567,6 +2722,7 @@ fn get_sub_task_info_for_routing_info_fork_true() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let (task_name, fork, parallel, cleanup_task) = get_sub_task_info_for_routing_info(
@@ -2594,6 +2750,10 @@ fn get_sub_task_info_for_routing_info_fork_true() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: None,
             condition_script_runner_args: None,
@@ -2607,6 +2767,34 @@ fn get_sub_task_info_for_routing_info_fork_true() {
     assert!(cleanup_task.is_none());
 }
 
+#[test]
+fn get_proxy_task_and_arguments_single_task() {
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.task = "test".to_string();
+    flow_info.cli_arguments = Some(vec!["arg1".to_string()]);
+
+    let (task, arguments) = get_proxy_task_and_arguments(&flow_info);
+
+    assert_eq!(task, "test");
+    assert_eq!(arguments.unwrap(), vec!["arg1".to_string()]);
+}
+
+#[test]
+fn get_proxy_task_and_arguments_multiple_tasks() {
+    let mut flow_info = test::create_empty_flow_info();
+    flow_info.task = "test1".to_string();
+    flow_info.additional_tasks = vec!["test2".to_string(), "test3".to_string()];
+    flow_info.cli_arguments = Some(vec!["arg1".to_string(), "arg2".to_string()]);
+
+    let (task, arguments) = get_proxy_task_and_arguments(&flow_info);
+
+    assert_eq!(task, "--tasks=test1,test2,test3");
+    assert_eq!(
+        arguments.unwrap(),
+        vec!["--".to_string(), "arg1".to_string(), "arg2".to_string()]
+    );
+}
+
 #[test]
 fn create_fork_step_valid() {
     let config = Config {
@@ -2616,10 +2804,12 @@ fn create_fork_step_valid() {
         env_scripts: vec![],
         tasks: IndexMap::new(),
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2632,6 +2822,7 @@ fn create_fork_step_valid() {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     let step = create_fork_step(&flow_info);
@@ -2698,10 +2889,12 @@ fn run_flow_skip_init_end_tasks() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     let flow_info = FlowInfo {
         config,
         task: "entry".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -2714,6 +2907,7 @@ fn run_flow_skip_init_end_tasks() {
         skip_init_end_tasks: true,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     };
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
@@ -2730,3 +2924,125 @@ fn run_flow_skip_init_end_tasks() {
 
     run_flow(&flow_info, Rc::new(RefCell::new(FlowState::new())), false).unwrap();
 }
+
+fn create_env_scope_flow_info() -> FlowInfo {
+    FlowInfo {
+        config: Config::default(),
+        task: "test".to_string(),
+        additional_tasks: vec![],
+        env_info: EnvInfo {
+            rust_info: RustInfo::new(),
+            crate_info: CrateInfo::new(),
+            git_info: GitInfo::new(),
+            ci_info: ci_info::get(),
+        },
+        disable_workspace: false,
+        disable_on_error: false,
+        allow_private: false,
+        skip_init_end_tasks: false,
+        skip_tasks_pattern: None,
+        cli_arguments: None,
+        why: false,
+    }
+}
+
+fn create_env_scope_step(existing_key: &str, new_key: &str, export_env: Option<bool>) -> Step {
+    Step {
+        name: "test".to_string(),
+        config: Task {
+            env: Some(
+                [
+                    (
+                        existing_key.to_string(),
+                        EnvValue::Value("TASK-VALUE".to_string()),
+                    ),
+                    (
+                        new_key.to_string(),
+                        EnvValue::Value("TASK-VALUE".to_string()),
+                    ),
+                ]
+                .into(),
+            ),
+            export_env,
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn run_task_env_restored_after_task() {
+    const EXISTING_ENV_VAR: &str = "RUN_TASK_ENV_RESTORED_EXISTING";
+    const NEW_ENV_VAR: &str = "RUN_TASK_ENV_RESTORED_NEW";
+    envmnt::set(EXISTING_ENV_VAR, "ORIGINAL-VALUE");
+    envmnt::remove(NEW_ENV_VAR);
+
+    let flow_info = create_env_scope_flow_info();
+    let step = create_env_scope_step(EXISTING_ENV_VAR, NEW_ENV_VAR, None);
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        std::env::var(EXISTING_ENV_VAR).unwrap(),
+        "ORIGINAL-VALUE".to_string()
+    );
+    assert_eq!(std::env::var(NEW_ENV_VAR), Err(VarError::NotPresent));
+
+    envmnt::remove(EXISTING_ENV_VAR);
+}
+
+#[test]
+fn run_task_env_exported_after_task() {
+    const EXISTING_ENV_VAR: &str = "RUN_TASK_ENV_EXPORTED_EXISTING";
+    const NEW_ENV_VAR: &str = "RUN_TASK_ENV_EXPORTED_NEW";
+    envmnt::set(EXISTING_ENV_VAR, "ORIGINAL-VALUE");
+    envmnt::remove(NEW_ENV_VAR);
+
+    let flow_info = create_env_scope_flow_info();
+    let step = create_env_scope_step(EXISTING_ENV_VAR, NEW_ENV_VAR, Some(true));
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(
+        std::env::var(EXISTING_ENV_VAR).unwrap(),
+        "TASK-VALUE".to_string()
+    );
+    assert_eq!(
+        std::env::var(NEW_ENV_VAR).unwrap(),
+        "TASK-VALUE".to_string()
+    );
+
+    envmnt::remove_all(&vec![EXISTING_ENV_VAR, NEW_ENV_VAR]);
+}
+
+#[test]
+fn run_task_vars_not_exported() {
+    const VAR: &str = "RUN_TASK_VARS_NOT_EXPORTED_VAR";
+    const ENV_VAR: &str = "RUN_TASK_VARS_NOT_EXPORTED_ENV";
+    envmnt::remove(VAR);
+    envmnt::remove(ENV_VAR);
+
+    let flow_info = create_env_scope_flow_info();
+    let step = Step {
+        name: "test".to_string(),
+        config: Task {
+            vars: Some([(VAR.to_string(), EnvValue::Value("VAR-VALUE".to_string()))].into()),
+            env: Some(
+                [(
+                    ENV_VAR.to_string(),
+                    EnvValue::Value(format!("${{{}}}", VAR)),
+                )]
+                .into(),
+            ),
+            export_env: Some(true),
+            ..Default::default()
+        },
+    };
+
+    run_task(&flow_info, Rc::new(RefCell::new(FlowState::new())), &step).unwrap();
+
+    assert_eq!(std::env::var(ENV_VAR).unwrap(), "VAR-VALUE".to_string());
+    assert_eq!(std::env::var(VAR), Err(VarError::NotPresent));
+    assert!(environment::get_var(VAR).is_none());
+
+    envmnt::remove(ENV_VAR);
+}
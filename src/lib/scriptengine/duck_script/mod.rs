@@ -12,6 +12,7 @@ mod sdk;
 use crate::environment;
 use crate::error::CargoMakeError;
 use crate::logger::{get_level, get_log_level, LogLevel};
+use crate::process_env;
 use crate::types::{FlowInfo, FlowState};
 use duckscript::runner;
 use duckscript::types::command::Commands;
@@ -91,7 +92,8 @@ pub(crate) fn create_common_context(cli_arguments: &Vec<String>) -> Context {
             .insert(index.to_string(), argument.to_string());
     }
 
-    let all_vars = envmnt::vars();
+    // the task env is passed explicitly as duckscript runs in process
+    let all_vars = process_env::get_child_env();
 
     for (key, value) in all_vars {
         context.variables.insert(key, value);
@@ -147,3 +147,42 @@ fn cm_run_task_error() {
     )
     .unwrap();
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn execute_duckscript_task_env() {
+    envmnt::remove("DUCKSCRIPT_TEST_TASK_ENV");
+    envmnt::remove("DUCKSCRIPT_TEST_TASK_ENV_SET");
+
+    let mut process_env = crate::process_env::ProcessEnv::default();
+    process_env.env.insert(
+        "DUCKSCRIPT_TEST_TASK_ENV".to_string(),
+        Some("test".to_string()),
+    );
+
+    let valid = crate::process_env::run_with(process_env, || {
+        execute(
+            &vec![
+                "assert_eq ${DUCKSCRIPT_TEST_TASK_ENV} test".to_string(),
+                "value = get_env DUCKSCRIPT_TEST_TASK_ENV".to_string(),
+                "assert_eq ${value} test".to_string(),
+                "set_env DUCKSCRIPT_TEST_TASK_ENV_SET set".to_string(),
+                "output = exec sh -c \"echo $DUCKSCRIPT_TEST_TASK_ENV-$DUCKSCRIPT_TEST_TASK_ENV_SET\""
+                    .to_string(),
+                "output = trim ${output.stdout}".to_string(),
+                "assert_eq ${output} test-set".to_string(),
+            ],
+            &vec![],
+            Some(&test::create_empty_flow_info()),
+            Some(Rc::new(RefCell::new(FlowState::new()))),
+            true,
+        )
+        .unwrap()
+    });
+
+    assert!(valid);
+    assert!(!envmnt::exists("DUCKSCRIPT_TEST_TASK_ENV"));
+    assert_eq!(envmnt::get_or_panic("DUCKSCRIPT_TEST_TASK_ENV_SET"), "set");
+
+    envmnt::remove("DUCKSCRIPT_TEST_TASK_ENV_SET");
+}
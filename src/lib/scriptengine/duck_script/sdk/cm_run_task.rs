@@ -4,12 +4,12 @@
 //!
 
 use crate::error::CargoMakeError;
+use crate::process_env;
 use crate::runner;
 use crate::types::{FlowInfo, FlowState};
 use duckscript::types::command::{Command, CommandInvocationContext, CommandResult};
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::thread;
 
 #[derive(Clone)]
 pub(crate) struct CommandImpl {
@@ -46,7 +46,7 @@ impl Command for CommandImpl {
                 if async_run {
                     let cloned_flow_state = self.flow_state.borrow().clone();
 
-                    thread::spawn(move || -> Result<(), CargoMakeError> {
+                    process_env::spawn(move || -> Result<(), CargoMakeError> {
                         runner::run_flow(
                             &sub_flow_info,
                             Rc::new(RefCell::new(cloned_flow_state)),
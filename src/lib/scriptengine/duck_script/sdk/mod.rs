@@ -4,6 +4,7 @@
 //!
 
 mod cm_run_task;
+mod task_env;
 
 use crate::types::{FlowInfo, FlowState};
 use duckscript::types::command::Commands;
@@ -17,6 +18,8 @@ pub(crate) fn load(
     flow_info_option: Option<&FlowInfo>,
     flow_state_option: Option<Rc<RefCell<FlowState>>>,
 ) -> Result<(), ScriptError> {
+    task_env::load(commands)?;
+
     if let (Some(flow_info), Some(flow_state)) = (flow_info_option, flow_state_option) {
         commands.set(cm_run_task::create(flow_info, flow_state))?;
     }
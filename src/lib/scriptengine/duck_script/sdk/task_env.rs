@@ -0,0 +1,217 @@
+//! # task_env
+//!
+//! Replaces the duckscript SDK commands which read or modify the process env, so they see and
+//! update the env of the task running in the current thread.
+//!
+
+use crate::process_env;
+use duckscript::types::command::{
+    Command, CommandBox, CommandInvocationContext, CommandResult, Commands,
+};
+use duckscript::types::error::ScriptError;
+use std::env;
+use std::io::Write;
+use std::process::Stdio;
+
+/// The SDK commands which only read or modify the process env
+static ENV_COMMANDS: [&str; 5] = ["get_env", "set_env", "unset_env", "env_to_map", "print_env"];
+
+#[derive(Clone)]
+/// Runs the wrapped command with the task env applied to the process env
+struct EnvCommandImpl {
+    command: CommandBox,
+}
+
+impl Command for EnvCommandImpl {
+    fn name(&self) -> String {
+        self.command.name()
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        self.command.aliases()
+    }
+
+    fn help(&self) -> String {
+        self.command.help()
+    }
+
+    fn clone_and_box(&self) -> Box<dyn Command> {
+        Box::new((*self).clone())
+    }
+
+    fn run(&self, context: CommandInvocationContext) -> CommandResult {
+        process_env::run_in_process(|| self.command.run(context))
+    }
+}
+
+enum LookingFor {
+    Flag,
+    Input,
+}
+
+#[derive(Clone)]
+/// Same as the SDK exec command, but the child process gets the task env explicitly
+struct ExecCommandImpl {
+    command: CommandBox,
+}
+
+impl Command for ExecCommandImpl {
+    fn name(&self) -> String {
+        self.command.name()
+    }
+
+    fn aliases(&self) -> Vec<String> {
+        self.command.aliases()
+    }
+
+    fn help(&self) -> String {
+        self.command.help()
+    }
+
+    fn clone_and_box(&self) -> Box<dyn Command> {
+        Box::new((*self).clone())
+    }
+
+    fn run(&self, context: CommandInvocationContext) -> CommandResult {
+        let mut input = None;
+        let mut command_start_index = 0;
+        let mut print_output = context.output_variable.is_none();
+        let mut fail_on_error = false;
+        let mut exit_code_output = false;
+
+        let mut looking_for = LookingFor::Flag;
+        for argument in &context.arguments {
+            match looking_for {
+                LookingFor::Flag => match argument.as_str() {
+                    "--fail-on-error" => {
+                        fail_on_error = context.output_variable.is_none();
+                        command_start_index += 1;
+                    }
+                    "--get-exit-code" => {
+                        exit_code_output = true;
+                        print_output = true;
+                        command_start_index += 1;
+                    }
+                    "--input" => {
+                        looking_for = LookingFor::Input;
+                        command_start_index += 1;
+                    }
+                    _ => break,
+                },
+                LookingFor::Input => {
+                    input = Some(argument.to_string());
+                    command_start_index += 1;
+
+                    looking_for = LookingFor::Flag;
+                }
+            }
+        }
+
+        if context.arguments.len() <= command_start_index {
+            return CommandResult::Error("Command not provided.".to_string());
+        }
+
+        let mut command = std::process::Command::new(&context.arguments[command_start_index]);
+        command.args(&context.arguments[command_start_index + 1..]);
+
+        match input {
+            Some(_) => command.stdin(Stdio::piped()),
+            None if context.output_variable.is_some() => command.stdin(Stdio::inherit()),
+            None => command.stdin(Stdio::null()),
+        };
+
+        if print_output {
+            command.stdout(Stdio::inherit()).stderr(Stdio::inherit());
+        } else {
+            command.stdout(Stdio::piped()).stderr(Stdio::piped());
+        }
+
+        process_env::apply_to_command(&mut command);
+        // duckscript may change the working directory while running
+        if let Ok(cwd) = env::current_dir() {
+            command.current_dir(cwd);
+        }
+
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(error) => return CommandResult::Error(error.to_string()),
+        };
+
+        if let Some(input) = input {
+            let written = match child.stdin.as_mut() {
+                Some(stdin) => stdin
+                    .write_all(input.as_bytes())
+                    .map_err(|error| error.to_string()),
+                None => Err("Unable to write input to process".to_string()),
+            };
+
+            if let Err(error) = written {
+                return CommandResult::Error(error);
+            }
+        }
+
+        let output = match child.wait_with_output() {
+            Ok(output) => output,
+            Err(error) => return CommandResult::Error(error.to_string()),
+        };
+
+        let exit_code = match output.status.code() {
+            Some(value) => value,
+            None => {
+                return CommandResult::Error(format!(
+                    "Unable to extract exit code for command: {}",
+                    &context.arguments[command_start_index]
+                ))
+            }
+        };
+
+        match context.output_variable {
+            Some(name) => {
+                if exit_code_output {
+                    CommandResult::Continue(Some(exit_code.to_string()))
+                } else {
+                    context.variables.insert(
+                        format!("{}.stdout", &name),
+                        String::from_utf8_lossy(&output.stdout).into_owned(),
+                    );
+                    context.variables.insert(
+                        format!("{}.stderr", &name),
+                        String::from_utf8_lossy(&output.stderr).into_owned(),
+                    );
+                    context
+                        .variables
+                        .insert(format!("{}.code", &name), exit_code.to_string());
+
+                    CommandResult::Continue(None)
+                }
+            }
+            None => {
+                if fail_on_error && exit_code != 0 {
+                    CommandResult::Error(format!(
+                        "Error while executing command, exit code: {}",
+                        exit_code
+                    ))
+                } else {
+                    CommandResult::Continue(None)
+                }
+            }
+        }
+    }
+}
+
+/// Replaces the loaded SDK env and exec commands
+pub(crate) fn load(commands: &mut Commands) -> Result<(), ScriptError> {
+    for name in ENV_COMMANDS {
+        if let Some(command) = commands.get_for_use(name) {
+            commands.remove(name);
+            commands.set(Box::new(EnvCommandImpl { command }))?;
+        }
+    }
+
+    if let Some(command) = commands.get_for_use("exec") {
+        commands.remove("exec");
+        commands.set(Box::new(ExecCommandImpl { command }))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! # secrets
+//!
+//! Keeps track of secret env vars and masks their values in any printed output.<br>
+//! The secret env names are stored in the env so child cargo-make processes mask them as well.
+//!
+
+#[cfg(test)]
+#[path = "secrets_test.rs"]
+mod secrets_test;
+
+use std::sync::Mutex;
+
+static SECRETS_ENV_VAR_NAME: &str = "CARGO_MAKE_INTERNAL_SECRET_ENV_KEYS";
+static MASK: &str = "***";
+/// Lines of multi line secrets shorter than this are not masked on their own, as they
+/// would mask common text (such as braces or numbers) all over the output
+static MIN_LINE_MASK_LENGTH: usize = 8;
+/// Secrets shorter than this are only masked when they appear as a whole word, so short
+/// values (such as 1 or on) do not mask unrelated text
+static MIN_MASK_LENGTH: usize = 8;
+static ADD_LOCK: Mutex<()> = Mutex::new(());
+
+/// Marks the given env var as secret
+pub(crate) fn add(key: &str) {
+    let _guard = ADD_LOCK.lock();
+
+    let mut keys = get_keys();
+
+    if !keys.contains(&key.to_string()) {
+        keys.push(key.to_string());
+        envmnt::set_list(SECRETS_ENV_VAR_NAME, &keys);
+    }
+}
+
+/// Returns all env var names marked as secret
+pub(crate) fn get_keys() -> Vec<String> {
+    envmnt::get_list(SECRETS_ENV_VAR_NAME).unwrap_or_default()
+}
+
+fn get_masked_values() -> Vec<String> {
+    let mut values = vec![];
+
+    for key in get_keys() {
+        let value = envmnt::get_or(&key, "");
+
+        if !value.is_empty() {
+            values.push(value.escape_debug().to_string());
+
+            for line in value.lines() {
+                let line = line.trim();
+
+                if line.len() >= MIN_LINE_MASK_LENGTH {
+                    values.push(line.to_string());
+                }
+            }
+
+            values.push(value);
+        }
+    }
+
+    // longer values first so partial matches will not leave a secret suffix unmasked
+    values.sort_by(|first, second| {
+        second
+            .len()
+            .cmp(&first.len())
+            .then_with(|| first.cmp(second))
+    });
+    values.dedup();
+
+    values
+}
+
+fn is_word_char(value: Option<char>) -> bool {
+    match value {
+        Some(value) => value.is_alphanumeric() || value == '_',
+        None => false,
+    }
+}
+
+/// Replaces the occurrences of the value which are not part of a longer word
+fn replace_word(text: &str, value: &str) -> String {
+    let mut output = String::new();
+    let mut last_index = 0;
+
+    for (index, _) in text.match_indices(value) {
+        let end_index = index + value.len();
+
+        if !is_word_char(text[..index].chars().next_back())
+            && !is_word_char(text[end_index..].chars().next())
+        {
+            output.push_str(&text[last_index..index]);
+            output.push_str(MASK);
+            last_index = end_index;
+        }
+    }
+    output.push_str(&text[last_index..]);
+
+    output
+}
+
+/// Replaces all secret values found in the given text with a mask
+pub(crate) fn mask(text: &str) -> String {
+    let mut masked = text.to_string();
+
+    for value in get_masked_values() {
+        masked = if value.len() < MIN_MASK_LENGTH {
+            replace_word(&masked, &value)
+        } else {
+            masked.replace(&value, MASK)
+        };
+    }
+
+    masked
+}
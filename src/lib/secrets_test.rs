@@ -0,0 +1,89 @@
+use super::*;
+
+#[test]
+fn add_new_key() {
+    add("SECRETS_TEST_ADD_NEW_KEY");
+
+    assert!(get_keys().contains(&"SECRETS_TEST_ADD_NEW_KEY".to_string()));
+}
+
+#[test]
+fn add_existing_key() {
+    add("SECRETS_TEST_ADD_EXISTING_KEY");
+    add("SECRETS_TEST_ADD_EXISTING_KEY");
+
+    let count = get_keys()
+        .iter()
+        .filter(|key| *key == "SECRETS_TEST_ADD_EXISTING_KEY")
+        .count();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn mask_no_secrets_in_text() {
+    let output = mask("text without any sensitive value");
+
+    assert_eq!(output, "text without any sensitive value");
+}
+
+#[test]
+fn mask_secret_value() {
+    envmnt::set("SECRETS_TEST_MASK_SECRET_VALUE", "my-secret-token-123");
+    add("SECRETS_TEST_MASK_SECRET_VALUE");
+
+    let output = mask("curl -H 'Authorization: my-secret-token-123' my-secret-token-123");
+
+    assert_eq!(output, "curl -H 'Authorization: ***' ***");
+}
+
+#[test]
+fn mask_short_secret_value() {
+    envmnt::set("SECRETS_TEST_MASK_SHORT_SECRET_VALUE", "zq");
+    add("SECRETS_TEST_MASK_SHORT_SECRET_VALUE");
+
+    let output = mask("value: zq, zqx xzq_ azqb zq");
+
+    assert_eq!(output, "value: ***, zqx xzq_ azqb ***");
+}
+
+#[test]
+fn mask_multi_line_secret_value() {
+    envmnt::set(
+        "SECRETS_TEST_MASK_MULTI_LINE_SECRET_VALUE",
+        "first-secret-line\nsecond-secret-line",
+    );
+    add("SECRETS_TEST_MASK_MULTI_LINE_SECRET_VALUE");
+
+    let output = mask("line: second-secret-line");
+    assert_eq!(output, "line: ***");
+
+    let output = mask(&format!(
+        "{:?}",
+        "first-secret-line\nsecond-secret-line".to_string()
+    ));
+    assert_eq!(output, "\"***\"");
+}
+
+#[test]
+fn mask_multi_line_secret_value_short_lines() {
+    envmnt::set(
+        "SECRETS_TEST_MASK_MULTI_LINE_SECRET_VALUE_SHORT_LINES",
+        "{\n  \"key\": \"multi-line-secret-key\",\n  \"id\": 1\n}",
+    );
+    add("SECRETS_TEST_MASK_MULTI_LINE_SECRET_VALUE_SHORT_LINES");
+
+    let output = mask("step 1 {done}");
+    assert_eq!(output, "step 1 {done}");
+
+    let output = mask("value: \"key\": \"multi-line-secret-key\",");
+    assert_eq!(output, "value: ***");
+}
+
+#[test]
+fn mask_secret_not_marked() {
+    envmnt::set("SECRETS_TEST_MASK_SECRET_NOT_MARKED", "not-a-secret-value");
+
+    let output = mask("value: not-a-secret-value");
+
+    assert_eq!(output, "value: not-a-secret-value");
+}
@@ -8,7 +8,7 @@ use indexmap::IndexMap;
 use rust_info::types::{RustChannel, RustInfo};
 use semver::Version;
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn on_test_startup() {
     logger::init(&LoggerOptions {
@@ -98,6 +98,20 @@ pub(crate) fn get_temp_test_directory(subdir: &str) -> PathBuf {
     directory
 }
 
+/// Creates a minimal library crate with the provided Cargo.toml dependencies section.
+pub(crate) fn create_test_crate(directory: &Path, name: &str, dependencies: &str) {
+    fsio::directory::create(&directory.join("src")).unwrap();
+    fsio::file::write_text_file(&directory.join("src/lib.rs"), "").unwrap();
+    fsio::file::write_text_file(
+        &directory.join("Cargo.toml"),
+        &format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n{}",
+            name, dependencies
+        ),
+    )
+    .unwrap();
+}
+
 pub(crate) fn is_not_rust_stable() -> bool {
     on_test_startup();
 
@@ -133,8 +147,10 @@ pub(crate) fn create_empty_flow_info() -> FlowInfo {
             env_scripts: vec![],
             tasks: IndexMap::new(),
             plugins: None,
+            vars: IndexMap::new(),
         },
         task: "test".to_string(),
+        additional_tasks: vec![],
         env_info: EnvInfo {
             rust_info: RustInfo::new(),
             crate_info: CrateInfo::new(),
@@ -147,5 +163,6 @@ pub(crate) fn create_empty_flow_info() -> FlowInfo {
         skip_init_end_tasks: false,
         skip_tasks_pattern: None,
         cli_arguments: None,
+        why: false,
     }
 }
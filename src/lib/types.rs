@@ -39,6 +39,25 @@ fn get_namespaced_task_name(namespace: &str, task: &str) -> String {
     namespaced_task
 }
 
+fn get_modified_tasks(
+    tasks: &IndexMap<String, Task>,
+    namespace: &str,
+    modify_config: &ModifyConfig,
+) -> IndexMap<String, Task> {
+    let mut modified_tasks = IndexMap::<String, Task>::new();
+
+    for (key, value) in tasks.iter() {
+        let namespaced_task = get_namespaced_task_name(namespace, key);
+        let mut task = value.clone();
+
+        task.apply(modify_config);
+
+        modified_tasks.insert(namespaced_task, task);
+    }
+
+    modified_tasks
+}
+
 fn extend_script_value(
     current_script_value: Option<ScriptValue>,
     new_script_value: Option<ScriptValue>,
@@ -85,6 +104,10 @@ pub struct CliArgs {
     pub build_file: Option<String>,
     /// The task to invoke
     pub task: String,
+    /// The additional tasks to invoke after the task in the same flow
+    pub additional_tasks: Vec<String>,
+    /// True if the tasks were provided via the --tasks CLI argument
+    pub tasks_provided: bool,
     /// The profile name
     pub profile: Option<String>,
     /// Log level name
@@ -115,6 +138,8 @@ pub struct CliArgs {
     pub list_all_steps: bool,
     /// List steps for a given category
     pub list_category_steps: Option<String>,
+    /// List the profiles defined in the makefiles
+    pub list_profiles: bool,
     /// Diff flows
     pub diff_execution_plan: bool,
     /// Disables the update check during startup
@@ -131,6 +156,36 @@ pub struct CliArgs {
     pub print_time_summary: bool,
     /// Hide any minor tasks such as pre/post hooks
     pub hide_uninteresting: bool,
+    /// Print why tasks were invoked or skipped
+    pub why: bool,
+    /// Run the condition scripts when printing why tasks would be invoked or skipped
+    pub why_condition_scripts: bool,
+    /// Print the resolved env of the task
+    pub print_env: bool,
+    /// Explain where the value of the env variable came from
+    pub explain_env: Option<String>,
+    /// Explain how the task was assembled
+    pub explain: Option<String>,
+    /// Print the merged config
+    pub print_config: bool,
+    /// The task to print the merged config for (all tasks if not explicitly provided)
+    pub print_config_task: Option<String>,
+    /// Print the makefile JSON schema
+    pub print_schema: bool,
+    /// Statically validate the makefiles
+    pub lint: bool,
+    /// Rewrite the makefile into the canonical layout
+    pub fmt: bool,
+    /// Only check if the makefile is in the canonical layout
+    pub fmt_check: bool,
+    /// Rewrite deprecated makefile content into its modern equivalent
+    pub migrate: bool,
+    /// The justfile, GNU Makefile or package.json file to import tasks from
+    pub import: Option<String>,
+    /// Interactively pick the task to run
+    pub pick: bool,
+    /// True if no task was provided and the default task is used
+    pub default_task: bool,
 }
 
 impl CliArgs {
@@ -140,6 +195,8 @@ impl CliArgs {
             command: "".to_string(),
             build_file: None,
             task: "default".to_string(),
+            additional_tasks: vec![],
+            tasks_provided: false,
             profile: None,
             log_level: "info".to_string(),
             disable_color: false,
@@ -155,6 +212,7 @@ impl CliArgs {
             print_only: false,
             list_all_steps: false,
             list_category_steps: None,
+            list_profiles: false,
             diff_execution_plan: false,
             disable_check_for_updates: false,
             experimental: false,
@@ -163,6 +221,21 @@ impl CliArgs {
             output_file: None,
             print_time_summary: false,
             hide_uninteresting: false,
+            why: false,
+            why_condition_scripts: false,
+            print_env: false,
+            explain_env: None,
+            explain: None,
+            print_config: false,
+            print_config_task: None,
+            print_schema: false,
+            lint: false,
+            fmt: false,
+            fmt_check: false,
+            migrate: false,
+            import: None,
+            pick: false,
+            default_task: false,
         }
     }
 }
@@ -280,6 +353,15 @@ pub enum CrateDependency {
     Info(CrateDependencyInfo),
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// Holds crate target (lib, bin, example, ...) info.
+pub struct CrateTarget {
+    /// target name
+    pub name: String,
+    /// target kinds (lib, bin, bench, example, test, ...)
+    pub kind: Vec<String>,
+}
+
 #[derive(Deserialize, Debug, Clone, Default)]
 /// Holds crate information loaded from the Cargo.toml file.
 pub struct CrateInfo {
@@ -289,6 +371,18 @@ pub struct CrateInfo {
     pub workspace: Option<Workspace>,
     /// crate dependencies
     pub dependencies: Option<IndexMap<String, CrateDependency>>,
+    /// crate feature names (loaded via cargo metadata)
+    #[serde(skip)]
+    pub features: Option<Vec<String>>,
+    /// names of all crate dependencies including dev and build dependencies (loaded via cargo metadata)
+    #[serde(skip)]
+    pub dependency_names: Option<Vec<String>>,
+    /// crate targets (loaded via cargo metadata)
+    #[serde(skip)]
+    pub targets: Option<Vec<CrateTarget>>,
+    /// true if the crate is a member of a workspace defined in a parent directory (loaded via cargo metadata)
+    #[serde(skip)]
+    pub workspace_member: Option<bool>,
 }
 
 impl CrateInfo {
@@ -318,6 +412,8 @@ pub struct FlowInfo {
     pub config: Config,
     /// The main task of the flow
     pub task: String,
+    /// The additional tasks of the flow, invoked after the main task
+    pub additional_tasks: Vec<String>,
     /// The env info
     pub env_info: EnvInfo,
     /// Prevent workspace support
@@ -332,6 +428,8 @@ pub struct FlowInfo {
     pub skip_tasks_pattern: Option<Regex>,
     /// additional command line arguments
     pub cli_arguments: Option<Vec<String>>,
+    /// Print why tasks were invoked or skipped
+    pub why: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -418,6 +516,14 @@ pub struct TaskCondition {
     pub files_not_exist: Option<Vec<String>>,
     /// Files modified since last execution
     pub files_modified: Option<FilesFilesModifiedCondition>,
+    /// Crate features which must be defined in the crate manifest
+    pub crate_has_feature: Option<Vec<String>>,
+    /// Crate dependencies (including dev and build dependencies) which must be defined in the crate manifest
+    pub crate_has_dependency: Option<Vec<String>>,
+    /// Crate target kinds (lib, bin, bench, example, test) which must be defined
+    pub crate_has_target: Option<Vec<String>>,
+    /// True/False if the crate must/must not be a workspace member
+    pub crate_is_workspace_member: Option<bool>,
 }
 
 impl TaskCondition {
@@ -429,6 +535,17 @@ impl TaskCondition {
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Holds the condition evaluation result and the reasons which led to it
+pub struct ConditionVerdict {
+    /// True if the condition was met
+    pub passed: bool,
+    /// The evaluated checks and their results
+    pub reasons: Vec<String>,
+    /// True if the verdict depends on a condition script which was not evaluated
+    pub script_skipped: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 /// Env file path and attributes
 pub struct EnvFileInfo {
@@ -497,6 +614,67 @@ pub struct EnvValueUnset {
     pub unset: bool,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+/// Env value which must never be printed out (logs, printed commands, etc...)
+pub struct EnvValueSecret {
+    /// True to mask the value in all outputs
+    pub secret: bool,
+    /// The secret value (can be an env expression)
+    pub value: Option<String>,
+    /// The file to read the secret value from (can be an env expression)
+    pub file: Option<String>,
+    /// The script to execute to get the secret value
+    pub script: Option<Vec<String>>,
+}
+
+impl std::fmt::Debug for EnvValueSecret {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = if self.secret {
+            self.value.as_ref().map(|_| "***".to_string())
+        } else {
+            self.value.clone()
+        };
+
+        formatter
+            .debug_struct("EnvValueSecret")
+            .field("secret", &self.secret)
+            .field("value", &value)
+            .field("file", &self.file)
+            .field("script", &self.script)
+            .finish()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Env values loaded from a structured (JSON/TOML) file
+pub struct EnvValueFromFile {
+    /// The structured file to load (can be an env expression)
+    pub from_file: String,
+    /// The file format (json, toml, yaml), if not provided it is based on the file extension
+    pub format: Option<String>,
+    /// Dot separated path to the document section to load (for example: services.api)
+    pub path: Option<String>,
+    /// Optional prefix added to all env variable names
+    pub prefix: Option<String>,
+    /// The condition to validate
+    pub condition: Option<TaskCondition>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Env values loaded from a script output (KEY=VALUE lines or JSON)
+pub struct EnvValueFromScript {
+    /// The script to execute to get the env values
+    pub from_script: Vec<String>,
+    /// The output format (dotenv, json, toml, yaml), if not provided it is detected from the output
+    pub format: Option<String>,
+    /// Dot separated path to the document section to load (only for json output)
+    pub path: Option<String>,
+    /// Optional prefix added to all env variable names
+    pub prefix: Option<String>,
+    /// The condition to validate
+    pub condition: Option<TaskCondition>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 /// Env value set if condition is met
 pub struct EnvValueConditioned {
@@ -533,6 +711,12 @@ pub enum EnvValue {
     List(Vec<String>),
     /// Unset env
     Unset(EnvValueUnset),
+    /// Secret value which is masked in all outputs
+    Secret(EnvValueSecret),
+    /// Multiple env values loaded from a structured file
+    FromFile(EnvValueFromFile),
+    /// Multiple env values loaded from a script output
+    FromScript(EnvValueFromScript),
     /// Script which will return the value
     Script(EnvValueScript),
     /// Env decoding info
@@ -1128,6 +1312,10 @@ pub struct Task {
     pub deprecated: Option<DeprecationInfo>,
     /// Extend any task based on the defined name
     pub extend: Option<String>,
+    /// Instantiate the task from the template based on the defined name
+    pub template: Option<String>,
+    /// The template parameters values
+    pub with: Option<IndexMap<String, String>>,
     /// set to false to notify cargo-make that this is not a workspace and should not call task for every member (same as --no-workspace CLI flag)
     pub workspace: Option<bool>,
     /// Optional plugin used to execute the task
@@ -1148,6 +1336,10 @@ pub struct Task {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the task commands
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// if true, the task env and env files are kept after the task is done and are visible to the next tasks
+    pub export_env: Option<bool>,
+    /// The makefile variables to setup before running the task commands (not exported to child processes)
+    pub vars: Option<IndexMap<String, EnvValue>>,
     /// The working directory for the task to execute its command/script
     pub cwd: Option<String>,
     /// if defined, task points to another task and all other properties are ignored
@@ -1388,6 +1580,13 @@ impl Task {
                         ));
                     }
 
+                    if self.template.is_some() {
+                        self.template = Some(get_namespaced_task_name(
+                            namespace,
+                            &self.template.clone().unwrap(),
+                        ));
+                    }
+
                     if self.run_task.is_some() {
                         let mut run_task = self.run_task.clone().unwrap();
 
@@ -1414,6 +1613,11 @@ impl Task {
                                     }
                                 };
 
+                                if let Some(ref cleanup_task) = run_task_details.cleanup_task {
+                                    run_task_details.cleanup_task =
+                                        Some(get_namespaced_task_name(namespace, cleanup_task));
+                                }
+
                                 RunTaskInfo::Details(run_task_details)
                             }
                             RunTaskInfo::Routing(mut routing_info_vector) => {
@@ -1436,6 +1640,11 @@ impl Task {
                                                 RunTaskName::Multiple(updated_names);
                                         }
                                     };
+
+                                    if let Some(ref cleanup_task) = routing_info.cleanup_task {
+                                        routing_info.cleanup_task =
+                                            Some(get_namespaced_task_name(namespace, cleanup_task));
+                                    }
                                 }
 
                                 RunTaskInfo::Routing(routing_info_vector)
@@ -1510,6 +1719,18 @@ impl Task {
             self.extend = None;
         }
 
+        if task.template.is_some() {
+            self.template = task.template.clone();
+        } else if override_values {
+            self.template = None;
+        }
+
+        if task.with.is_some() {
+            self.with = task.with.clone();
+        } else if override_values {
+            self.with = None;
+        }
+
         if task.workspace.is_some() {
             self.workspace = task.workspace.clone();
         } else if override_values {
@@ -1570,6 +1791,18 @@ impl Task {
             self.env = None;
         }
 
+        if task.export_env.is_some() {
+            self.export_env = task.export_env;
+        } else if override_values {
+            self.export_env = None;
+        }
+
+        if task.vars.is_some() {
+            self.vars = task.vars.clone();
+        } else if override_values {
+            self.vars = None;
+        }
+
         if task.cwd.is_some() {
             self.cwd = task.cwd.clone();
         } else if override_values {
@@ -1692,6 +1925,11 @@ impl Task {
         }
     }
 
+    /// Returns true if the task export_env attribute is defined and true
+    pub fn should_export_env(self: &Task) -> bool {
+        self.export_env.unwrap_or(false)
+    }
+
     /// Returns true if the task ignore_errors attribute is defined and true
     pub fn should_ignore_errors(self: &Task) -> bool {
         match self.ignore_errors {
@@ -1742,6 +1980,8 @@ impl Task {
                     private: override_task.private.clone(),
                     deprecated: override_task.deprecated.clone(),
                     extend: override_task.extend.clone(),
+                    template: self.template.clone(),
+                    with: self.with.clone(),
                     workspace: self.workspace.clone(),
                     plugin: override_task.plugin.clone(),
                     watch: override_task.watch.clone(),
@@ -1754,6 +1994,8 @@ impl Task {
                     force: override_task.force.clone(),
                     env_files: override_task.env_files.clone(),
                     env: override_task.env.clone(),
+                    export_env: self.export_env,
+                    vars: override_task.vars.clone(),
                     cwd: override_task.cwd.clone(),
                     alias: None,
                     linux_alias: None,
@@ -1919,6 +2161,8 @@ pub struct PlatformOverrideTask {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the task commands
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// The makefile variables to setup before running the task commands (not exported to child processes)
+    pub vars: Option<IndexMap<String, EnvValue>>,
     /// The working directory for the task to execute its command/script
     pub cwd: Option<String>,
     /// if defined, the provided crate will be installed (if needed) before running the task
@@ -2014,6 +2258,10 @@ impl PlatformOverrideTask {
                 self.env = task.env.clone();
             }
 
+            if self.vars.is_none() && task.vars.is_some() {
+                self.vars = task.vars.clone();
+            }
+
             if self.cwd.is_none() && task.cwd.is_some() {
                 self.cwd = task.cwd.clone();
             }
@@ -2080,6 +2328,17 @@ pub struct ExtendOptions {
     /// Relative to option, sub as current makefile, git root, crate root, workspace root, etc...
     /// Possible values: (makefile, git, crate, workspace)
     pub relative: Option<String>,
+    /// Git repository url to extend from, the path is relative to the repository root
+    pub git: Option<String>,
+    /// Git revision (commit, tag or branch) to checkout, defaults to the default branch
+    pub rev: Option<String>,
+    /// Crate dependency to extend from, the path is relative to the crate root
+    #[serde(rename = "crate")]
+    pub crate_name: Option<String>,
+    /// If set to some value, all the extended tasks are modified to: namespace::name
+    pub namespace: Option<String>,
+    /// If true, all the extended tasks will be set to private (default false)
+    pub private: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -2382,6 +2641,9 @@ pub struct Config {
     pub env_files: Vec<EnvFile>,
     /// The env vars to setup before running the flow
     pub env: IndexMap<String, EnvValue>,
+    /// The makefile variables to setup before running the flow (not exported to child processes)
+    #[serde(default)]
+    pub vars: IndexMap<String, EnvValue>,
     /// The env scripts to execute before running the flow
     pub env_scripts: Vec<String>,
     /// All task definitions
@@ -2400,21 +2662,23 @@ impl Config {
             None => "",
         };
 
-        let mut modified_tasks = IndexMap::<String, Task>::new();
-
-        for (key, value) in self.tasks.iter() {
-            let namespaced_task = get_namespaced_task_name(namespace, &key);
-            let mut task = value.clone();
-
-            task.apply(&modify_config);
-
-            modified_tasks.insert(namespaced_task, task);
-        }
-
-        self.tasks = modified_tasks;
+        self.tasks = get_modified_tasks(&self.tasks, namespace, modify_config);
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Holds the origin of a single env value definition
+pub struct EnvOrigin {
+    /// The makefile which defined the value (not defined for internal or cli values)
+    pub file: Option<String>,
+    /// The layer which defined the value (internal, extend, makefile, workspace, config, cli)
+    pub layer: String,
+    /// The profile name in case the value was defined in a profile env block
+    pub profile: Option<String>,
+    /// The defined value
+    pub value: EnvValue,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 /// Holds the entire externally read configuration such as task definitions and env vars where all values are optional
 pub struct ExternalConfig {
@@ -2426,12 +2690,19 @@ pub struct ExternalConfig {
     pub env_files: Option<Vec<EnvFile>>,
     /// The env vars to setup before running the flow
     pub env: Option<IndexMap<String, EnvValue>>,
+    /// The makefile variables to setup before running the flow (not exported to child processes)
+    pub vars: Option<IndexMap<String, EnvValue>>,
     /// The env scripts to execute before running the flow
     pub env_scripts: Option<Vec<String>>,
+    /// All task templates definitions
+    pub templates: Option<IndexMap<String, Task>>,
     /// All task definitions
     pub tasks: Option<IndexMap<String, Task>>,
     /// All plugin definitions
     pub plugins: Option<Plugins>,
+    /// The origins of all env values (ordered from lowest to highest priority)
+    #[serde(skip)]
+    pub env_origins: Option<IndexMap<String, Vec<EnvOrigin>>>,
 }
 
 impl ExternalConfig {
@@ -2439,6 +2710,25 @@ impl ExternalConfig {
     pub fn new() -> ExternalConfig {
         Default::default()
     }
+
+    /// Apply modifications to the tasks, task templates and config task references
+    pub fn apply(self: &mut ExternalConfig, modify_config: &ModifyConfig) {
+        if let Some(ref mut config) = self.config {
+            config.apply(modify_config);
+        }
+
+        let namespace = match modify_config.namespace {
+            Some(ref namespace) => namespace,
+            None => "",
+        };
+
+        if let Some(ref tasks) = self.tasks {
+            self.tasks = Some(get_modified_tasks(tasks, namespace, modify_config));
+        }
+        if let Some(ref templates) = self.templates {
+            self.templates = Some(get_modified_tasks(templates, namespace, modify_config));
+        }
+    }
 }
 
 #[derive(Serialize, Clone, Debug)]
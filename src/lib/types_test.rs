@@ -1649,6 +1649,135 @@ fn env_value_deserialize_unset() {
     }
 }
 
+#[test]
+fn env_value_deserialize_secret_value() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { secret = true, value = "my secret" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::Secret(value) => {
+                assert!(value.secret);
+                assert_eq!(value.value.clone().unwrap(), "my secret");
+                assert!(value.file.is_none());
+                assert!(value.script.is_none());
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_secret_file() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { secret = true, file = "./token.txt" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::Secret(value) => {
+                assert!(value.secret);
+                assert_eq!(value.file.clone().unwrap(), "./token.txt");
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_secret_script() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { secret = true, script = ["echo test"] }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::Secret(value) => {
+                assert!(value.secret);
+                assert_eq!(value.script.clone().unwrap(), vec!["echo test"]);
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_from_file() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { from_file = "deploy.json", path = "services.api", prefix = "API_" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::FromFile(value) => {
+                assert_eq!(value.from_file, "deploy.json");
+                assert_eq!(value.path.clone().unwrap(), "services.api");
+                assert_eq!(value.prefix.clone().unwrap(), "API_");
+                assert!(value.format.is_none());
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_deserialize_from_script() {
+    let config: ExternalConfig = toml::from_str(
+        r#"
+        [env]
+        key = { from_script = ["echo A=1"], format = "dotenv" }
+        "#,
+    )
+    .unwrap();
+    let env = config.env.unwrap();
+
+    for (_, info) in &env {
+        match info {
+            EnvValue::FromScript(value) => {
+                assert_eq!(value.from_script, vec!["echo A=1"]);
+                assert_eq!(value.format.clone().unwrap(), "dotenv");
+            }
+            _ => panic!("invalid env value type"),
+        };
+    }
+}
+
+#[test]
+fn env_value_secret_debug_masked() {
+    let secret = EnvValueSecret {
+        secret: true,
+        value: Some("my secret".to_string()),
+        file: None,
+        script: None,
+    };
+
+    let output = format!("{:?}", &secret);
+
+    assert!(!output.contains("my secret"));
+    assert!(output.contains("***"));
+}
+
 #[test]
 fn toolchain_specifier_deserialize_string() {
     #[derive(Deserialize)]
@@ -1746,6 +1875,65 @@ fn external_config_new() {
     assert!(config.plugins.is_none());
 }
 
+#[test]
+fn task_should_export_env_none() {
+    let task = Task::new();
+    assert!(!task.should_export_env());
+}
+
+#[test]
+fn task_should_export_env_false() {
+    let mut task = Task::new();
+    task.export_env = Some(false);
+    assert!(!task.should_export_env());
+}
+
+#[test]
+fn task_should_export_env_true() {
+    let mut task = Task::new();
+    task.export_env = Some(true);
+    assert!(task.should_export_env());
+}
+
+#[test]
+fn task_extend_export_env() {
+    let mut base = Task::new();
+    base.export_env = Some(true);
+
+    let extended = Task::new();
+    base.extend(&extended);
+    assert!(base.should_export_env());
+
+    let mut extended = Task::new();
+    extended.export_env = Some(false);
+    base.extend(&extended);
+    assert!(!base.should_export_env());
+}
+
+#[test]
+fn task_extend_vars() {
+    let mut vars = IndexMap::new();
+    vars.insert("VAR".to_string(), EnvValue::Value("base".to_string()));
+
+    let mut base = Task::new();
+    base.vars = Some(vars);
+
+    let extended = Task::new();
+    base.extend(&extended);
+    assert!(base.vars.is_some());
+
+    let mut vars = IndexMap::new();
+    vars.insert("VAR".to_string(), EnvValue::Value("extended".to_string()));
+    let mut extended = Task::new();
+    extended.vars = Some(vars);
+    base.extend(&extended);
+
+    match base.vars.unwrap().get("VAR").unwrap() {
+        EnvValue::Value(value) => assert_eq!(value, "extended"),
+        _ => panic!("invalid var value type"),
+    };
+}
+
 #[test]
 fn task_should_ignore_errors_none() {
     let task = Task::new();
@@ -1811,6 +1999,8 @@ fn task_extend_both_have_misc_data() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Message("extended".to_string())),
         extend: None,
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
         condition_script: None,
@@ -1837,6 +2027,8 @@ fn task_extend_both_have_misc_data() {
         linux: None,
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     base.extend(&extended);
@@ -1913,6 +2105,8 @@ fn task_extend_extended_have_all_fields() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
         condition_script: None,
@@ -1939,6 +2133,8 @@ fn task_extend_extended_have_all_fields() {
         linux: None,
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let mut env = IndexMap::new();
@@ -1956,6 +2152,8 @@ fn task_extend_extended_have_all_fields() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(false)),
         extend: Some("extended".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -1975,6 +2173,10 @@ fn task_extend_extended_have_all_fields() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2029,6 +2231,10 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2050,6 +2256,7 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2080,6 +2287,10 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2101,6 +2312,7 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2131,6 +2343,10 @@ fn task_extend_extended_have_all_fields() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2152,7 +2368,10 @@ fn task_extend_extended_have_all_fields() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
+        export_env: None,
+        vars: None,
     };
 
     base.extend(&extended);
@@ -2264,6 +2483,8 @@ fn task_extend_clear_with_no_data() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2283,6 +2504,10 @@ fn task_extend_clear_with_no_data() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2337,6 +2562,10 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2358,6 +2587,7 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2388,6 +2618,10 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2409,6 +2643,7 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2439,6 +2674,10 @@ fn task_extend_clear_with_no_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2460,7 +2699,10 @@ fn task_extend_clear_with_no_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
+        export_env: None,
+        vars: None,
     };
 
     let mut extended = Task::new();
@@ -2525,6 +2767,8 @@ fn task_extend_clear_with_all_data() {
         private: Some(false),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(false)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2544,6 +2788,10 @@ fn task_extend_clear_with_all_data() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2598,6 +2846,10 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2619,6 +2871,7 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         windows: Some(PlatformOverrideTask {
             clear: Some(false),
@@ -2649,6 +2902,10 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2670,6 +2927,7 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
         mac: Some(PlatformOverrideTask {
             clear: None,
@@ -2700,6 +2958,10 @@ fn task_extend_clear_with_all_data() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -2721,7 +2983,10 @@ fn task_extend_clear_with_all_data() {
             run_task: Some(RunTaskInfo::Name("task3".to_string())),
             dependencies: Some(vec!["A".into()]),
             toolchain: Some("toolchain".into()),
+            vars: None,
         }),
+        export_env: None,
+        vars: None,
     };
 
     base.extend(&extended);
@@ -2815,6 +3080,8 @@ fn task_get_normalized_task_undefined() {
         private: Some(true),
         deprecated: None,
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: None,
         condition_script: None,
@@ -2844,6 +3111,8 @@ fn task_get_normalized_task_undefined() {
         linux: None,
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -2950,6 +3219,8 @@ fn task_get_normalized_task_with_override_no_clear() {
         private: Some(true),
         deprecated: None,
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -2969,6 +3240,10 @@ fn task_get_normalized_task_with_override_no_clear() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -3019,6 +3294,10 @@ fn task_get_normalized_task_with_override_no_clear() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
             condition_script_runner_args: Some(vec![
@@ -3053,9 +3332,12 @@ fn task_get_normalized_task_with_override_no_clear() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            vars: None,
         }),
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3176,6 +3458,8 @@ fn task_get_normalized_task_with_override_clear_false() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(false)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3195,6 +3479,10 @@ fn task_get_normalized_task_with_override_clear_false() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -3248,6 +3536,10 @@ fn task_get_normalized_task_with_override_clear_false() {
                 files_exist: None,
                 files_not_exist: None,
                 files_modified: None,
+                crate_has_feature: None,
+                crate_has_dependency: None,
+                crate_has_target: None,
+                crate_is_workspace_member: None,
             }),
             condition_script: Some(ConditionScriptValue::Text(vec![
                 "echo test".to_string(),
@@ -3286,9 +3578,12 @@ fn task_get_normalized_task_with_override_clear_false() {
             run_task: Some(RunTaskInfo::Name("task2".to_string())),
             dependencies: Some(vec!["1".into(), "2".into()]),
             toolchain: Some("toolchain2".into()),
+            vars: None,
         }),
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3402,6 +3697,8 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3421,6 +3718,10 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -3474,9 +3775,12 @@ fn task_get_normalized_task_with_override_clear_false_partial_override() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            vars: None,
         }),
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -3576,6 +3880,8 @@ fn task_get_normalized_task_with_override_clear_true() {
         private: Some(true),
         deprecated: Some(DeprecationInfo::Boolean(true)),
         extend: Some("base".to_string()),
+        template: None,
+        with: None,
         watch: Some(TaskWatchOptions::Boolean(true)),
         condition: Some(TaskCondition {
             condition_type: None,
@@ -3595,6 +3901,10 @@ fn task_get_normalized_task_with_override_clear_true() {
             files_exist: None,
             files_not_exist: None,
             files_modified: None,
+            crate_has_feature: None,
+            crate_has_dependency: None,
+            crate_has_target: None,
+            crate_is_workspace_member: None,
         }),
         condition_script: Some(ConditionScriptValue::Text(vec!["exit 0".to_string()])),
         condition_script_runner_args: Some(vec!["csr_a1".to_string(), "csr_a2".to_string()]),
@@ -3648,9 +3958,12 @@ fn task_get_normalized_task_with_override_clear_true() {
             run_task: None,
             dependencies: None,
             toolchain: None,
+            vars: None,
         }),
         windows: None,
         mac: None,
+        export_env: None,
+        vars: None,
     };
 
     let normalized_task = task.get_normalized_task();
@@ -4652,6 +4965,7 @@ fn config_apply_modify_empty() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.apply(&modify_config);
 
@@ -4680,6 +4994,7 @@ fn config_apply_modify_all() {
         env_scripts: vec![],
         tasks,
         plugins: None,
+        vars: IndexMap::new(),
     };
     config.apply(&modify_config);
 
@@ -4689,6 +5004,85 @@ fn config_apply_modify_all() {
     assert!(config.tasks.get("all::test").unwrap().private.unwrap());
 }
 
+#[test]
+fn task_apply_template_and_cleanup_task_modify_namespace() {
+    let modify_config = ModifyConfig {
+        private: None,
+        namespace: Some("default".to_string()),
+    };
+
+    let mut task = Task::new();
+    task.template = Some("template1".to_string());
+    task.run_task = Some(RunTaskInfo::Details(RunTaskDetails {
+        name: RunTaskName::Single("run_task1".to_string()),
+        fork: Some(true),
+        parallel: None,
+        cleanup_task: Some("cleanup1".to_string()),
+    }));
+
+    task.apply(&modify_config);
+
+    assert_eq!(task.template.unwrap(), "default::template1");
+    let details = match task.run_task.unwrap() {
+        RunTaskInfo::Details(ref mut details) => details.clone(),
+        _ => panic!("Invalid run task value."),
+    };
+    assert_eq!(details.cleanup_task.unwrap(), "default::cleanup1");
+}
+
+#[test]
+fn external_config_apply_modify_empty() {
+    let modify_config = ModifyConfig {
+        private: None,
+        namespace: None,
+    };
+    let mut config = ExternalConfig::new();
+    config.apply(&modify_config);
+
+    assert!(config.config.is_none());
+    assert!(config.tasks.is_none());
+    assert!(config.templates.is_none());
+}
+
+#[test]
+fn external_config_apply_modify_all() {
+    let modify_config = ModifyConfig {
+        private: Some(true),
+        namespace: Some("shared".to_string()),
+    };
+    let mut config_section = ConfigSection::new();
+    config_section.end_task = Some("end".to_string());
+    let mut tasks = IndexMap::new();
+    let mut task = Task::new();
+    task.dependencies = Some(vec!["build".into()]);
+    task.template = Some("template".to_string());
+    tasks.insert("test".to_string(), task);
+    let mut templates = IndexMap::new();
+    let mut template = Task::new();
+    template.dependencies = Some(vec!["format".into()]);
+    templates.insert("template".to_string(), template);
+    let mut config = ExternalConfig {
+        config: Some(config_section),
+        tasks: Some(tasks),
+        templates: Some(templates),
+        ..ExternalConfig::new()
+    };
+    config.apply(&modify_config);
+
+    assert_eq!(config.config.unwrap().end_task.unwrap(), "shared::end");
+    let tasks = config.tasks.unwrap();
+    assert_eq!(tasks.len(), 1);
+    let task = tasks.get("shared::test").unwrap();
+    assert!(task.private.unwrap());
+    assert_eq!(task.dependencies, Some(vec!["shared::build".into()]));
+    assert_eq!(task.template, Some("shared::template".to_string()));
+    let templates = config.templates.unwrap();
+    assert_eq!(
+        templates.get("shared::template").unwrap().dependencies,
+        Some(vec!["shared::format".into()])
+    );
+}
+
 #[test]
 fn deprecation_info_partial_eq_same_bool_true() {
     let value1 = DeprecationInfo::Boolean(true);